@@ -0,0 +1,59 @@
+//! `aoc::arena::Arena` against plain vectors on the pattern of day 19's
+//! matcher: many short lists of positions, built, memoized and handed out
+//! again on every hit. `cargo bench --bench arena`; `cargo bench -- day19`
+//! and `day20` time the solvers that allocate from it.
+
+use std::hint::black_box;
+
+use aoc::arena::Arena;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// How many lists a round builds, about as many as the memo of a long
+/// day 19 message holds.
+const LISTS: usize = 10_000;
+
+/// The positions of list `i`, one to four of them.
+fn positions(i: usize) -> std::ops::Range<usize> {
+    i..i + i % 4 + 1
+}
+
+fn lists(c: &mut Criterion) {
+    let mut group = c.benchmark_group("arena");
+    group.bench_function("vec", |b| {
+        b.iter(|| {
+            let mut memo: Vec<Vec<usize>> = Vec::with_capacity(LISTS);
+            let mut total = 0;
+            for i in 0..LISTS {
+                let list: Vec<usize> = positions(i).collect();
+                total += list.len();
+                memo.push(list);
+            }
+            // a hit hands out a copy of the memoized list
+            for list in &memo {
+                total += black_box(list.clone()).len();
+            }
+            total
+        })
+    });
+    let mut arena = Arena::new();
+    group.bench_function("arena", |b| {
+        b.iter(|| {
+            arena.reset();
+            let mut memo: Vec<&[usize]> = Vec::with_capacity(LISTS);
+            let mut total = 0;
+            for i in 0..LISTS {
+                let list = arena.alloc_iter(positions(i));
+                total += list.len();
+                memo.push(list);
+            }
+            for list in &memo {
+                total += black_box(*list).len();
+            }
+            total
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, lists);
+criterion_main!(benches);
@@ -0,0 +1,31 @@
+//! Criterion benchmarks of both parts of every day of the default year on
+//! the real inputs, grouped per day: `cargo bench -- day09` runs one day. Days without an
+//! `inputs/NN-input.txt` are skipped.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn days(c: &mut Criterion) {
+    for puzzle in aoc::year2020::PUZZLES {
+        let Ok(input) = aoc::read_as_string(puzzle.day, "input") else {
+            eprintln!("skipping day {}: no input", puzzle.day);
+            continue;
+        };
+        let mut group = c.benchmark_group(format!("day{:02}", puzzle.day));
+        for part in [1, 2] {
+            group.bench_function(format!("part{part}"), |b| {
+                b.iter(|| puzzle.solve(part, black_box(&input)))
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group! {
+    name = benches;
+    // the slowest parts take seconds, so keep to the fewest samples
+    config = Criterion::default().sample_size(10);
+    targets = days
+}
+criterion_main!(benches);
@@ -0,0 +1,109 @@
+//! How solvers scale with the size of their input, on inputs from
+//! `aoc::generate::sized` well past the size of the real ones: a solver
+//! that turns quadratic shows up here as a growth of `n^2` where the
+//! benchmarks of `days.rs` only show it a little slower. `cargo bench
+//! --bench scaling`, or `-- day11` for one day; each group ends with a
+//! chart of the median runtime at each size.
+
+use std::hint::black_box;
+use std::time::Duration;
+
+use aoc::bench::{scaling_chart, summarize};
+use aoc::timing::Stopwatch;
+use aoc::year2020::day09::PreambleStrategy;
+use aoc::year2020::{day09, day11, day24};
+use criterion::{
+    criterion_group, criterion_main, BenchmarkId, Criterion, Throughput,
+};
+
+/// Every size gets the same input on every run.
+const SEED: u64 = 2020;
+
+/// One part of a day at growing sizes.
+struct Case {
+    name: &'static str,
+    day: u8,
+    sizes: &'static [usize],
+    /// How many items an input of a size has, e.g. the seats of a grid.
+    elements: fn(usize) -> usize,
+    solve: fn(&str) -> u64,
+}
+
+const CASES: &[Case] = &[
+    Case {
+        name: "day09-part2",
+        day: 9,
+        sizes: &[10_000, 100_000],
+        elements: |size| size,
+        solve: |input| {
+            day09::part_two_with(input, PreambleStrategy::Real).unwrap()
+        },
+    },
+    Case {
+        name: "day11-part1",
+        day: 11,
+        sizes: &[100, 250, 500],
+        elements: |size| size * size,
+        solve: |input| day11::part_one(input) as u64,
+    },
+    Case {
+        name: "day11-part2",
+        day: 11,
+        sizes: &[100, 250, 500],
+        elements: |size| size * size,
+        solve: |input| day11::part_two(input) as u64,
+    },
+    Case {
+        name: "day24-part2",
+        day: 24,
+        sizes: &[1_000, 10_000],
+        elements: |size| size,
+        solve: |input| day24::part_two(input) as u64,
+    },
+];
+
+fn scaling(c: &mut Criterion) {
+    for case in CASES {
+        let mut group = c.benchmark_group(format!("scaling/{}", case.name));
+        let mut points = Vec::new();
+        for &size in case.sizes {
+            let input = aoc::generate::sized(case.day, size, SEED).unwrap();
+            let elements = (case.elements)(size);
+            let mut samples: Vec<Duration> = Vec::new();
+            group.throughput(Throughput::Elements(elements as u64));
+            group.bench_with_input(
+                BenchmarkId::from_parameter(size),
+                &input,
+                |b, input| {
+                    // timed here rather than by criterion to keep the
+                    // samples for the chart
+                    b.iter_custom(|iters| {
+                        let stopwatch = Stopwatch::start();
+                        for _ in 0..iters {
+                            black_box((case.solve)(black_box(input)));
+                        }
+                        let elapsed = stopwatch.elapsed();
+                        samples.push(elapsed / iters as u32);
+                        elapsed
+                    })
+                },
+            );
+            // nothing ran when a filter left the size out
+            if !samples.is_empty() {
+                points.push((elements, summarize(&samples).median));
+            }
+        }
+        group.finish();
+        if !points.is_empty() {
+            println!("{}\n{}", case.name, scaling_chart(&points, 40));
+        }
+    }
+}
+
+criterion_group! {
+    name = benches;
+    // the largest sizes take seconds, so keep to the fewest samples
+    config = Criterion::default().sample_size(10);
+    targets = scaling
+}
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+//! Records the toolchain and git commit the crate is built from, for the
+//! records of the timing history.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    if let Some(version) = output(&rustc, &["--version"]) {
+        let version = version.split(" (").next().unwrap_or_default();
+        println!("cargo:rustc-env=AOC_TOOLCHAIN={version}");
+    }
+    if let Some(commit) = output("git", &["rev-parse", "--short", "HEAD"]) {
+        println!("cargo:rustc-env=AOC_COMMIT={commit}");
+    }
+
+    // a new commit moves HEAD or the branch it points to
+    println!("cargo:rerun-if-changed=build.rs");
+    let mut refs = vec!["HEAD".to_string(), "packed-refs".to_string()];
+    refs.extend(output("git", &["symbolic-ref", "-q", "HEAD"]));
+    for name in refs {
+        if let Some(path) = output("git", &["rev-parse", "--git-path", &name])
+        {
+            if Path::new(&path).exists() {
+                println!("cargo:rerun-if-changed={path}");
+            }
+        }
+    }
+}
+
+fn output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
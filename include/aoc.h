@@ -0,0 +1,59 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated from src/ffi.rs by cbindgen; do not edit. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * The answer is in the output buffer.
+ */
+#define AOC_OK 0
+
+/**
+ * The output buffer is too small; `out_len` holds the length needed,
+ * without the terminating NUL.
+ */
+#define AOC_BUFFER_TOO_SMALL 1
+
+/**
+ * No puzzle for the day, or a part other than 1 and 2.
+ */
+#define AOC_NO_PUZZLE 2
+
+/**
+ * A null pointer or an input that is not UTF-8.
+ */
+#define AOC_INVALID_ARGUMENT 3
+
+/**
+ * A malformed input or one without an answer; the message is in the
+ * output buffer.
+ */
+#define AOC_ERROR 4
+
+/**
+ * The solver panicked; the message is in the output buffer.
+ */
+#define AOC_PANIC 5
+
+/**
+ * Solve `part` of `day` for the `len` bytes of UTF-8 at `input`, writing
+ * the answer, or the message of `AOC_ERROR` and `AOC_PANIC`, to `out` as
+ * a NUL-terminated string. `*out_len` is the capacity of `out` on entry
+ * and the length of the string on return.
+ *
+ * # Safety
+ *
+ * `input` must point to `len` readable bytes, `out_len` to a `size_t`, and
+ * `out` to `*out_len` writable bytes.
+ */
+int32_t aoc_solve(uint8_t day,
+                  uint8_t part,
+                  const uint8_t *input,
+                  size_t len,
+                  uint8_t *out,
+                  size_t *out_len);
+
+#endif  /* AOC_H */
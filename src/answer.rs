@@ -0,0 +1,94 @@
+//! Puzzle Answers
+//!
+//! Days return a mix of signed, unsigned and textual answers. `Answer` holds
+//! any of them by value so runners can collect, compare and print answers
+//! without boxing each one behind `dyn Display`. Answers past `u64`, e.g.
+//! day 13 and day 18 on scaled-up inputs, are `Wide`.
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    /// An unsigned answer too large for `Unsigned`.
+    Wide(u128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(v) => v.fmt(f),
+            Answer::Unsigned(v) => v.fmt(f),
+            Answer::Wide(v) => v.fmt(f),
+            Answer::Text(v) => v.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident as $target:ty: $($source:ty),*) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(Signed as i64: i32, i64);
+impl_from!(Unsigned as u64: u16, u32, u64, usize);
+
+/// `Unsigned` where it fits, so answers compare equal whatever type a day
+/// computes them in.
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match u64::try_from(value) {
+            Ok(v) => Answer::Unsigned(v),
+            Err(_) => Answer::Wide(value),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(-42i32).to_string(), "-42");
+        assert_eq!(
+            Answer::from(2314037239808usize).to_string(),
+            "2314037239808"
+        );
+        assert_eq!(
+            Answer::from("mxmxvkd,sqjhc").to_string(),
+            "mxmxvkd,sqjhc"
+        );
+        assert_eq!(format!("{:>5}", Answer::from(7u16)), "    7");
+        assert_eq!(Answer::from(19208u128), Answer::Unsigned(19208));
+        assert_eq!(Answer::from(u128::MAX), Answer::Wide(u128::MAX));
+        assert_eq!(
+            Answer::from(u128::MAX).to_string(),
+            "340282366920938463463374607431768211455"
+        );
+    }
+}
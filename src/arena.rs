@@ -0,0 +1,134 @@
+//! Arena Allocation
+//!
+//! An `Arena` hands out slices of `Copy` values from a few large chunks
+//! instead of a heap allocation each, and frees them all at once when it
+//! is dropped or `reset` for the next round, e.g. the next message of day
+//! 19. Solvers that build many small, short-lived lists (day 19's end
+//! positions, day 20's tile orientations and edges) allocate them here and
+//! pass `&[T]` around instead of cloning `Vec`s. `benches/arena.rs`
+//! compares it with plain vectors.
+
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::slice;
+
+/// The capacity of the first chunk, in values.
+const FIRST_CHUNK: usize = 256;
+
+/// A bump allocator for slices of `T`.
+#[derive(Debug, Default)]
+pub struct Arena<T> {
+    /// Chunks are never grown past their capacity, so their buffers never
+    /// move while the arena is borrowed.
+    chunks: RefCell<Vec<Vec<T>>>,
+}
+
+impl<T: Copy> Arena<T> {
+    pub fn new() -> Self {
+        Arena {
+            chunks: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// A copy of `values` that lives as long as the arena.
+    pub fn alloc_slice(&self, values: &[T]) -> &[T] {
+        self.alloc_iter(values.iter().copied())
+    }
+
+    /// The values of `iter` in a slice that lives as long as the arena.
+    pub fn alloc_iter<I>(&self, iter: I) -> &[T]
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let len = iter.len();
+        if len == 0 {
+            return &[];
+        }
+        let mut chunks = self.chunks.borrow_mut();
+        let chunk = match chunks.last_mut() {
+            Some(chunk) if chunk.capacity() - chunk.len() >= len => chunk,
+            _ => {
+                let last = chunks.last().map_or(0, Vec::capacity);
+                let capacity = (2 * last).max(FIRST_CHUNK).max(len);
+                chunks.push(Vec::with_capacity(capacity));
+                chunks.last_mut().unwrap()
+            }
+        };
+        let start = chunk.len();
+        // never more than `len`, which fits without reallocating
+        chunk.extend(iter.take(len));
+        let values = &chunk[start..];
+        // SAFETY: the values are in a chunk that is neither reallocated
+        // nor dropped until the arena is dropped or reset, both of which
+        // take it mutably, so no borrow of `self` outlives them
+        unsafe { slice::from_raw_parts(values.as_ptr(), values.len()) }
+    }
+
+    /// Free every slice, keeping the largest chunk for what comes next.
+    pub fn reset(&mut self) {
+        let chunks = self.chunks.get_mut();
+        if let Some(mut largest) = chunks.pop() {
+            largest.clear();
+            chunks.clear();
+            chunks.push(largest);
+        }
+    }
+
+    /// How many values the arena holds.
+    pub fn len(&self) -> usize {
+        self.chunks.borrow().iter().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slices() {
+        let mut arena = Arena::new();
+        let a = arena.alloc_slice(&[1, 2, 3]);
+        let b = arena.alloc_iter((0..1000).map(|i| i * 2));
+        let c = arena.alloc_slice(&[4]);
+        // earlier slices survive the chunks added after them
+        assert_eq!((a, c), (&[1, 2, 3][..], &[4][..]));
+        assert_eq!((b.len(), b[999]), (1000, 1998));
+        assert!(arena.alloc_slice(&[]).is_empty());
+        assert_eq!(arena.len(), 1004);
+        assert!(arena.chunks.borrow().len() > 1);
+
+        arena.reset();
+        assert!(arena.is_empty());
+        assert_eq!(arena.chunks.borrow().len(), 1);
+        let d = arena.alloc_slice(&[5, 6]);
+        let e = arena.alloc_slice(&[7]);
+        // both in the chunk kept by the reset
+        assert_eq!((d, e), (&[5, 6][..], &[7][..]));
+    }
+
+    #[test]
+    fn short_iterator() {
+        // an iterator that claims more than it yields
+        struct Liar(u8);
+        impl Iterator for Liar {
+            type Item = u8;
+            fn next(&mut self) -> Option<u8> {
+                self.0 = self.0.checked_sub(1)?;
+                Some(self.0)
+            }
+        }
+        impl ExactSizeIterator for Liar {
+            fn len(&self) -> usize {
+                10
+            }
+        }
+        let arena = Arena::new();
+        assert_eq!(arena.alloc_iter(Liar(3)), &[2, 1, 0]);
+    }
+}
@@ -0,0 +1,329 @@
+//! Cellular Automata
+//!
+//! The Game of Life variants of days 11, 17 and 24 are a `Rule`, which
+//! names the neighbors of a cell and whether a cell is live in the next
+//! generation given how many of them are, run by one of two engines:
+//!
+//! - `Sparse` keeps only the live cells in a `FastSet` and counts, for
+//!   every neighbor of a live cell, how many live cells it is next to, so
+//!   an unbounded space costs only what is alive (days 17 and 24; std
+//!   only).
+//! - `Dense` runs a fixed set of cells, e.g. the seats of day 11, with the
+//!   neighbors of each looked up once, when it is built, and its states in
+//!   two flat buffers flipped in turn.
+//!
+//! Both are an `Automaton`, stepped one generation at a time, `run_n`
+//! generations or until a generation changes nothing. A space that flips
+//! between two states forever instead fails `run_until_stable` with
+//! `AocError::NoSolution`; longer cycles are not detected.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::cancel;
+#[cfg(feature = "std")]
+use crate::collections::{FastMap, FastSet};
+use crate::error::AocError;
+
+/// The neighborhood and the transition of an automaton.
+pub trait Rule {
+    /// The position of a cell, e.g. `(x, y)`.
+    type Cell: Copy + Eq + core::hash::Hash;
+
+    /// The cells whose states count for `cell`, each once.
+    fn neighbors(&self, cell: Self::Cell)
+        -> impl Iterator<Item = Self::Cell>;
+
+    /// Whether a cell is live in the next generation, given whether it is
+    /// now and how many of its neighbors are.
+    fn next(&self, live: bool, live_neighbors: usize) -> bool;
+}
+
+/// The conventional Game of Life rule: born with three live neighbors,
+/// surviving with two or three.
+pub fn conway(live: bool, live_neighbors: usize) -> bool {
+    live_neighbors == 3 || live && live_neighbors == 2
+}
+
+/// A space of cells under a `Rule`.
+pub trait Automaton {
+    /// Advance one generation, returning whether any cell changed.
+    fn step(&mut self) -> bool;
+
+    /// Whether the last step went back to the generation before the one
+    /// it started from, so the cells flip between the two forever.
+    fn oscillates(&self) -> bool;
+
+    /// The number of live cells.
+    fn population(&self) -> usize;
+
+    /// Advance `n` generations.
+    fn run_n(&mut self, n: usize) -> Result<(), AocError> {
+        for _ in 0..n {
+            cancel::check()?;
+            self.step();
+        }
+        Ok(())
+    }
+
+    /// Advance until a generation changes nothing, returning how many
+    /// changed, or fail once the cells flip between two generations.
+    fn run_until_stable(&mut self) -> Result<usize, AocError> {
+        let mut steps = 0;
+        loop {
+            cancel::check()?;
+            if !self.step() {
+                return Ok(steps);
+            }
+            if self.oscillates() {
+                return Err(AocError::no_solution(
+                    "the cells flip between two states forever",
+                ));
+            }
+            steps += 1;
+        }
+    }
+}
+
+/// An automaton of only its live cells, in an unbounded space.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct Sparse<R: Rule> {
+    rule: R,
+    live: FastSet<R::Cell>,
+    /// The live cells of the generation before.
+    before: FastSet<R::Cell>,
+    generation: usize,
+    oscillates: bool,
+}
+
+#[cfg(feature = "std")]
+impl<R: Rule> Sparse<R> {
+    pub fn new(rule: R, live: impl IntoIterator<Item = R::Cell>) -> Self {
+        Sparse {
+            rule,
+            live: live.into_iter().collect(),
+            before: FastSet::default(),
+            generation: 0,
+            oscillates: false,
+        }
+    }
+
+    pub fn live(&self) -> &FastSet<R::Cell> {
+        &self.live
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Rule> Automaton for Sparse<R> {
+    fn step(&mut self) -> bool {
+        // only live cells and their neighbors can be live next
+        let mut counts: FastMap<R::Cell, usize> = FastMap::default();
+        for &cell in &self.live {
+            for neighbor in self.rule.neighbors(cell) {
+                *counts.entry(neighbor).or_default() += 1;
+            }
+        }
+        let mut next: FastSet<R::Cell> = counts
+            .iter()
+            .filter(|(cell, &n)| self.rule.next(self.live.contains(cell), n))
+            .map(|(&cell, _)| cell)
+            .collect();
+        next.extend(self.live.iter().filter(|cell| {
+            !counts.contains_key(cell) && self.rule.next(true, 0)
+        }));
+
+        let changed = next != self.live;
+        self.oscillates =
+            changed && self.generation > 0 && next == self.before;
+        self.before = core::mem::replace(&mut self.live, next);
+        self.generation += 1;
+        changed
+    }
+
+    fn oscillates(&self) -> bool {
+        self.oscillates
+    }
+
+    fn population(&self) -> usize {
+        self.live.len()
+    }
+}
+
+/// An automaton of a fixed set of cells; neighbors outside it are never
+/// live.
+#[derive(Debug, Clone)]
+pub struct Dense<R: Rule> {
+    rule: R,
+    cells: Vec<R::Cell>,
+    /// The neighbors of cell `i` are `neighbors[starts[i]..starts[i + 1]]`,
+    /// by index.
+    neighbors: Vec<usize>,
+    starts: Vec<usize>,
+    live: Vec<bool>,
+    /// The generation before `live`, overwritten by the next one.
+    spare: Vec<bool>,
+    generation: usize,
+    oscillates: bool,
+}
+
+impl<R: Rule> Dense<R>
+where
+    R::Cell: Ord,
+{
+    /// The `cells` with whether each is live.
+    pub fn new(
+        rule: R,
+        cells: impl IntoIterator<Item = (R::Cell, bool)>,
+    ) -> Self {
+        let (cells, live): (Vec<R::Cell>, Vec<bool>) =
+            cells.into_iter().unzip();
+        let index: BTreeMap<R::Cell, usize> = cells
+            .iter()
+            .enumerate()
+            .map(|(i, &cell)| (cell, i))
+            .collect();
+        let mut neighbors = Vec::new();
+        let mut starts = Vec::with_capacity(cells.len() + 1);
+        for &cell in &cells {
+            starts.push(neighbors.len());
+            neighbors.extend(
+                rule.neighbors(cell).filter_map(|n| index.get(&n).copied()),
+            );
+        }
+        starts.push(neighbors.len());
+        Dense {
+            rule,
+            spare: live.clone(),
+            cells,
+            neighbors,
+            starts,
+            live,
+            generation: 0,
+            oscillates: false,
+        }
+    }
+}
+
+impl<R: Rule> Dense<R> {
+    /// Every cell with whether it is live, in the order they were given.
+    pub fn states(&self) -> impl Iterator<Item = (R::Cell, bool)> + '_ {
+        self.cells.iter().copied().zip(self.live.iter().copied())
+    }
+}
+
+impl<R: Rule> Automaton for Dense<R> {
+    fn step(&mut self) -> bool {
+        let mut changed = false;
+        // whether the next generation is the one before, which `spare`
+        // holds from the second step on
+        let mut repeats = self.generation > 0;
+        for i in 0..self.cells.len() {
+            let live_neighbors = self.neighbors
+                [self.starts[i]..self.starts[i + 1]]
+                .iter()
+                .filter(|&&j| self.live[j])
+                .count();
+            let next = self.rule.next(self.live[i], live_neighbors);
+            changed |= next != self.live[i];
+            repeats &= next == self.spare[i];
+            self.spare[i] = next;
+        }
+        core::mem::swap(&mut self.live, &mut self.spare);
+        self.generation += 1;
+        self.oscillates = changed && repeats;
+        changed
+    }
+
+    fn oscillates(&self) -> bool {
+        self.oscillates
+    }
+
+    fn population(&self) -> usize {
+        self.live.iter().filter(|&&live| live).count()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    /// Conway's Game of Life on the plane.
+    struct Life;
+
+    impl Rule for Life {
+        type Cell = (i32, i32);
+
+        fn neighbors(
+            &self,
+            (x, y): (i32, i32),
+        ) -> impl Iterator<Item = (i32, i32)> {
+            (-1..=1)
+                .flat_map(move |dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+                .filter(move |&cell| cell != (x, y))
+        }
+
+        fn next(&self, live: bool, live_neighbors: usize) -> bool {
+            conway(live, live_neighbors)
+        }
+    }
+
+    const BLINKER: [(i32, i32); 3] = [(0, 1), (1, 1), (2, 1)];
+    const BLOCK: [(i32, i32); 4] = [(0, 0), (1, 0), (0, 1), (1, 1)];
+
+    fn dense(live: &[(i32, i32)]) -> Dense<Life> {
+        let cells = (-2..5).flat_map(|y| (-2..5).map(move |x| (x, y)));
+        Dense::new(Life, cells.map(|cell| (cell, live.contains(&cell))))
+    }
+
+    #[test]
+    fn sparse() {
+        let mut block = Sparse::new(Life, BLOCK);
+        assert_eq!(block.run_until_stable().unwrap(), 0);
+        assert_eq!(block.population(), 4);
+
+        let mut blinker = Sparse::new(Life, BLINKER);
+        assert!(blinker.step() && !blinker.oscillates());
+        assert!(blinker.live().contains(&(1, 0)));
+        assert!(matches!(
+            blinker.run_until_stable(),
+            Err(AocError::NoSolution(_))
+        ));
+
+        // a glider keeps its five cells, moving one cell every four steps
+        let mut glider =
+            Sparse::new(Life, [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+        glider.run_n(4).unwrap();
+        assert_eq!(glider.population(), 5);
+        assert!(glider.live().contains(&(3, 3)));
+    }
+
+    #[test]
+    fn dense_matches_sparse() {
+        let mut block = dense(&BLOCK);
+        assert_eq!(block.run_until_stable().unwrap(), 0);
+        assert_eq!(block.population(), 4);
+
+        let mut blinker = dense(&BLINKER);
+        let mut sparse = Sparse::new(Life, BLINKER);
+        for _ in 0..3 {
+            assert_eq!(blinker.step(), sparse.step());
+            let live: FastSet<_> = blinker
+                .states()
+                .filter(|&(_, live)| live)
+                .map(|(cell, _)| cell)
+                .collect();
+            assert_eq!(&live, sparse.live());
+        }
+        assert!(blinker.oscillates());
+        assert!(matches!(
+            blinker.run_until_stable(),
+            Err(AocError::NoSolution(_))
+        ));
+
+        // a dense space's edge is dead for good
+        let mut edge = Dense::new(Life, BLINKER.map(|cell| (cell, true)));
+        edge.run_n(1).unwrap();
+        assert_eq!(edge.population(), 1);
+    }
+}
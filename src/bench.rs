@@ -0,0 +1,109 @@
+//! Benchmark Statistics
+//!
+//! Summaries of repeated timings for `aoc bench`, which runs each part
+//! several times after a warmup instead of trusting a single `--time`
+//! measurement, and of the timings at growing input sizes of
+//! `benches/scaling.rs`, whose growth tells a quadratic solver from a
+//! linear one where a single size only tells fast from slow.
+
+use std::time::Duration;
+
+use crate::report::bar;
+use crate::timing::format_duration;
+
+/// Statistics of the durations of repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Population standard deviation.
+    pub stddev: Duration,
+}
+
+/// Summarize at least one duration.
+pub fn summarize(durations: &[Duration]) -> Summary {
+    let mut sorted = durations.to_vec();
+    sorted.sort_unstable();
+    let n = sorted.len();
+    let median = if n % 2 == 1 {
+        sorted[n / 2]
+    } else {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+    };
+    let mean =
+        sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+    let variance = sorted
+        .iter()
+        .map(|d| (d.as_secs_f64() - mean).powi(2))
+        .sum::<f64>()
+        / n as f64;
+    Summary {
+        runs: n,
+        min: sorted[0],
+        median,
+        mean: Duration::from_secs_f64(mean),
+        stddev: Duration::from_secs_f64(variance.sqrt()),
+    }
+}
+
+/// The exponent `k` of the `n^k` the runtime grew with from one input size
+/// to another, e.g. 1 for a linear solver and 2 for a quadratic one.
+pub fn growth(from: (usize, Duration), to: (usize, Duration)) -> f64 {
+    let time = to.1.as_secs_f64() / from.1.as_secs_f64();
+    time.ln() / (to.0 as f64 / from.0 as f64).ln()
+}
+
+/// A bar chart of the runtime at each input size, scaled so the slowest
+/// spans `width` cells, with its growth from the size before, e.g.
+///
+/// ```text
+///    10000 ▊                      412µs
+///   100000 ███████▉               4.33ms  n^1.02
+/// ```
+pub fn scaling_chart(points: &[(usize, Duration)], width: usize) -> String {
+    let max = points.iter().map(|(_, d)| d.as_nanos()).max().unwrap_or(0);
+    let mut chart = String::new();
+    for (i, &(size, time)) in points.iter().enumerate() {
+        let bar = bar(time.as_nanos(), max, width);
+        let time = format_duration(time);
+        chart += &match i.checked_sub(1).map(|j| growth(points[j], points[i]))
+        {
+            Some(k) => format!("{size:>9} {bar} {time:<8} n^{k:.2}\n"),
+            None => format!("{size:>9} {bar} {time}\n"),
+        };
+    }
+    chart
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary() {
+        let ms = Duration::from_millis;
+        let summary = summarize(&[ms(4), ms(2), ms(6), ms(8)]);
+        assert_eq!(summary.runs, 4);
+        assert_eq!(summary.min, ms(2));
+        assert_eq!(summary.median, ms(5));
+        assert_eq!(summary.mean, ms(5));
+        assert_eq!(summary.stddev.as_micros(), 2236);
+
+        let summary = summarize(&[ms(3)]);
+        assert_eq!((summary.median, summary.stddev), (ms(3), Duration::ZERO));
+    }
+
+    #[test]
+    fn scaling() {
+        let ms = Duration::from_millis;
+        assert!((growth((10, ms(2)), (100, ms(20))) - 1.0).abs() < 1e-9);
+        assert!((growth((10, ms(2)), (100, ms(200))) - 2.0).abs() < 1e-9);
+        let chart = scaling_chart(&[(100, ms(1)), (1000, ms(100))], 4);
+        assert_eq!(
+            chart,
+            "      100 ▏     1.00ms\n     1000 ████  100ms    n^2.00\n"
+        );
+    }
+}
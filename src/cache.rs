@@ -0,0 +1,159 @@
+//! Answer Cache
+//!
+//! Support for `--cached`, which reuses the answers of earlier runs instead
+//! of solving the parts again. Answers are keyed by a hash of the year, the
+//! day, the part, the input and the solver options, and tagged with a hash of the
+//! executable, so rebuilding the runner invalidates every cached answer.
+//!
+//! Answers are flat JSON objects, one per line:
+//!
+//! ```text
+//! {"key": "9f2c6e1a04b3d587", "build": "51e0c2a77d9b3f60", "day": 15,
+//!  "part": 2, "answer": "48568", "duration_ns": 512300000}
+//! ```
+
+use std::env;
+use std::fs;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::json::{parse_flat_object, quote};
+
+pub const DEFAULT_PATH: &str = ".aoc/cache.jsonl";
+
+/// The answer of a part, with how long it took when it was solved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cached {
+    pub key: u64,
+    /// The `build_id` of the executable that solved the part.
+    pub build: u64,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub duration: Duration,
+}
+
+/// The cache key of a part of a day of `year` solved on `input` with
+/// `options`, such as the `--algo` in use.
+pub fn key(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    options: &[&str],
+) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    (year, day, part, input, options).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A hash of the running executable, or 0 if it cannot be read.
+pub fn build_id() -> u64 {
+    let Ok(bytes) = env::current_exe().and_then(fs::read) else {
+        return 0;
+    };
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Load all answers, skipping lines that are not valid answers.
+pub fn load(path: &Path) -> io::Result<Vec<Cached>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    Ok(content.lines().filter_map(parse_cached).collect())
+}
+
+fn parse_cached(line: &str) -> Option<Cached> {
+    let fields = parse_flat_object(line)?;
+    Some(Cached {
+        key: u64::from_str_radix(fields.get("key")?, 16).ok()?,
+        build: u64::from_str_radix(fields.get("build")?, 16).ok()?,
+        day: fields.get("day")?.parse().ok()?,
+        part: fields.get("part")?.parse().ok()?,
+        answer: fields.get("answer")?.clone(),
+        duration: Duration::from_nanos(
+            fields.get("duration_ns")?.parse().ok()?,
+        ),
+    })
+}
+
+/// The answer cached under `key` by the executable `build`.
+pub fn lookup(cache: &[Cached], key: u64, build: u64) -> Option<&Cached> {
+    cache.iter().find(|c| c.key == key && c.build == build)
+}
+
+/// Add `cached` to `cache`, replacing an answer with the same key and
+/// dropping those of other builds, which can never be used again.
+pub fn insert(cache: &mut Vec<Cached>, cached: Cached) {
+    cache.retain(|c| c.build == cached.build && c.key != cached.key);
+    cache.push(cached);
+}
+
+pub fn save(path: &Path, cache: &[Cached]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let lines: String = cache
+        .iter()
+        .map(|c| {
+            format!(
+                "{{\"key\": \"{:016x}\", \"build\": \"{:016x}\", \
+                 \"day\": {}, \"part\": {}, \"answer\": {}, \
+                 \"duration_ns\": {}}}\n",
+                c.key,
+                c.build,
+                c.day,
+                c.part,
+                quote(&c.answer),
+                c.duration.as_nanos()
+            )
+        })
+        .collect();
+    fs::write(path, lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let input = "0,3,6\n";
+        let cached = |build, answer: &str| Cached {
+            key: key(2020, 15, 2, input, &[]),
+            build,
+            day: 15,
+            part: 2,
+            answer: answer.to_string(),
+            duration: Duration::from_millis(512),
+        };
+        assert_ne!(cached(1, "").key, key(2020, 15, 2, input, &["hashmap"]));
+        assert_ne!(cached(1, "").key, key(2020, 15, 2, "1,3,2\n", &[]));
+        assert_ne!(cached(1, "").key, key(2021, 15, 2, input, &[]));
+
+        let mut cache = Vec::new();
+        insert(&mut cache, cached(1, "175594"));
+        insert(&mut cache, cached(1, "\"quoted\""));
+        assert_eq!(cache, [cached(1, "\"quoted\"")]);
+        insert(&mut cache, cached(2, "175594"));
+        assert_eq!(cache, [cached(2, "175594")]);
+
+        let dir =
+            env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let path = dir.join("cache.jsonl");
+        save(&path, &cache).unwrap();
+        let loaded = load(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(loaded, cache);
+        let key = cache[0].key;
+        assert_eq!(lookup(&loaded, key, 2), Some(&cache[0]));
+        assert_eq!(lookup(&loaded, key, 1), None);
+        assert!(load(&path).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,163 @@
+//! Cancellation
+//!
+//! A `CancelToken` stops a solver that is taking too long: the runner
+//! cancels one on `--timeout` and on Ctrl-C, and the long loops of the
+//! solvers (day 15's turns, day 17's cycles, day 23's moves) call `check`
+//! now and then, which fails with `AocError::Cancelled` once it is. The
+//! token is current for the thread inside `with` rather than a parameter,
+//! so the `Solver` signatures stay as they are. Without the `std` feature
+//! no token is ever current and `check` always succeeds.
+
+use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::error::AocError;
+
+/// How many iterations a hot loop runs between two `check`s, a few
+/// milliseconds of work at most.
+pub const CHECK_EVERY: usize = 1 << 16;
+
+/// A flag shared by its clones, cancelled for good once set.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<Inner>);
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    parent: Option<CancelToken>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        CancelToken::default()
+    }
+
+    /// A token that is also cancelled with this one, e.g. one part's
+    /// token under the token of the whole run.
+    pub fn child(&self) -> Self {
+        CancelToken(Arc::new(Inner {
+            cancelled: AtomicBool::new(false),
+            parent: Some(self.clone()),
+        }))
+    }
+
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether this token or one of its parents is cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::Relaxed)
+            || self.0.parent.as_ref().is_some_and(Self::is_cancelled)
+    }
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> =
+        const { RefCell::new(None) };
+}
+
+/// Run `f` with `token` as the current token of this thread.
+#[cfg(feature = "std")]
+pub fn with<T>(token: &CancelToken, f: impl FnOnce() -> T) -> T {
+    /// Restores the previous token, also when `f` panics.
+    struct Restore(Option<CancelToken>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(CURRENT.replace(Some(token.clone())));
+    f()
+}
+
+/// `AocError::Cancelled` once the current token is cancelled.
+pub fn check() -> Result<(), AocError> {
+    #[cfg(feature = "std")]
+    if CURRENT.with_borrow(|token| {
+        token.as_ref().is_some_and(CancelToken::is_cancelled)
+    }) {
+        return Err(AocError::Cancelled);
+    }
+    Ok(())
+}
+
+/// Cancel `token` on the first Ctrl-C; the second one exits as usual.
+/// Unix only: elsewhere Ctrl-C always exits right away. The handler is
+/// declared by hand, like the limits of `sandbox`, to stay free of libc.
+#[cfg(all(feature = "std", unix))]
+pub fn cancel_on_interrupt(token: &CancelToken) {
+    use std::sync::OnceLock;
+
+    const SIGINT: i32 = 2;
+    static INTERRUPT: OnceLock<CancelToken> = OnceLock::new();
+
+    extern "C" {
+        fn signal(signum: i32, handler: extern "C" fn(i32)) -> usize;
+        fn _exit(status: i32) -> !;
+    }
+
+    // only atomic loads and stores, which are safe in a signal handler
+    extern "C" fn on_interrupt(_: i32) {
+        match INTERRUPT.get() {
+            Some(token) if !token.is_cancelled() => token.cancel(),
+            _ => unsafe { _exit(130) },
+        }
+    }
+
+    if INTERRUPT.set(token.clone()).is_ok() {
+        unsafe { signal(SIGINT, on_interrupt) };
+    }
+}
+
+/// Ctrl-C exits right away where `cancel_on_interrupt` is not supported.
+#[cfg(all(feature = "std", not(unix)))]
+pub fn cancel_on_interrupt(_: &CancelToken) {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn tokens() {
+        let run = CancelToken::new();
+        let part = run.child();
+        assert!(!part.is_cancelled());
+        run.cancel();
+        assert!(part.is_cancelled() && run.clone().is_cancelled());
+        assert!(run.child().child().is_cancelled());
+
+        assert!(check().is_ok());
+        let token = CancelToken::new();
+        with(&token, || {
+            assert!(check().is_ok());
+            token.cancel();
+            assert!(matches!(check(), Err(AocError::Cancelled)));
+            with(&CancelToken::new(), || assert!(check().is_ok()));
+            assert!(check().is_err());
+        });
+        assert!(check().is_ok());
+    }
+
+    #[test]
+    fn solvers() {
+        let token = CancelToken::new();
+        token.cancel();
+        for (day, part, input) in [
+            (15, 2, "0,3,6\n"),
+            (17, 1, ".#.\n..#\n###\n"),
+            (17, 2, ".#.\n..#\n###\n"),
+            (23, 2, "389125467\n"),
+        ] {
+            let puzzle = registry::find(2020, day).unwrap();
+            let solved = with(&token, || puzzle.solve(part, input));
+            assert!(matches!(solved, Err(AocError::Cancelled)), "day {day}");
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Circular Lists
+//!
+//! The cup circle of day 23: an `IndexList` is a singly linked circle of
+//! nodes numbered below its capacity, with the node after each in one flat
+//! array, `next[node]`, so inserting or removing after a node is two
+//! writes and no allocation, however large the circle. A cursor marks the
+//! current node; `advance` moves it clockwise, and `iter` goes once round
+//! the circle from it. Nodes not in the circle cost a slot each, so the
+//! numbers should be dense, like the labels of the cups.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The `next` of a node not in the circle.
+const ABSENT: u32 = u32::MAX;
+
+/// A circle of distinct nodes below `u32::MAX`, linked through an array.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexList {
+    /// The node after each node, `ABSENT` for those not in the circle.
+    next: Vec<u32>,
+    len: usize,
+    cursor: Option<usize>,
+}
+
+impl IndexList {
+    /// An empty circle of nodes below `capacity`.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity <= ABSENT as usize, "a capacity of {capacity}");
+        IndexList {
+            next: vec![ABSENT; capacity],
+            len: 0,
+            cursor: None,
+        }
+    }
+
+    /// The circle of `order`, each node followed by the next and the last
+    /// by the first, which is the cursor. Panics on a repeated node.
+    pub fn from_order(order: impl IntoIterator<Item = usize>) -> Self {
+        let order: Vec<usize> = order.into_iter().collect();
+        let capacity = order.iter().max().map_or(0, |&max| max + 1);
+        let mut list = IndexList::new(capacity);
+        for (i, &node) in order.iter().enumerate() {
+            assert!(!list.contains(node), "{node} is in the circle twice");
+            list.next[node] = order[(i + 1) % order.len()] as u32;
+        }
+        list.len = order.len();
+        list.cursor = order.first().copied();
+        list
+    }
+
+    /// One more than the largest node the circle can hold.
+    pub fn capacity(&self) -> usize {
+        self.next.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn contains(&self, node: usize) -> bool {
+        self.next.get(node).is_some_and(|&next| next != ABSENT)
+    }
+
+    /// The node after `node`; panics unless it is in the circle.
+    pub fn next(&self, node: usize) -> usize {
+        assert!(self.contains(node), "{node} is not in the circle");
+        self.next[node] as usize
+    }
+
+    /// The current node, none for an empty circle.
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// Make `node` the current one; panics unless it is in the circle.
+    pub fn set_cursor(&mut self, node: usize) {
+        assert!(self.contains(node), "{node} is not in the circle");
+        self.cursor = Some(node);
+    }
+
+    /// Move the cursor `steps` nodes clockwise.
+    pub fn advance(&mut self, steps: usize) {
+        if let Some(mut node) = self.cursor {
+            for _ in 0..steps % self.len {
+                node = self.next[node] as usize;
+            }
+            self.cursor = Some(node);
+        }
+    }
+
+    /// Put `node` after `at`; panics unless `at` is in the circle and
+    /// `node` is not.
+    pub fn insert_after(&mut self, at: usize, node: usize) {
+        assert!(self.contains(at), "{at} is not in the circle");
+        assert!(
+            node < self.capacity() && !self.contains(node),
+            "{node} cannot join the circle"
+        );
+        self.next[node] = self.next[at];
+        self.next[at] = node as u32;
+        self.len += 1;
+    }
+
+    /// Put `node` after the cursor, or start an empty circle with it as
+    /// the cursor.
+    pub fn insert(&mut self, node: usize) {
+        match self.cursor {
+            Some(cursor) => self.insert_after(cursor, node),
+            None => {
+                assert!(
+                    node < self.capacity(),
+                    "{node} cannot join the circle"
+                );
+                self.next[node] = node as u32;
+                self.len = 1;
+                self.cursor = Some(node);
+            }
+        }
+    }
+
+    /// Take out the node after `at`, which may be `at` itself if it is
+    /// alone, and return it. A removed cursor passes to the node after it.
+    pub fn remove_after(&mut self, at: usize) -> usize {
+        let node = self.next(at);
+        self.next[at] = self.next[node];
+        self.next[node] = ABSENT;
+        self.len -= 1;
+        if self.cursor == Some(node) {
+            self.cursor = (self.len > 0).then(|| self.next[at] as usize);
+        }
+        node
+    }
+
+    /// Once round the circle from `node`; panics unless it is in it.
+    pub fn iter_from(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        let first = self.contains(node).then_some(node);
+        assert!(first.is_some(), "{node} is not in the circle");
+        core::iter::successors(first, |&node| Some(self.next[node] as usize))
+            .take(self.len)
+    }
+
+    /// Once round the circle from the cursor.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        let first = self.cursor;
+        core::iter::successors(first, |&node| Some(self.next[node] as usize))
+            .take(self.len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::VecDeque;
+    use proptest::prelude::*;
+
+    #[test]
+    fn circle() {
+        let mut list = IndexList::from_order([3, 8, 9, 1, 2]);
+        assert_eq!((list.len(), list.capacity()), (5, 10));
+        assert_eq!(list.cursor(), Some(3));
+        assert_eq!(list.next(2), 3);
+        assert!(list.contains(9) && !list.contains(4) && !list.contains(10));
+
+        assert_eq!(list.remove_after(3), 8);
+        list.insert_after(2, 8);
+        list.advance(6);
+        assert_eq!(list.iter().collect::<Vec<_>>(), [9, 1, 2, 8, 3]);
+        assert_eq!(list.iter_from(2).collect::<Vec<_>>(), [2, 8, 3, 9, 1]);
+
+        // removing the cursor passes it on, and the last node empties it
+        let mut list = IndexList::from_order([0, 1]);
+        assert_eq!(list.remove_after(1), 0);
+        assert_eq!(list.cursor(), Some(1));
+        assert_eq!(list.remove_after(1), 1);
+        assert!(list.is_empty() && list.cursor().is_none());
+        list.insert(0);
+        assert_eq!(list.iter().collect::<Vec<_>>(), [0]);
+        assert_eq!(IndexList::new(0).iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot join the circle")]
+    fn twice() {
+        IndexList::from_order([0, 1]).insert_after(0, 1);
+    }
+
+    proptest! {
+        /// Every operation agrees with a `VecDeque` with the cursor in
+        /// front.
+        #[test]
+        fn model(ops in prop::collection::vec(
+            (0..5u8, 0..64usize, 0..24usize),
+            0..64,
+        )) {
+            let mut list = IndexList::new(24);
+            let mut model: VecDeque<usize> = VecDeque::new();
+            for (op, pick, node) in ops {
+                let at =
+                    (!model.is_empty()).then(|| model[pick % model.len()]);
+                let position = |model: &VecDeque<usize>, node| {
+                    model.iter().position(|&n| n == node).unwrap()
+                };
+                match (op, at) {
+                    (0, _) if !model.contains(&node) => {
+                        list.insert(node);
+                        let i = model.len().min(1);
+                        model.insert(i, node);
+                    }
+                    (1, Some(at)) if !model.contains(&node) => {
+                        list.insert_after(at, node);
+                        let i = position(&model, at) + 1;
+                        model.insert(i, node);
+                    }
+                    (2, Some(at)) => {
+                        let i = (position(&model, at) + 1) % model.len();
+                        prop_assert_eq!(list.remove_after(at), model[i]);
+                        model.remove(i);
+                    }
+                    (3, Some(_)) => {
+                        list.advance(pick);
+                        model.rotate_left(pick % model.len());
+                    }
+                    (4, Some(at)) => {
+                        list.set_cursor(at);
+                        let i = position(&model, at);
+                        model.rotate_left(i);
+                    }
+                    _ => continue,
+                }
+                prop_assert_eq!(list.len(), model.len());
+                prop_assert_eq!(list.cursor(), model.front().copied());
+                prop_assert!(list.iter().eq(model.iter().copied()));
+                prop_assert!(
+                    (0..24).all(|n| list.contains(n) == model.contains(&n))
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Hash Collections
+//!
+//! `FastMap` and `FastSet` are the hash maps and sets the day modules use.
+//! By default they are the std collections with SipHash, which resists
+//! hash flooding but is slow for the small integer and tuple keys most
+//! puzzles hash. The `fast-hash` feature switches them to FxHash.
+//!
+//! Build them with `default()`, `collect()` or `with_capacity_and_hasher`,
+//! since `new()` and `with_capacity()` only exist for SipHash.
+//!
+//! Measured with `--time` on the real inputs (release build):
+//!
+//! | Day | Part | SipHash | FxHash |
+//! |-----|------|---------|--------|
+//! | 7   | 1    | 58ms    | 22ms   |
+//! | 14  | 2    | 12.4ms  | 9.0ms  |
+//! | 17  | 1    | 16ms    | 3.8ms  |
+//! | 17  | 2    | 637ms   | 115ms  |
+//! | 21  | 2    | 1.6ms   | 0.7ms  |
+//! | 22  | 2    | 4.19s   | 1.49s  |
+//! | 24  | 2    | 137ms   | 50ms   |
+
+use std::collections::{HashMap, HashSet};
+
+#[cfg(feature = "fast-hash")]
+pub type BuildHasher = rustc_hash::FxBuildHasher;
+#[cfg(not(feature = "fast-hash"))]
+pub type BuildHasher = std::collections::hash_map::RandomState;
+
+pub type FastMap<K, V> = HashMap<K, V, BuildHasher>;
+pub type FastSet<T> = HashSet<T, BuildHasher>;
@@ -0,0 +1,184 @@
+//! Configuration File
+//!
+//! Defaults for the runner read from `aoc.toml` in the working directory,
+//! or the file given with `--config`. Flags on the command line and
+//! environment variables such as `AOC_DATA_DIR` override them. All keys are
+//! optional:
+//!
+//! ```toml
+//! data_dir = "inputs"        # where NN-input.txt and NN-example.txt live
+//! year = 2020                # the event whose puzzles to solve
+//! session_file = ".aoc/session"
+//! format = "markdown"        # or "text"
+//! time = true                # as if --time were given
+//! days = "1-14,!9"           # when no days are given, also [1, 2, 3]
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const DEFAULT_PATH: &str = "aoc.toml";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    pub data_dir: Option<String>,
+    pub year: Option<u16>,
+    pub session_file: Option<String>,
+    pub format: Option<String>,
+    pub time: Option<bool>,
+    /// Day selectors, as on the command line.
+    pub days: Option<Vec<String>>,
+}
+
+/// Load the configuration at `path`, which may not exist.
+pub fn load(path: &Path) -> io::Result<Config> {
+    match fs::read_to_string(path) {
+        Ok(content) => parse(&content).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {e}", path.display()),
+            )
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            Ok(Config::default())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Parse the `key = value` lines of a configuration.
+pub fn parse(content: &str) -> Result<Config, String> {
+    let mut config = Config::default();
+    for (i, line) in content.lines().enumerate() {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        let error = |message: &str| format!("line {}: {message}", i + 1);
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error("expected `key = value`"))?;
+        let value = value.trim();
+        match key.trim() {
+            "data_dir" => {
+                config.data_dir = Some(
+                    string(value)
+                        .ok_or_else(|| error("data_dir must be a string"))?,
+                )
+            }
+            "year" => {
+                config.year = Some(
+                    value
+                        .parse()
+                        .map_err(|_| error("year must be a number"))?,
+                )
+            }
+            "session_file" => {
+                config.session_file =
+                    Some(string(value).ok_or_else(|| {
+                        error("session_file must be a string")
+                    })?)
+            }
+            "format" => {
+                let format = string(value)
+                    .ok_or_else(|| error("format must be a string"))?;
+                if !matches!(format.as_str(), "text" | "markdown") {
+                    return Err(error(
+                        "format must be \"text\" or \"markdown\"",
+                    ));
+                }
+                config.format = Some(format);
+            }
+            "time" => {
+                config.time = Some(match value {
+                    "true" => true,
+                    "false" => false,
+                    _ => return Err(error("time must be true or false")),
+                })
+            }
+            "days" => {
+                let days = match value
+                    .strip_prefix('[')
+                    .and_then(|v| v.strip_suffix(']'))
+                {
+                    Some(list) => list
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|v| !v.is_empty())
+                        .map(|v| string(v).unwrap_or_else(|| v.to_string()))
+                        .collect(),
+                    None => vec![string(value).ok_or_else(|| {
+                        error("days must be a string or a list")
+                    })?],
+                };
+                config.days = Some(days);
+            }
+            key => return Err(error(&format!("unknown key `{key}`"))),
+        }
+    }
+    Ok(config)
+}
+
+/// The contents of a basic string without escapes.
+fn string(value: &str) -> Option<String> {
+    let inner = value.strip_prefix('"')?.strip_suffix('"')?;
+    (!inner.contains(['"', '\\'])).then(|| inner.to_string())
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys() {
+        let config = parse(concat!(
+            "# defaults\n",
+            "data_dir = \"data/#2020\"  # comment\n",
+            "year = 2020\n",
+            "session_file = \"~/.aoc-session\"\n",
+            "format = \"markdown\"\n",
+            "time = true\n",
+            "days = [1, 2, \"5-7\"]\n",
+        ))
+        .unwrap();
+        assert_eq!(config.data_dir.as_deref(), Some("data/#2020"));
+        assert_eq!(config.year, Some(2020));
+        assert_eq!(config.session_file.as_deref(), Some("~/.aoc-session"));
+        assert_eq!(config.format.as_deref(), Some("markdown"));
+        assert_eq!(config.time, Some(true));
+        assert_eq!(config.days.unwrap(), ["1", "2", "5-7"]);
+
+        let config = parse("days = \"1-14,!9\"").unwrap();
+        assert_eq!(config.days.unwrap(), ["1-14,!9"]);
+        assert_eq!(parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse("color = true"),
+            Err("line 1: unknown key `color`".to_string())
+        );
+        assert!(parse("\ntime = yes").unwrap_err().starts_with("line 2: "));
+        assert!(parse("format = \"html\"").is_err());
+        assert!(parse("data_dir").is_err());
+        assert!(parse("year = \"2020\"").is_err());
+        assert_eq!(
+            load(Path::new("missing/aoc.toml")).unwrap(),
+            Config::default()
+        );
+    }
+}
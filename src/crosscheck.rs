@@ -0,0 +1,83 @@
+//! Reference Cross-Check
+//!
+//! Runs an external reference solution, e.g. one written in another
+//! language, for each day and part and compares its output with ours.
+//!
+//! The command is a template split on whitespace, in which `{day}`,
+//! `{part}` and `{input}` (the path of the input file) are substituted.
+//! The input is also written to the command's stdin, and the trimmed stdout
+//! is taken as its answer:
+//!
+//! ```text
+//! python3 ref/solve.py {day} {part} {input}
+//! ```
+
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+/// The program and arguments of `template` for one day and part.
+pub fn expand(template: &str, day: u8, part: u8, path: &str) -> Vec<String> {
+    template
+        .split_whitespace()
+        .map(|arg| {
+            arg.replace("{day}", &day.to_string())
+                .replace("{part}", &part.to_string())
+                .replace("{input}", path)
+        })
+        .collect()
+}
+
+/// Run the reference command and return its trimmed stdout.
+pub fn reference_answer(
+    template: &str,
+    day: u8,
+    part: u8,
+    path: &str,
+    input: &str,
+) -> io::Result<String> {
+    let args = expand(template, day, part, path);
+    let (program, args) = args.split_first().ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, "empty command")
+    })?;
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    // the command may not read its stdin at all
+    let _ = child.stdin.take().unwrap().write_all(input.as_bytes());
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "{program} exited with {}",
+            output.status
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn template() {
+        assert_eq!(
+            expand("python3 solve.py {day} {part} {input}", 7, 2, "in.txt"),
+            ["python3", "solve.py", "7", "2", "in.txt"]
+        );
+        assert_eq!(expand("ref --day={day}", 25, 1, ""), ["ref", "--day=25"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reference() {
+        assert_eq!(reference_answer("cat", 1, 1, "", "42\n").unwrap(), "42");
+        assert_eq!(
+            reference_answer("echo {day}-{part}", 3, 2, "", "").unwrap(),
+            "3-2"
+        );
+        assert!(reference_answer("false", 1, 1, "", "").is_err());
+        assert!(reference_answer("", 1, 1, "", "").is_err());
+    }
+}
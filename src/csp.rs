@@ -0,0 +1,142 @@
+//! Constraint Satisfaction
+//!
+//! The assignments of days 16 and 21, each field to a position of the
+//! tickets and each allergen to an ingredient: every key gets one of its
+//! candidate values, no two the same. `assign_unique` first eliminates,
+//! taking the value of a key with one candidate left from every other key
+//! until nothing changes, which settles the puzzles alone, then falls back
+//! to backtracking on the key with the fewest candidates, eliminating
+//! again after each guess. Maps are `BTreeMap`s, so the search is the same
+//! from run to run and the answer comes out in order of key.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+
+use crate::cancel;
+use crate::error::AocError;
+
+/// A value for every key of `candidates`, one of its own and no two the
+/// same, failing with `AocError::NoSolution` if there is none. With more
+/// than one, the first found.
+pub fn assign_unique<K: Ord, V: Ord + Clone>(
+    candidates: BTreeMap<K, BTreeSet<V>>,
+) -> Result<BTreeMap<K, V>, AocError> {
+    let (keys, domains): (Vec<K>, Vec<BTreeSet<V>>) =
+        candidates.into_iter().unzip();
+    let values = search(domains)?.ok_or_else(|| {
+        AocError::no_solution("no assignment gives every key its own value")
+    })?;
+    Ok(keys.into_iter().zip(values).collect())
+}
+
+/// The value of every domain, none if they contradict each other.
+fn search<V: Ord + Clone>(
+    mut domains: Vec<BTreeSet<V>>,
+) -> Result<Option<Vec<V>>, AocError> {
+    cancel::check()?;
+    if !eliminate(&mut domains) {
+        return Ok(None);
+    }
+    // guess at the unsettled domain with the fewest values
+    let Some(i) = (0..domains.len())
+        .filter(|&i| domains[i].len() > 1)
+        .min_by_key(|&i| domains[i].len())
+    else {
+        return Ok(Some(
+            domains
+                .into_iter()
+                .filter_map(|d| d.into_iter().next())
+                .collect(),
+        ));
+    };
+    for value in domains[i].clone() {
+        let mut guess = domains.clone();
+        guess[i] = BTreeSet::from([value]);
+        if let Some(values) = search(guess)? {
+            return Ok(Some(values));
+        }
+    }
+    Ok(None)
+}
+
+/// Take the value of every domain with one left from all the others,
+/// until nothing changes; false once a domain is empty.
+fn eliminate<V: Ord + Clone>(domains: &mut [BTreeSet<V>]) -> bool {
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for i in 0..domains.len() {
+            let value = match domains[i].len() {
+                0 => return false,
+                1 => domains[i].first().unwrap().clone(),
+                _ => continue,
+            };
+            for (j, domain) in domains.iter_mut().enumerate() {
+                if j != i && domain.remove(&value) {
+                    if domain.is_empty() {
+                        return false;
+                    }
+                    changed = true;
+                }
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(domains: &[(char, &[u8])]) -> BTreeMap<char, BTreeSet<u8>> {
+        domains
+            .iter()
+            .map(|&(key, values)| (key, values.iter().copied().collect()))
+            .collect()
+    }
+
+    #[test]
+    fn elimination() {
+        // day 16's example: row, class and seat
+        let assigned = assign_unique(candidates(&[
+            ('a', &[1]),
+            ('b', &[0, 1]),
+            ('c', &[0, 1, 2]),
+        ]))
+        .unwrap();
+        assert_eq!(assigned, BTreeMap::from([('a', 1), ('b', 0), ('c', 2)]));
+        assert_eq!(assign_unique(candidates(&[])).unwrap(), BTreeMap::new());
+    }
+
+    #[test]
+    fn backtracking() {
+        // no key is settled to begin with, and `a` = 1 settles `b` = 3,
+        // `d` = 4 and then `c` = 4 too
+        let domains = candidates(&[
+            ('a', &[1, 2]),
+            ('b', &[1, 3]),
+            ('c', &[3, 4]),
+            ('d', &[4, 1]),
+        ]);
+        let assigned = assign_unique(domains).unwrap();
+        assert_eq!(
+            assigned,
+            BTreeMap::from([('a', 2), ('b', 1), ('c', 3), ('d', 4)])
+        );
+    }
+
+    #[test]
+    fn contradictions() {
+        let error = assign_unique(candidates(&[('a', &[1]), ('b', &[1])]))
+            .unwrap_err();
+        assert!(matches!(error, AocError::NoSolution(_)));
+        // three keys share two values, found only by guessing
+        let error = assign_unique(candidates(&[
+            ('a', &[1, 2]),
+            ('b', &[1, 2]),
+            ('c', &[1, 2]),
+        ]))
+        .unwrap_err();
+        assert!(matches!(error, AocError::NoSolution(_)));
+    }
+}
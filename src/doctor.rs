@@ -0,0 +1,152 @@
+//! Environment Checks
+//!
+//! Diagnostics for `aoc doctor`, meant for first-time setup on a new
+//! machine. Each check reports what it found and, when something is wrong,
+//! how to fix it:
+//!
+//! - **Inputs**: every day has its puzzle input and the example files its
+//!   parts run against with `--example`, including alternates such as
+//!   `inputs/14-example-2.txt`
+//! - **Session**: the `session=...` cookie used to download inputs is
+//!   accepted by adventofcode.com
+//! - **Directories**: the input and local state directories can be written
+
+use std::fs;
+use std::path::Path;
+
+/// The outcome of one check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub ok: bool,
+    pub summary: String,
+    /// What to do about a failed check.
+    pub fix: Option<String>,
+}
+
+impl Check {
+    fn ok(summary: String) -> Check {
+        Check {
+            ok: true,
+            summary,
+            fix: None,
+        }
+    }
+
+    fn failed(summary: String, fix: String) -> Check {
+        Check {
+            ok: false,
+            summary,
+            fix: Some(fix),
+        }
+    }
+}
+
+/// One check per day for the files in `dir` that its parts read.
+pub fn input_files(dir: &Path, days: u8) -> Vec<Check> {
+    (1..=days)
+        .map(|day| {
+            let files = crate::expected::files(day);
+            let missing: Vec<String> = files
+                .iter()
+                .map(|file| dir.join(format!("{day:02}-{file}.txt")))
+                .filter(|path| !path.is_file())
+                .map(|path| path.display().to_string())
+                .collect();
+            if missing.is_empty() {
+                return Check::ok(format!(
+                    "Day {day:02}: {}",
+                    files.join(", ")
+                ));
+            }
+            let fix = if missing.iter().any(|p| p.ends_with("-input.txt")) {
+                format!(
+                    "run `aoc fetch {day}` or save {}",
+                    crate::input_url(day)
+                )
+            } else {
+                "copy the example from the puzzle text".to_string()
+            };
+            Check::failed(
+                format!("Day {day:02}: missing {}", missing.join(", ")),
+                fix,
+            )
+        })
+        .collect()
+}
+
+/// Whether adventofcode.com accepts `cookie` (such as `session=...`) by
+/// downloading the input of day 1 with it.
+pub fn session(cookie: Option<&str>) -> Check {
+    const FIX: &str = "copy the `session` cookie of adventofcode.com from \
+                       the browser and set AOC_COOKIE=session=<token>, or \
+                       save the token in .aoc/session";
+    let Some(cookie) = cookie.and_then(crate::cookie_header) else {
+        return Check::failed("Session: no cookie".to_string(), FIX.into());
+    };
+    match crate::http::get(&crate::input_url(1), &[("Cookie", &cookie)]) {
+        Ok(_) => Check::ok("Session: accepted by adventofcode.com".into()),
+        Err(e) => Check::failed(format!("Session: {e}"), FIX.into()),
+    }
+}
+
+/// Whether files can be created in `dir`, creating it if needed.
+pub fn writable(dir: &Path) -> Check {
+    let probe = dir.join(".doctor");
+    let result = fs::create_dir_all(dir)
+        .and_then(|_| fs::write(&probe, ""))
+        .and_then(|_| fs::remove_file(&probe));
+    match result {
+        Ok(()) => Check::ok(format!("{}: writable", dir.display())),
+        Err(e) => Check::failed(
+            format!("{}: {e}", dir.display()),
+            format!(
+                "run from the repository root, or fix the permissions of {}",
+                dir.display()
+            ),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn files() {
+        let checks = input_files(Path::new("inputs"), 25);
+        assert_eq!(checks.len(), 25);
+        assert!(checks.iter().all(|c| c.ok));
+        assert_eq!(checks[13].summary, "Day 14: input, example, example-2");
+
+        let dir = std::env::temp_dir().join("aoc-doctor-files");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("14-input.txt"), "").unwrap();
+        fs::write(dir.join("14-example.txt"), "").unwrap();
+        let checks = input_files(&dir, 14);
+        assert!(!checks[0].ok);
+        assert!(checks[0].fix.as_ref().unwrap().contains("aoc fetch 1"));
+        assert!(!checks[13].ok);
+        assert!(checks[13].summary.ends_with("14-example-2.txt"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn directories() {
+        let dir = std::env::temp_dir().join("aoc-doctor-writable");
+        assert!(writable(&dir).ok);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let file = std::env::temp_dir().join("aoc-doctor-file");
+        fs::write(&file, "").unwrap();
+        assert!(!writable(&file.join("dir")).ok);
+        fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn missing_session() {
+        let check = session(None);
+        assert!(!check.ok);
+        assert!(check.fix.unwrap().contains("AOC_COOKIE"));
+    }
+}
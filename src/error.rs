@@ -0,0 +1,147 @@
+//! Errors
+//!
+//! `AocError` is what reading, parsing and solving a puzzle input can fail
+//! with, so the runner can point at the offending line of a malformed
+//! input instead of panicking.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::io;
+
+#[derive(Debug)]
+pub enum AocError {
+    /// An input file in none of the places it was looked for.
+    Missing { paths: Vec<String> },
+    /// An input file that cannot be read.
+    #[cfg(feature = "std")]
+    Io { path: String, source: io::Error },
+    /// A malformed input, at a line counted from 1.
+    Parse { line: usize, message: String },
+    /// An input without an answer, e.g. no entries summing to 2020.
+    NoSolution(String),
+    /// A solver stopped by its `CancelToken`.
+    Cancelled,
+    /// A day without a registered puzzle, e.g. day 26.
+    UnknownPuzzle { year: u16, day: u8 },
+    /// A part other than 1 or 2.
+    UnknownPart(u8),
+}
+
+impl AocError {
+    /// A parse error at the line of `fragment`, which must be a slice of
+    /// `input`, as the lines and paragraphs of a day's parser are.
+    pub fn at(
+        input: &str,
+        fragment: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        AocError::Parse {
+            line: line_of(input, fragment),
+            message: message.into(),
+        }
+    }
+
+    /// A parse error of `fragment` parsed on its own, e.g. by a `FromStr`,
+    /// moved to the line of `fragment` in `input`.
+    pub fn within(self, input: &str, fragment: &str) -> Self {
+        match self {
+            AocError::Parse { line, message } => AocError::Parse {
+                line: line_of(input, fragment) + line - 1,
+                message,
+            },
+            error => error,
+        }
+    }
+
+    /// A missing answer, e.g. `no two entries sum to 2020`.
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        AocError::NoSolution(message.into())
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Missing { paths } => {
+                write!(f, "expected file at {}", paths.join(" or "))
+            }
+            #[cfg(feature = "std")]
+            AocError::Io { path, source } => write!(f, "{path}: {source}"),
+            AocError::Parse { line, message } => {
+                write!(f, "line {line}: {message}")
+            }
+            AocError::NoSolution(message) => {
+                write!(f, "no solution: {message}")
+            }
+            AocError::Cancelled => write!(f, "cancelled"),
+            AocError::UnknownPuzzle { year, day } => {
+                write!(f, "no puzzle for day {day} of {year}")
+            }
+            AocError::UnknownPart(part) => write!(f, "no part {part}"),
+        }
+    }
+}
+
+impl core::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The line of `fragment` in `input`, counted from 1.
+fn line_of(input: &str, fragment: &str) -> usize {
+    let offset = (fragment.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|&offset| offset <= input.len())
+        .unwrap_or(0);
+    input.as_bytes()[..offset]
+        .iter()
+        .filter(|&&b| b == b'\n')
+        .count()
+        + 1
+}
+
+/// `s`, a slice of `input`, as a number, or an error at its line.
+pub fn number<T: FromStr>(input: &str, s: &str) -> Result<T, AocError> {
+    s.trim()
+        .parse()
+        .map_err(|_| AocError::at(input, s, format!("invalid number `{s}`")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn line_numbers() {
+        let input = "1\n2\nx\n";
+        let line = input.lines().nth(2).unwrap();
+        let error = AocError::at(input, line, "invalid");
+        assert_eq!(error.to_string(), "line 3: invalid");
+        assert_eq!(
+            number::<u32>(input, line).unwrap_err().to_string(),
+            "line 3: invalid number `x`"
+        );
+        assert_eq!(number::<u32>(input, "2").unwrap(), 2);
+        let fragment = &input[2..];
+        let error = AocError::at(fragment, &fragment[2..3], "invalid");
+        assert_eq!(
+            error.within(input, fragment).to_string(),
+            "line 3: invalid"
+        );
+        let error = AocError::no_solution("no two entries sum to 2020");
+        assert_eq!(
+            error.to_string(),
+            "no solution: no two entries sum to 2020"
+        );
+    }
+}
@@ -0,0 +1,117 @@
+//! Embedded Example Inputs
+//!
+//! With the `embedded-examples` feature the example inputs are compiled into
+//! the library, so tests keep working when `inputs/` is not reachable from
+//! the working directory. `read_as_string` falls back to these copies only
+//! when the file itself is missing. `embed-inputs` adds the real inputs, so
+//! a release binary copied to another machine solves every day without an
+//! `inputs/` directory.
+
+macro_rules! examples {
+    ($const:ident $($day:literal: $($name:literal => $file:literal),*;)*) => {
+        const $const: &[(u8, &str, &str)] = &[
+            $($(
+                ($day, $name, include_str!(concat!("../inputs/", $file))),
+            )*)*
+        ];
+    };
+}
+
+examples! {
+    EXAMPLES
+    1: "example" => "01-example.txt";
+    2: "example" => "02-example.txt";
+    3: "example" => "03-example.txt";
+    4: "example" => "04-example.txt";
+    5: "example" => "05-example.txt";
+    6: "example" => "06-example.txt";
+    7: "example" => "07-example.txt";
+    8: "example" => "08-example.txt";
+    9: "example" => "09-example.txt";
+    10: "example" => "10-example.txt";
+    11: "example" => "11-example.txt";
+    12: "example" => "12-example.txt";
+    13: "example" => "13-example.txt";
+    14: "example" => "14-example.txt", "example-2" => "14-example-2.txt";
+    15: "example" => "15-example.txt";
+    16: "example" => "16-example.txt";
+    17: "example" => "17-example.txt";
+    18: "example" => "18-example.txt";
+    19: "example" => "19-example.txt", "example-2" => "19-example-2.txt";
+    20: "example" => "20-example.txt";
+    21: "example" => "21-example.txt";
+    22: "example" => "22-example.txt";
+    23: "example" => "23-example.txt";
+    24: "example" => "24-example.txt";
+    25: "example" => "25-example.txt";
+}
+
+#[cfg(feature = "embed-inputs")]
+examples! {
+    INPUTS
+    1: "input" => "01-input.txt";
+    2: "input" => "02-input.txt";
+    3: "input" => "03-input.txt";
+    4: "input" => "04-input.txt";
+    5: "input" => "05-input.txt";
+    6: "input" => "06-input.txt";
+    7: "input" => "07-input.txt";
+    8: "input" => "08-input.txt";
+    9: "input" => "09-input.txt";
+    10: "input" => "10-input.txt";
+    11: "input" => "11-input.txt";
+    12: "input" => "12-input.txt";
+    13: "input" => "13-input.txt";
+    14: "input" => "14-input.txt";
+    15: "input" => "15-input.txt";
+    16: "input" => "16-input.txt";
+    17: "input" => "17-input.txt";
+    18: "input" => "18-input.txt";
+    19: "input" => "19-input.txt";
+    20: "input" => "20-input.txt";
+    21: "input" => "21-input.txt";
+    22: "input" => "22-input.txt";
+    23: "input" => "23-input.txt";
+    24: "input" => "24-input.txt";
+    25: "input" => "25-input.txt";
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+const INPUTS: &[(u8, &str, &str)] = &[];
+
+/// The embedded copy of `inputs/{day:02}-{filename}.txt`, if any.
+pub fn embedded(day: u8, filename: &str) -> Option<&'static str> {
+    EXAMPLES
+        .iter()
+        .chain(INPUTS)
+        .find(|&&(d, name, _)| d == day && name == filename)
+        .map(|&(_, _, input)| input)
+}
+
+/// The names of the embedded inputs of `day`, e.g. `example-2`.
+pub fn filenames(day: u8) -> Vec<String> {
+    EXAMPLES
+        .iter()
+        .chain(INPUTS)
+        .filter(|&&(d, _, _)| d == day)
+        .map(|&(_, name, _)| name.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn matches_files() {
+        for &(day, name, input) in EXAMPLES.iter().chain(INPUTS) {
+            let path = format!("inputs/{day:02}-{name}.txt");
+            assert_eq!(fs::read_to_string(path).unwrap(), input);
+        }
+        assert!(embedded(14, "example-2").is_some());
+        assert!(filenames(19).contains(&"example-2".to_string()));
+        let input = embedded(14, "input");
+        assert_eq!(input.is_some(), cfg!(feature = "embed-inputs"));
+    }
+}
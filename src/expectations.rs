@@ -0,0 +1,145 @@
+//! Runtime Expectations
+//!
+//! The complexity class of each part and the runtime range it typically
+//! falls in for a real input, measured on a release build on a desktop
+//! machine. The ranges are deliberately wide: a part outside its range
+//! usually means a wrong input or a parsing problem rather than a slow
+//! machine, e.g. day 1 taking seconds.
+//!
+//! `n` is the number of input lines unless noted otherwise.
+
+use std::time::Duration;
+
+use crate::registry::DEFAULT_YEAR;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Expectation {
+    pub complexity: &'static str,
+    /// Typical runtime range, in microseconds.
+    pub typical: (u64, u64),
+}
+
+impl Expectation {
+    pub fn min(&self) -> Duration {
+        Duration::from_micros(self.typical.0)
+    }
+
+    pub fn max(&self) -> Duration {
+        Duration::from_micros(self.typical.1)
+    }
+
+    /// Whether `duration` lies within the typical range.
+    pub fn contains(&self, duration: Duration) -> bool {
+        (self.min()..=self.max()).contains(&duration)
+    }
+}
+
+macro_rules! expectations {
+    ($($day:literal => $(($c:literal, $lo:literal..$hi:literal)),*;)*) => {
+        static EXPECTATIONS: &[(u8, [Expectation; 2])] = &[$(
+            ($day, [$(Expectation {
+                complexity: $c,
+                typical: ($lo, $hi),
+            }),*]),
+        )*];
+    };
+}
+
+expectations! {
+    1 => ("O(n²)", 1..5_000), ("O(n³)", 10..100_000);
+    2 => ("O(n)", 10..20_000), ("O(n)", 10..20_000);
+    3 => ("O(n)", 5..5_000), ("O(n)", 5..5_000);
+    4 => ("O(n)", 10..20_000), ("O(n)", 10..20_000);
+    5 => ("O(n)", 5..5_000), ("O(n log n)", 5..5_000);
+    6 => ("O(n)", 10..10_000), ("O(n)", 10..10_000);
+    7 => ("O(n²)", 1_000..2_000_000), ("O(n)", 30..50_000);
+    8 => ("O(n)", 5..5_000), ("O(n²)", 10..20_000);
+    9 => ("O(n·p²), p = preamble", 5..10_000), ("O(n)", 5..10_000);
+    10 => ("O(n log n)", 1..1_000), ("O(n log n)", 1..1_000);
+    11 => ("O(g·s), g = generations, s = seats", 500..1_000_000),
+        ("O(g·s·w), w = grid width", 1_000..2_000_000);
+    12 => ("O(n)", 2..3_000), ("O(n)", 2..3_000);
+    13 => ("O(b), b = buses", 0..500), ("O(b·id)", 0..500);
+    14 => ("O(n)", 10..10_000),
+        ("O(n·2^x), x = floating bits", 500..1_000_000);
+    15 => ("O(t), t = turns", 1..2_000),
+        ("O(t), t = turns", 100_000..20_000_000);
+    16 => ("O(v·f), v = values, f = fields", 10..20_000),
+        ("O(v·f + f²)", 30..50_000);
+    17 => ("O(c·3³), c = cubes", 500..1_000_000),
+        ("O(c·3⁴), c = cubes", 20_000..20_000_000);
+    18 => ("O(n)", 20..40_000), ("O(n)", 20..40_000);
+    19 => ("O(m·l²), m = messages, l = length", 500..1_000_000),
+        ("O(m·l²), m = messages, l = length", 3_000..5_000_000);
+    20 => ("O(t²), t = tiles", 20..40_000),
+        ("O(t² + p), p = pixels", 10_000..10_000_000);
+    21 => ("O(f·i), f = foods, i = ingredients", 30..60_000),
+        ("O(f·i + a²), a = allergens", 30..60_000);
+    22 => ("O(r), r = rounds", 1..2_000),
+        ("O(r·g), g = games", 100_000..30_000_000);
+    23 => ("O(m·c), m = moves, c = cups", 1..5_000),
+        ("O(m), m = moves", 50_000..20_000_000);
+    24 => ("O(n)", 30..60_000),
+        ("O(d·t), d = days, t = tiles", 5_000..5_000_000);
+    25 => ("O(p), p = loop size", 5_000..5_000_000), ("O(1)", 0..1_000);
+}
+
+/// The expectation of one part of a day, if the day is known; only those
+/// of the 2020 puzzles are.
+pub fn expectation(day: u8, part: u8) -> Option<Expectation> {
+    if crate::year() != DEFAULT_YEAR {
+        return None;
+    }
+    let (_, parts) = EXPECTATIONS.iter().find(|(d, _)| *d == day)?;
+    parts.get(usize::from(part).checked_sub(1)?).copied()
+}
+
+/// A warning when a part ran outside its typical range.
+pub fn check(day: u8, part: u8, duration: Duration) -> Option<String> {
+    let e = expectation(day, part)?;
+    if e.contains(duration) {
+        return None;
+    }
+    let direction = if duration > e.max() {
+        "slower"
+    } else {
+        "faster"
+    };
+    Some(format!(
+        "Day {day} part {part} took {duration:.1?}, {direction} than the \
+         typical {:?}..{:?} for {}",
+        e.min(),
+        e.max(),
+        e.complexity
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_part() {
+        for day in 1..=25 {
+            for part in 1..=2 {
+                let e = expectation(day, part).unwrap();
+                assert!(e.typical.0 < e.typical.1);
+            }
+        }
+        assert_eq!(expectation(26, 1), None);
+        assert_eq!(expectation(1, 0), None);
+        assert_eq!(expectation(1, 3), None);
+    }
+
+    #[test]
+    fn outliers() {
+        assert_eq!(check(1, 1, Duration::from_micros(40)), None);
+        assert_eq!(
+            check(1, 1, Duration::from_secs(2)).unwrap(),
+            "Day 1 part 1 took 2.0s, slower than the typical 1µs..5ms for O(n²)"
+        );
+        assert!(check(15, 2, Duration::from_micros(50))
+            .unwrap()
+            .contains("faster"));
+    }
+}
@@ -0,0 +1,109 @@
+//! Example Answers
+//!
+//! The answers of each day for the examples given in the puzzle text,
+//! declared once so that `cargo test` and the runner agree on them. The
+//! tests below are generated from the same table with `aoc_example_tests!`,
+//! one per day, and `aoc selftest` checks an optimized build against it.
+//!
+//! A part may have its own example file, e.g. day 14 and day 19 part two
+//! use `inputs/14-example-2.txt` and `inputs/19-example-2.txt`, which the runner picks with `--example`;
+//! `--example-file` and `--example-file-N` choose any other file. Parts
+//! without an example in a file, such as day 25 part two, are left out.
+//!
+//! The table holds the 2020 puzzles; the runner knows no example answers
+//! for the other years yet.
+
+use crate::registry::DEFAULT_YEAR;
+
+/// The expected answer of one part for an example file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: u8,
+    /// Input file name, e.g. `example` for `inputs/NN-example.txt`.
+    pub file: &'static str,
+    pub answer: &'static str,
+}
+
+macro_rules! expected {
+    ($(
+        $day:literal $module:ident:
+        ($file1:literal, $answer1:literal)
+        $(, ($file2:literal, $answer2:literal))?;
+    )*) => {
+        pub static EXPECTED: &[Expected] = &[$(
+            Expected { day: $day, part: 1, file: $file1, answer: $answer1 },
+            $(Expected { day: $day, part: 2, file: $file2, answer: $answer2 },)?
+        )*];
+
+        #[cfg(test)]
+        mod tests {
+            $(
+                mod $module {
+                    use crate::year2020::$module::Solution;
+
+                    aoc_example_tests!(
+                        day = $day,
+                        part_one = $answer1 in $file1,
+                        $(part_two = $answer2 in $file2,)?
+                    );
+                }
+            )*
+        }
+    };
+}
+
+expected! {
+    1 day01: ("example", "514579"), ("example", "241861950");
+    2 day02: ("example", "2"), ("example", "1");
+    3 day03: ("example", "7"), ("example", "336");
+    4 day04: ("example", "2");
+    5 day05: ("example", "820");
+    6 day06: ("example", "11"), ("example", "6");
+    7 day07: ("example", "4"), ("example", "32");
+    8 day08: ("example", "5"), ("example", "8");
+    9 day09: ("example", "127"), ("example", "62");
+    10 day10: ("example", "220"), ("example", "19208");
+    11 day11: ("example", "37"), ("example", "26");
+    12 day12: ("example", "25"), ("example", "286");
+    13 day13: ("example", "295"), ("example", "1068781");
+    14 day14: ("example", "165"), ("example-2", "208");
+    15 day15: ("example", "436"), ("example", "175594");
+    16 day16: ("example", "71");
+    17 day17: ("example", "112"), ("example", "848");
+    18 day18: ("example", "26457"), ("example", "694173");
+    19 day19: ("example", "2"), ("example-2", "12");
+    20 day20: ("example", "20899048083289"), ("example", "273");
+    21 day21: ("example", "5"), ("example", "mxmxvkd,sqjhc,fvjkl");
+    22 day22: ("example", "306"), ("example", "291");
+    23 day23: ("example", "67384529"), ("example", "149245887792");
+    24 day24: ("example", "10"), ("example", "2208");
+    25 day25: ("example", "14897079");
+}
+
+/// The example answers of the `year` being read.
+pub fn examples() -> &'static [Expected] {
+    match crate::year() {
+        DEFAULT_YEAR => EXPECTED,
+        _ => &[],
+    }
+}
+
+/// The files the parts of a day read: `input`, then the example files.
+pub fn files(day: u8) -> Vec<&'static str> {
+    let mut files = vec!["input"];
+    for e in examples().iter().filter(|e| e.day == day) {
+        if !files.contains(&e.file) {
+            files.push(e.file);
+        }
+    }
+    files
+}
+
+/// The example file a part runs against with `--example`.
+pub fn example_file(day: u8, part: u8) -> &'static str {
+    examples()
+        .iter()
+        .find(|e| e.day == day && e.part == part)
+        .map_or("example", |e| e.file)
+}
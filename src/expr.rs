@@ -0,0 +1,250 @@
+//! Expressions
+//!
+//! The arithmetic of day 18 under any precedence: an `Expr` is a line of
+//! numbers, binary operators and parentheses, checked when it is parsed,
+//! and `Expr::eval` converts it to reverse Polish notation by the
+//! shunting-yard algorithm with a table of `Operator`s, then evaluates it
+//! on a stack. Operators of equal precedence apply left to right. Values
+//! are `u128`, and an expression past it fails rather than wraps.
+//!
+//! `eval("1 + 2 * 3", &[ADD.binding(1), MUL.binding(1)])` is 9, and with
+//! `MUL.binding(2)` it is 7.
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::error::AocError;
+
+/// A binary operator and how tightly it binds.
+#[derive(Debug, Clone, Copy)]
+pub struct Operator {
+    pub symbol: char,
+    /// Higher binds tighter.
+    pub precedence: u8,
+    /// The value of `lhs op rhs`, none past `u128` (or below 0).
+    pub apply: fn(u128, u128) -> Option<u128>,
+}
+
+impl Operator {
+    /// The operator with a precedence of `precedence`.
+    pub const fn binding(self, precedence: u8) -> Operator {
+        Operator { precedence, ..self }
+    }
+}
+
+pub const ADD: Operator = Operator {
+    symbol: '+',
+    precedence: 1,
+    apply: u128::checked_add,
+};
+
+pub const SUB: Operator = Operator {
+    symbol: '-',
+    precedence: 1,
+    apply: u128::checked_sub,
+};
+
+pub const MUL: Operator = Operator {
+    symbol: '*',
+    precedence: 2,
+    apply: u128::checked_mul,
+};
+
+/// The precedence of school: `*` before `+` and `-`.
+pub const ARITHMETIC: [Operator; 3] = [ADD, SUB, MUL];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Number(u128),
+    Operator(char),
+    Open,
+    Close,
+}
+
+/// A well-formed expression: operands and operators alternate, and the
+/// parentheses are balanced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expr<'a> {
+    pub source: &'a str,
+    tokens: Vec<Token>,
+}
+
+/// A line like `2 * 3 + (4 * 5)`.
+impl<'a> TryFrom<&'a str> for Expr<'a> {
+    type Error = AocError;
+
+    fn try_from(s: &'a str) -> Result<Self, AocError> {
+        let error = |message| AocError::at(s, s, message);
+        let mut tokens = Vec::new();
+        let mut operand = true;
+        let mut depth = 0usize;
+        let mut chars = s.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let token = match c {
+                _ if c.is_whitespace() => continue,
+                '0'..='9' => {
+                    let mut end = i + 1;
+                    while let Some(&(j, '0'..='9')) = chars.peek() {
+                        end = j + 1;
+                        chars.next();
+                    }
+                    let digits = &s[i..end];
+                    let n = digits.parse().map_err(|_| {
+                        error(format!("`{digits}` is past u128"))
+                    })?;
+                    Token::Number(n)
+                }
+                '(' => Token::Open,
+                ')' => Token::Close,
+                _ if c.is_ascii_punctuation() => Token::Operator(c),
+                _ => return Err(error(format!("unexpected `{c}` in `{s}`"))),
+            };
+            // a number or `(` where an operand goes, and `)` or an
+            // operator after one
+            let expected = match token {
+                Token::Number(_) | Token::Open => operand,
+                Token::Close => !operand && depth > 0,
+                Token::Operator(_) => !operand,
+            };
+            if !expected {
+                return Err(error(format!("unexpected `{c}` in `{s}`")));
+            }
+            match token {
+                Token::Number(_) | Token::Close => operand = false,
+                Token::Operator(_) => operand = true,
+                Token::Open => {}
+            }
+            match token {
+                Token::Open => depth += 1,
+                Token::Close => depth -= 1,
+                _ => {}
+            }
+            tokens.push(token);
+        }
+        if operand || depth > 0 {
+            return Err(error(format!("incomplete expression `{s}`")));
+        }
+        Ok(Expr { source: s, tokens })
+    }
+}
+
+impl Expr<'_> {
+    /// The value of the expression under the precedences of `table`, which
+    /// must have every operator of it.
+    pub fn eval(&self, table: &[Operator]) -> Result<u128, AocError> {
+        let mut stack = Vec::new();
+        for token in self.rpn(table)? {
+            match token {
+                Token::Number(n) => stack.push(n),
+                Token::Operator(op) => {
+                    // the expression is well-formed, so both are there
+                    let rhs = stack.pop().unwrap();
+                    let lhs = stack.pop().unwrap();
+                    let apply = operator(table, op).unwrap().apply;
+                    stack.push(apply(lhs, rhs).ok_or_else(|| {
+                        AocError::no_solution(format!(
+                            "`{}` is past u128",
+                            self.source
+                        ))
+                    })?);
+                }
+                Token::Open | Token::Close => unreachable!(),
+            }
+        }
+        Ok(stack.pop().unwrap())
+    }
+
+    /// The tokens in reverse Polish notation, by the shunting-yard
+    /// algorithm.
+    fn rpn(&self, table: &[Operator]) -> Result<Vec<Token>, AocError> {
+        let precedence = |op| {
+            operator(table, op).map(|o| o.precedence).ok_or_else(|| {
+                AocError::at(
+                    self.source,
+                    self.source,
+                    format!("unknown operator `{op}` in `{}`", self.source),
+                )
+            })
+        };
+        let mut output = Vec::with_capacity(self.tokens.len());
+        let mut operators = Vec::new();
+        for &token in &self.tokens {
+            match token {
+                Token::Number(_) => output.push(token),
+                Token::Open => operators.push(token),
+                Token::Close => {
+                    while let Some(top) = operators.pop() {
+                        if top == Token::Open {
+                            break;
+                        }
+                        output.push(top);
+                    }
+                }
+                Token::Operator(op) => {
+                    let binding = precedence(op)?;
+                    // left to right: apply those before that bind as
+                    // tightly first
+                    while let Some(&Token::Operator(top)) = operators.last() {
+                        if precedence(top)? < binding {
+                            break;
+                        }
+                        output.push(operators.pop().unwrap());
+                    }
+                    operators.push(token);
+                }
+            }
+        }
+        output.extend(operators.into_iter().rev());
+        Ok(output)
+    }
+}
+
+fn operator(table: &[Operator], symbol: char) -> Option<&Operator> {
+    table.iter().find(|o| o.symbol == symbol)
+}
+
+/// The value of `expr` under the precedences of `table`.
+pub fn eval(expr: &str, table: &[Operator]) -> Result<u128, AocError> {
+    Expr::try_from(expr)?.eval(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn precedence() {
+        let flat = [ADD.binding(1), MUL.binding(1)];
+        assert_eq!(eval("1 + 2 * 3", &flat).unwrap(), 9);
+        assert_eq!(eval("1 + 2 * 3", &ARITHMETIC).unwrap(), 7);
+        assert_eq!(eval("2 * (3 + 4)", &ARITHMETIC).unwrap(), 14);
+        assert_eq!(eval("10 - 4 - 3", &ARITHMETIC).unwrap(), 3);
+        assert_eq!(eval("((12))", &ARITHMETIC).unwrap(), 12);
+        let additive_first = [ADD.binding(2), MUL.binding(1)];
+        let expr = "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2";
+        assert_eq!(eval(expr, &additive_first).unwrap(), 23340);
+    }
+
+    #[test]
+    fn malformed() {
+        for (expr, message) in [
+            ("1 +", "incomplete expression `1 +`"),
+            ("(1 + 2", "incomplete expression `(1 + 2`"),
+            ("1 + 2)", "unexpected `)` in `1 + 2)`"),
+            ("1 2", "unexpected `2` in `1 2`"),
+            ("1 + x", "unexpected `x` in `1 + x`"),
+            ("", "incomplete expression ``"),
+        ] {
+            let error = Expr::try_from(expr).unwrap_err();
+            assert_eq!(error.to_string(), format!("line 1: {message}"));
+        }
+        let error = eval("4 / 2", &ARITHMETIC).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1: unknown operator `/` in `4 / 2`"
+        );
+        let error = eval("1 - 2", &ARITHMETIC).unwrap_err();
+        assert!(matches!(error, AocError::NoSolution(_)));
+    }
+}
@@ -0,0 +1,146 @@
+//! C Interface
+//!
+//! With the `ffi` feature the solvers can be called from C and anything
+//! that speaks its ABI. `include/aoc.h` declares the interface; regenerate
+//! it with `cbindgen --config cbindgen.toml --output include/aoc.h` after
+//! changing this module. Like the WASM bindings, build the library as a
+//! `cdylib` or `staticlib` with `cargo rustc --crate-type`.
+
+use std::panic;
+use std::slice;
+
+use crate::{panic_message, AocError, Part};
+
+/// The answer is in the output buffer.
+pub const AOC_OK: i32 = 0;
+/// The output buffer is too small; `out_len` holds the length needed,
+/// without the terminating NUL.
+pub const AOC_BUFFER_TOO_SMALL: i32 = 1;
+/// No puzzle for the day, or a part other than 1 and 2.
+pub const AOC_NO_PUZZLE: i32 = 2;
+/// A null pointer or an input that is not UTF-8.
+pub const AOC_INVALID_ARGUMENT: i32 = 3;
+/// A malformed input or one without an answer; the message is in the
+/// output buffer.
+pub const AOC_ERROR: i32 = 4;
+/// The solver panicked; the message is in the output buffer.
+pub const AOC_PANIC: i32 = 5;
+
+/// Solve `part` of `day` for the `len` bytes of UTF-8 at `input`, writing
+/// the answer, or the message of `AOC_ERROR` and `AOC_PANIC`, to `out` as
+/// a NUL-terminated string. `*out_len` is the capacity of `out` on entry
+/// and the length of the string on return.
+///
+/// # Safety
+///
+/// `input` must point to `len` readable bytes, `out_len` to a `size_t`, and
+/// `out` to `*out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    len: usize,
+    out: *mut u8,
+    out_len: *mut usize,
+) -> i32 {
+    if input.is_null() || out.is_null() || out_len.is_null() {
+        return AOC_INVALID_ARGUMENT;
+    }
+    let Ok(input) = std::str::from_utf8(slice::from_raw_parts(input, len))
+    else {
+        return AOC_INVALID_ARGUMENT;
+    };
+    let (status, text) = solve(day, part, input);
+    let capacity = *out_len;
+    *out_len = text.len();
+    if text.len() >= capacity {
+        return AOC_BUFFER_TOO_SMALL;
+    }
+    let out = slice::from_raw_parts_mut(out, capacity);
+    out[..text.len()].copy_from_slice(text.as_bytes());
+    out[text.len()] = 0;
+    status
+}
+
+/// The status of solving `part` of `day` for `input`, and its text.
+fn solve(day: u8, part: u8, input: &str) -> (i32, String) {
+    let Ok(part) = Part::try_from(part) else {
+        return (AOC_NO_PUZZLE, String::new());
+    };
+    // no panic may unwind into the caller
+    match panic::catch_unwind(|| crate::solve(day, part, input)) {
+        Ok(Ok(answer)) => (AOC_OK, answer.to_string()),
+        Ok(Err(AocError::UnknownPuzzle { .. })) => {
+            (AOC_NO_PUZZLE, String::new())
+        }
+        Ok(Err(e)) => (AOC_ERROR, e.to_string()),
+        Err(payload) => (AOC_PANIC, panic_message(&*payload)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    fn call(
+        day: u8,
+        part: u8,
+        input: &str,
+        capacity: usize,
+    ) -> (i32, String) {
+        let mut out = vec![0xff; capacity];
+        let mut len = capacity;
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                &mut len,
+            )
+        };
+        if status == AOC_BUFFER_TOO_SMALL {
+            return (status, len.to_string());
+        }
+        assert_eq!(out.get(len), Some(&0));
+        (status, String::from_utf8(out[..len].to_vec()).unwrap())
+    }
+
+    #[test]
+    fn statuses() {
+        let input = read_example(1).unwrap();
+        assert_eq!(call(1, 1, &input, 64), (AOC_OK, "514579".to_string()));
+        assert_eq!(call(1, 1, &input, 6), (AOC_BUFFER_TOO_SMALL, "6".into()));
+        assert_eq!(call(1, 1, &input, 7).0, AOC_OK);
+        assert_eq!(
+            call(1, 1, "1721\nabc\n", 64),
+            (AOC_ERROR, "line 2: invalid number `abc`".to_string())
+        );
+        assert_eq!(call(26, 1, &input, 64).0, AOC_NO_PUZZLE);
+        assert_eq!(call(1, 3, &input, 64).0, AOC_NO_PUZZLE);
+        let mut len = 0;
+        let status = unsafe {
+            aoc_solve(1, 1, [0xff].as_ptr(), 1, [0].as_mut_ptr(), &mut len)
+        };
+        assert_eq!(status, AOC_INVALID_ARGUMENT);
+    }
+
+    #[test]
+    fn header() {
+        let header = include_str!("../include/aoc.h");
+        assert!(header.contains("int32_t aoc_solve("));
+        for (name, value) in [
+            ("AOC_OK", AOC_OK),
+            ("AOC_BUFFER_TOO_SMALL", AOC_BUFFER_TOO_SMALL),
+            ("AOC_NO_PUZZLE", AOC_NO_PUZZLE),
+            ("AOC_INVALID_ARGUMENT", AOC_INVALID_ARGUMENT),
+            ("AOC_ERROR", AOC_ERROR),
+            ("AOC_PANIC", AOC_PANIC),
+        ] {
+            assert!(header.contains(&format!("#define {name} {value}\n")));
+        }
+    }
+}
@@ -0,0 +1,483 @@
+//! Input Generators
+//!
+//! Random puzzle inputs built from scratch rather than scrambled from an
+//! existing input, for fuzzing the solvers end-to-end. Each generator
+//! encodes the invariants real inputs guarantee, so generated inputs are
+//! always solvable:
+//!
+//! - **Day 1**: exactly one pair and one triple of distinct entries sum to
+//!   2020
+//! - **Day 16**: every valid ticket value is covered by the rules, and the
+//!   fields can be determined one at a time by elimination
+//! - **Day 20**: tiles form a perfect square whose edges match exactly one
+//!   neighbor, with sea monsters hidden in the image
+//! - **Day 22**: unique cards, dealt so that regular Combat terminates
+//!
+//! `sized` builds inputs of a given size instead, for the scaling
+//! benchmarks of `benches/scaling.rs`:
+//!
+//! - **Day 9**: `size` numbers whose first invalid number is the sum of a
+//!   range near the end, so finding that range scans them all. Only the
+//!   number after the preamble is checked: the sums of a valid sequence
+//!   double every 25 numbers and overflow `u64` within 2000 of them
+//! - **Day 11**: a `size`×`size` grid of seats and floor, with the seats
+//!   that would flip forever floored
+//! - **Day 24**: `size` paths to tiles spread over an area that grows with
+//!   them, so the number of black tiles does too
+//!
+//! The same seed always produces the same input.
+
+use std::collections::HashSet;
+
+use crate::grid::Grid;
+use crate::rng::Rng;
+use crate::scramble::{deal_combat, dihedral, grid_to_string};
+use crate::year2020::day11;
+
+pub trait GenerateInput {
+    /// A random input satisfying the day's invariants.
+    fn generate(rng: &mut Rng) -> String;
+}
+
+pub struct Day01;
+pub struct Day16;
+pub struct Day20;
+pub struct Day22;
+
+/// Generate an input for the given day using `seed`, if the day has a
+/// generator.
+pub fn generate(day: u8, seed: u64) -> Option<String> {
+    let rng = &mut Rng::new(seed);
+    match day {
+        1 => Some(Day01::generate(rng)),
+        16 => Some(Day16::generate(rng)),
+        20 => Some(Day20::generate(rng)),
+        22 => Some(Day22::generate(rng)),
+        _ => None,
+    }
+}
+
+/// Generate an input of `size` for the given day using `seed`, if the day
+/// has a sized generator.
+pub fn sized(day: u8, size: usize, seed: u64) -> Option<String> {
+    let rng = &mut Rng::new(seed);
+    match day {
+        9 => Some(sized_day09(rng, size)),
+        11 => Some(sized_day11(rng, size)),
+        24 => Some(sized_day24(rng, size)),
+        _ => None,
+    }
+}
+
+fn sized_day09(rng: &mut Rng, size: usize) -> String {
+    const PREAMBLE: usize = 25;
+    assert!(size >= 2 * PREAMBLE + 50, "too few numbers: {size}");
+    // the preamble is too large for its sums to be the invalid number
+    let mut nums: Vec<u64> = Vec::with_capacity(size);
+    while nums.len() < PREAMBLE {
+        let v = (1 << 40) + rng.below(1 << 40);
+        if !nums.contains(&v) {
+            nums.push(v);
+        }
+    }
+    // every range of the even numbers after it has an even sum, so only
+    // the ranges around the one odd number can sum to the odd target
+    let mut rest: Vec<u64> = (PREAMBLE + 1..size)
+        .map(|_| 2 * rng.range(1, 500) as u64)
+        .collect();
+    let odd = rest.len() - PREAMBLE;
+    rest[odd] = 2 * rng.range(0, 499) as u64 + 1;
+    let invalid = rest[odd - 10..=odd + 10].iter().sum();
+    nums.push(invalid);
+    nums.extend(rest);
+    nums.iter().map(|n| format!("{n}\n")).collect()
+}
+
+fn sized_day11(rng: &mut Rng, size: usize) -> String {
+    let cells = (0..size * size)
+        .map(|_| if rng.below(100) < 85 { 'L' } else { '.' })
+        .collect();
+    let mut seats = Grid::new(size, size, cells);
+    // random layouts may flip between two forever; floor the seats that
+    // do until both parts settle
+    loop {
+        let flipping: Vec<(usize, usize)> = [1, 2]
+            .into_iter()
+            .flat_map(|part| day11::unsettled(&seats, part))
+            .collect();
+        if flipping.is_empty() {
+            break;
+        }
+        for (x, y) in flipping {
+            seats[(x, y)] = '.';
+        }
+    }
+    seats.to_string()
+}
+
+fn sized_day24(rng: &mut Rng, size: usize) -> String {
+    // about four tiles of the area per path, so most flip a tile of their
+    // own
+    let radius = (size as f64).sqrt() as i64;
+    let path = |rng: &mut Rng| -> String {
+        let (x, z) = (rng.range(-radius, radius), rng.range(-radius, radius));
+        let mut steps = Vec::new();
+        steps.extend(vec![
+            if x < 0 { "w" } else { "e" };
+            x.unsigned_abs() as usize
+        ]);
+        steps.extend(vec![
+            if z < 0 { "nw" } else { "se" };
+            z.unsigned_abs() as usize
+        ]);
+        // detours that cancel out
+        for _ in 0..rng.range(0, 3) {
+            steps.extend(["ne", "sw"]);
+        }
+        rng.shuffle(&mut steps);
+        steps.concat()
+    };
+    (0..size).map(|_| path(rng) + "\n").collect()
+}
+
+impl GenerateInput for Day01 {
+    fn generate(rng: &mut Rng) -> String {
+        // whether `v` would add a pair or triple summing to 2020, counting
+        // an entry twice as well so that no solver can pick one
+        let completes = |entries: &[i64], v: i64| {
+            let set: HashSet<i64> = entries.iter().copied().collect();
+            set.contains(&v)
+                || 2 * v == 2020
+                || set.contains(&(2020 - v))
+                || entries.iter().any(|&a| {
+                    let b = 2020 - v - a;
+                    b == a || b == v || set.contains(&b)
+                })
+        };
+
+        // pairs and triples summing to 2020, reusing entries like the
+        // brute-force solver may
+        let sums = |entries: &[i64]| {
+            let n = entries.len();
+            let pairs = (0..n)
+                .flat_map(|i| (i..n).map(move |j| (i, j)))
+                .filter(|&(i, j)| entries[i] + entries[j] == 2020)
+                .count();
+            let triples = (0..n)
+                .flat_map(|i| {
+                    (i..n).flat_map(move |j| (j..n).map(move |k| (i, j, k)))
+                })
+                .filter(|&(i, j, k)| {
+                    entries[i] + entries[j] + entries[k] == 2020
+                })
+                .count();
+            (pairs, triples)
+        };
+
+        let mut entries = loop {
+            let a = rng.range(100, 1920);
+            let x = rng.range(100, 1800);
+            let y = rng.range(100, 1920 - x);
+            let entries = vec![a, 2020 - a, x, y, 2020 - x - y];
+            let distinct: HashSet<i64> = entries.iter().copied().collect();
+            if distinct.len() == 5 && sums(&entries) == (1, 1) {
+                break entries;
+            }
+        };
+        while entries.len() < 200 {
+            let v = rng.range(1, 2019);
+            if !completes(&entries, v) {
+                entries.push(v);
+            }
+        }
+        rng.shuffle(&mut entries);
+        entries
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl GenerateInput for Day16 {
+    fn generate(rng: &mut Rng) -> String {
+        const NAMES: [&str; 20] = [
+            "departure location",
+            "departure station",
+            "departure platform",
+            "departure track",
+            "departure date",
+            "departure time",
+            "arrival location",
+            "arrival station",
+            "arrival platform",
+            "arrival track",
+            "class",
+            "duration",
+            "price",
+            "route",
+            "row",
+            "seat",
+            "train",
+            "type",
+            "wagon",
+            "zone",
+        ];
+        const WIDTH: i64 = 50;
+
+        let count = rng.range(8, 20) as usize;
+        let mut others = NAMES[6..].to_vec();
+        rng.shuffle(&mut others);
+        let mut names = NAMES[..6].to_vec();
+        names.extend(&others[..count - 6]);
+        rng.shuffle(&mut names);
+
+        // field k owns lo[k]..=hi[k]; its second range starts at lo[k + 1]
+        // so values of field m are also valid for all fields k < m, which
+        // makes the candidate fields of the columns nested
+        let lo: Vec<i64> = (0..=count as i64)
+            .map(|k| 25 + WIDTH * k + rng.range(0, 5))
+            .collect();
+        let hi: Vec<i64> = (0..count as i64)
+            .map(|k| 25 + WIDTH * (k + 1) - rng.range(5, 10))
+            .collect();
+        let top = hi[count - 1];
+        let rules: Vec<String> = (0..count)
+            .map(|k| {
+                let (lo2, hi2) = if k + 1 < count {
+                    (lo[k + 1], top)
+                } else {
+                    // the last field has no later fields to cover
+                    (lo[k] + 1, hi[k] - 1)
+                };
+                format!("{}: {}-{} or {lo2}-{hi2}", names[k], lo[k], hi[k])
+            })
+            .collect();
+
+        let mut columns: Vec<usize> = (0..count).collect();
+        rng.shuffle(&mut columns);
+        let ticket = |rng: &mut Rng| -> Vec<i64> {
+            columns.iter().map(|&k| rng.range(lo[k], hi[k])).collect()
+        };
+        let invalid = |rng: &mut Rng| match rng.below(3) {
+            0 => rng.range(1, lo[0] - 1),
+            1 => rng.range(hi[0] + 1, lo[1] - 1),
+            _ => rng.range(top + 1, top + 100),
+        };
+        let yours = ticket(rng);
+        let nearby: Vec<Vec<i64>> = (0..rng.range(190, 250))
+            .map(|_| {
+                let mut values = ticket(rng);
+                if rng.below(4) == 0 {
+                    let i = rng.below(count as u64) as usize;
+                    values[i] = invalid(rng);
+                }
+                values
+            })
+            .collect();
+
+        let line = |values: &[i64]| {
+            values
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        format!(
+            "{}\n\nyour ticket:\n{}\n\nnearby tickets:\n{}",
+            rules.join("\n"),
+            line(&yours),
+            nearby
+                .iter()
+                .map(|t| line(t))
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    }
+}
+
+impl GenerateInput for Day20 {
+    fn generate(rng: &mut Rng) -> String {
+        const MONSTER: [&str; 3] = [
+            "                  # ",
+            "#    ##    ##    ###",
+            " #  #  #  #  #  #   ",
+        ];
+
+        let side = rng.range(3, 12) as usize;
+        let n = 8 * side;
+        let mut image: Vec<Vec<u8>> = (0..n)
+            .map(|_| {
+                (0..n)
+                    .map(|_| if rng.below(3) == 0 { b'#' } else { b'.' })
+                    .collect()
+            })
+            .collect();
+        let mut taken: Vec<(usize, usize)> = Vec::new();
+        for _ in 0..side * side / 4 + 1 {
+            let (y, x) = (rng.below(n as u64 - 2), rng.below(n as u64 - 19));
+            let (y, x) = (y as usize, x as usize);
+            if taken
+                .iter()
+                .any(|&(ty, tx)| ty.abs_diff(y) < 3 && tx.abs_diff(x) < 20)
+            {
+                continue;
+            }
+            taken.push((y, x));
+            for (dy, row) in MONSTER.iter().enumerate() {
+                for (dx, c) in row.bytes().enumerate() {
+                    if c == b'#' {
+                        image[y + dy][x + dx] = b'#';
+                    }
+                }
+            }
+        }
+
+        // tiles overlap on their borders in a (9 * side + 1)² lattice, every
+        // border is drawn so that it matches no other border in any
+        // orientation
+        let m = 9 * side + 1;
+        let mut lattice = vec![vec![b'.'; m]; m];
+        let mut used = HashSet::new();
+        for y in (0..m).step_by(9) {
+            for x in (0..m).step_by(9) {
+                lattice[y][x] = if rng.chance() { b'#' } else { b'.' };
+            }
+        }
+        for i in (0..m).step_by(9) {
+            for j in (0..m - 1).step_by(9) {
+                for horizontal in [true, false] {
+                    let cell = |k: usize| match horizontal {
+                        true => (i, j + k),
+                        false => (j + k, i),
+                    };
+                    let ends = [cell(0), cell(9)].map(|(y, x)| lattice[y][x]);
+                    let border = loop {
+                        let bits = rng.below(256) as u16;
+                        let mut border = (u16::from(ends[0] == b'#') << 9)
+                            | (bits << 1)
+                            | u16::from(ends[1] == b'#');
+                        let flipped = border.reverse_bits() >> 6;
+                        if border != flipped
+                            && used.insert(border.min(flipped))
+                        {
+                            border >>= 1;
+                            break border;
+                        }
+                    };
+                    for k in 1..9 {
+                        let (y, x) = cell(k);
+                        let bit = (border >> (8 - k)) & 1;
+                        lattice[y][x] = if bit == 1 { b'#' } else { b'.' };
+                    }
+                }
+            }
+        }
+        for (y, row) in image.iter().enumerate() {
+            for (x, &c) in row.iter().enumerate() {
+                lattice[y / 8 * 9 + y % 8 + 1][x / 8 * 9 + x % 8 + 1] = c;
+            }
+        }
+
+        let mut ids = HashSet::new();
+        let mut tiles: Vec<String> = Vec::new();
+        for r in 0..side {
+            for c in 0..side {
+                let id = loop {
+                    let id = rng.range(1000, 9999);
+                    if ids.insert(id) {
+                        break id;
+                    }
+                };
+                let tile: Vec<Vec<u8>> = lattice[9 * r..=9 * r + 9]
+                    .iter()
+                    .map(|row| row[9 * c..=9 * c + 9].to_vec())
+                    .collect();
+                let tile = grid_to_string(&dihedral(tile, rng));
+                tiles.push(format!("Tile {id}:\n{tile}"));
+            }
+        }
+        rng.shuffle(&mut tiles);
+        tiles.join("\n\n")
+    }
+}
+
+impl GenerateInput for Day22 {
+    fn generate(rng: &mut Rng) -> String {
+        let n = rng.range(5, 25) as usize;
+        deal_combat(n, n, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        for day in [1, 16, 20, 22] {
+            assert_eq!(generate(day, 7), generate(day, 7));
+            assert_ne!(generate(day, 7), generate(day, 8));
+        }
+        assert_eq!(generate(2, 7), None);
+    }
+
+    #[test]
+    fn sized_inputs() {
+        use crate::year2020::day09::{self, PreambleStrategy};
+
+        let input = sized(9, 200, 7).unwrap();
+        let nums = day09::parse(&input).unwrap();
+        assert_eq!(nums.len(), 200);
+        assert_eq!(
+            day09::find_invalid_sum(&nums, 25).unwrap(),
+            day09::find_invalid_sum_brute(&nums, 25).unwrap()
+        );
+        assert_eq!(
+            day09::part_one_with(&input, PreambleStrategy::Real).unwrap(),
+            nums[25]
+        );
+
+        let input = sized(11, 30, 7).unwrap();
+        assert_eq!(input.lines().count(), 30);
+        assert!(input.lines().all(|line| line.len() == 30));
+        assert!(day11::part_one(&input) > 0 && day11::part_two(&input) > 0);
+
+        let input = sized(24, 100, 7).unwrap();
+        assert_eq!(input.lines().count(), 100);
+        assert!(crate::year2020::day24::part_one(&input) > 60);
+        assert_eq!(sized(24, 100, 7), Some(input));
+        assert_eq!(sized(1, 100, 7), None);
+    }
+
+    #[test]
+    fn solvable() {
+        for seed in 0..3 {
+            let input = generate(1, seed).unwrap();
+            assert_eq!(
+                crate::year2020::day01::part_one(&input),
+                crate::year2020::day01::part_one_hashset(&input)
+            );
+            assert_eq!(
+                crate::year2020::day01::part_two(&input),
+                crate::year2020::day01::part_two_hashset(&input)
+            );
+
+            let input = generate(16, seed).unwrap();
+            assert!(crate::year2020::day16::part_one(&input) > 0);
+            assert!(crate::year2020::day16::part_two(&input) > 0);
+
+            let input = generate(20, seed).unwrap();
+            let corners = crate::year2020::day20::part_one(&input);
+            assert!(corners >= 1000 * 1000 * 1000 * 1000);
+            let total = input.matches('#').count();
+            assert!(crate::year2020::day20::part_two(&input) < total);
+
+            let input = generate(22, seed).unwrap();
+            let cards: HashSet<&str> =
+                input.lines().filter(|l| l.parse::<u32>().is_ok()).collect();
+            assert_eq!(cards.len(), input.lines().count() - 3);
+            assert!(crate::year2020::day22::part_one(&input) > 0);
+        }
+    }
+}
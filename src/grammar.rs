@@ -0,0 +1,286 @@
+//! Grammars
+//!
+//! The message rules of day 19: a context-free grammar of numbered `Rule`s,
+//! each a literal letter or alternatives of sequences of other rules,
+//! parsed from lines like `0: 4 1 5`, `1: 2 3 | 3 2` and `4: "a"`. A rule
+//! can be replaced with `substitute`, recursion included, e.g. `8: 42 | 42
+//! 8`. `Rules::matches` tells whether a whole message matches rule 0 by
+//! recursive descent, memoized per rule and position; it follows every
+//! alternative, so it handles ambiguous and right-recursive grammars, but
+//! not left recursion, which never consumes a letter. A rule that is never
+//! defined matches nothing.
+//!
+//! The end positions of the matches are slices in an `Arena`, reset
+//! between messages, so the memo hands them out without cloning.
+
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt;
+use core::str::FromStr;
+
+use crate::arena::Arena;
+use crate::error::{number, AocError};
+
+/// The right-hand side of a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    /// One letter, e.g. `"a"`.
+    Literal(u8),
+    /// Sequences of rule numbers, one of which matches, e.g. `1 2 | 3`.
+    Alternatives(Vec<Vec<usize>>),
+}
+
+/// `"a"` or rule numbers with alternatives separated by `|`.
+impl FromStr for Rule {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, AocError> {
+        if let Some(literal) = s.strip_prefix('"') {
+            return match literal.as_bytes() {
+                [letter, b'"'] => Ok(Rule::Literal(*letter)),
+                _ => Err(AocError::at(
+                    s,
+                    s,
+                    format!("expected a letter like `\"a\"`, got `{s}`"),
+                )),
+            };
+        }
+        let alternatives = s
+            .split('|')
+            .map(|seq| seq.split_whitespace().map(|v| number(s, v)).collect())
+            .collect::<Result<_, _>>()?;
+        Ok(Rule::Alternatives(alternatives))
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::Literal(letter) => write!(f, "\"{}\"", *letter as char),
+            Rule::Alternatives(alternatives) => {
+                for (i, seq) in alternatives.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" |")?;
+                    }
+                    for (j, rule) in seq.iter().enumerate() {
+                        if i > 0 || j > 0 {
+                            f.write_str(" ")?;
+                        }
+                        write!(f, "{rule}")?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Grammar rules in a table indexed by rule number, ready to match any
+/// number of messages.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Rules {
+    rules: Vec<Option<Rule>>,
+}
+
+impl Rules {
+    /// The rules of `input`, one `n: rule` a line.
+    pub fn parse(input: &str) -> Result<Rules, AocError> {
+        let mut rules = Rules::default();
+        for line in input.lines() {
+            let (id, rule) = line.split_once(": ").ok_or_else(|| {
+                AocError::at(
+                    input,
+                    line,
+                    format!("expected `n: rule`, got `{line}`"),
+                )
+            })?;
+            let id = number(input, id)?;
+            let rule =
+                rule.parse().map_err(|e: AocError| e.within(input, line))?;
+            rules.substitute(id, rule);
+        }
+        Ok(rules)
+    }
+
+    /// Rule `id`, none if it is not defined.
+    pub fn get(&self, id: usize) -> Option<&Rule> {
+        self.rules.get(id)?.as_ref()
+    }
+
+    /// Define rule `id` as `rule`, returning the rule it replaces.
+    pub fn substitute(&mut self, id: usize, rule: Rule) -> Option<Rule> {
+        if self.rules.len() <= id {
+            self.rules.resize(id + 1, None);
+        }
+        self.rules[id].replace(rule)
+    }
+
+    /// Whether `message` completely matches rule 0.
+    pub fn matches(&self, message: &str) -> bool {
+        self.matches_rule(0, message)
+    }
+
+    /// Whether `message` completely matches rule `id`.
+    pub fn matches_rule(&self, id: usize, message: &str) -> bool {
+        self.matches_in(id, message, &mut Arena::new())
+    }
+
+    /// `matches_rule`, allocating in `arena`, which is reset first.
+    fn matches_in(
+        &self,
+        id: usize,
+        message: &str,
+        arena: &mut Arena<usize>,
+    ) -> bool {
+        arena.reset();
+        let message = message.as_bytes();
+        let mut memo = vec![None; self.rules.len() * (message.len() + 1)];
+        let mut matcher = Matcher {
+            rules: &self.rules,
+            message,
+            memo: &mut memo,
+            arena,
+            scratch: Vec::new(),
+        };
+        matcher.match_rule(id, 0).contains(&message.len())
+    }
+
+    /// How many of `messages` match rule 0, checked in parallel with the
+    /// `parallel` feature. Every thread reuses one arena.
+    pub fn count_matches(&self, messages: &[&str]) -> usize {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            messages
+                .par_iter()
+                .map_init(Arena::new, |arena, m| self.matches_in(0, m, arena))
+                .filter(|&matched| matched)
+                .count()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            let mut arena = Arena::new();
+            messages
+                .iter()
+                .filter(|m| self.matches_in(0, m, &mut arena))
+                .count()
+        }
+    }
+}
+
+/// Matching one message, with the end positions in an arena.
+struct Matcher<'a> {
+    rules: &'a [Option<Rule>],
+    message: &'a [u8],
+    /// The ends of every sequence rule and start position seen so far.
+    memo: &'a mut [Option<&'a [usize]>],
+    arena: &'a Arena<usize>,
+    /// The lists being built, innermost call last.
+    scratch: Vec<usize>,
+}
+
+impl<'a> Matcher<'a> {
+    // Returns the positions where a match of rule idx starting at `start`
+    // can end, memoized per sequence rule and start position
+    fn match_rule(&mut self, idx: usize, start: usize) -> &'a [usize] {
+        let seqs = match self.rules.get(idx) {
+            Some(Some(Rule::Literal(ch)))
+                if self.message.get(start) == Some(ch) =>
+            {
+                return self.arena.alloc_slice(&[start + 1]);
+            }
+            Some(Some(Rule::Alternatives(seqs))) => seqs,
+            _ => return &[],
+        };
+        let key = idx * (self.message.len() + 1) + start;
+        if let Some(ends) = self.memo[key] {
+            return ends;
+        }
+        // the ends of every sequence go on the scratch stack from `base`,
+        // the next positions of the one being matched above them
+        let base = self.scratch.len();
+        for seq in seqs {
+            let first = [start];
+            let mut positions: &[usize] = &first;
+            for &i in seq {
+                let from = self.scratch.len();
+                for &p in positions {
+                    for &end in self.match_rule(i, p) {
+                        if !self.scratch[from..].contains(&end) {
+                            self.scratch.push(end);
+                        }
+                    }
+                }
+                positions = self.arena.alloc_slice(&self.scratch[from..]);
+                self.scratch.truncate(from);
+                if positions.is_empty() {
+                    break;
+                }
+            }
+            self.scratch.extend_from_slice(positions);
+        }
+        let ends = self.arena.alloc_slice(&self.scratch[base..]);
+        self.scratch.truncate(base);
+        self.memo[key] = Some(ends);
+        ends
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn rules() {
+        let rule: Rule = "1 2 | 3".parse().unwrap();
+        assert_eq!(rule, Rule::Alternatives(vec![vec![1, 2], vec![3]]));
+        assert_eq!(rule.to_string(), "1 2 | 3");
+        assert_eq!("\"a\"".parse::<Rule>().unwrap(), Rule::Literal(b'a'));
+        assert_eq!(Rule::Literal(b'a').to_string(), "\"a\"");
+        assert!("\"ab\"".parse::<Rule>().is_err());
+        assert!("1 x".parse::<Rule>().is_err());
+
+        let error = Rules::parse("0: 1\n1 \"a\"").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected `n: rule`, got `1 \"a\"`"
+        );
+    }
+
+    #[test]
+    fn matching() {
+        let rules =
+            Rules::parse("0: 1 2\n1: \"a\"\n2: 1 3 | 3 1\n3: \"b\"").unwrap();
+        assert!(rules.matches("aab"));
+        assert!(rules.matches("aba"));
+        assert!(!rules.matches("abab"));
+        assert!(!rules.matches("ab"));
+        assert!(rules.matches_rule(2, "ba"));
+        assert_eq!(rules.count_matches(&["aab", "bba", "aba"]), 2);
+
+        // an undefined rule matches nothing
+        assert!(!rules.matches_rule(7, ""));
+        assert!(!Rules::default().matches("a"));
+    }
+
+    #[test]
+    fn substitution() {
+        // balanced parentheses: 0: "(" 0 ")" 0 | nothing
+        let mut rules = Rules::parse("1: \"(\"\n2: \")\"").unwrap();
+        let balanced = "1 0 2 0 |".parse().unwrap();
+        assert_eq!(rules.substitute(0, balanced), None);
+        assert!(rules.matches("(()())()"));
+        assert!(rules.matches(""));
+        assert!(!rules.matches("(()"));
+        assert!(!rules.matches(")("));
+
+        // a^n b^n, replacing a rule that does not recurse
+        let mut rules = Rules::parse("0: 1 2\n1: \"a\"\n2: \"b\"").unwrap();
+        let old = rules.substitute(0, "1 2 | 1 0 2".parse().unwrap());
+        assert_eq!(old, Some(Rule::Alternatives(vec![vec![1, 2]])));
+        assert!(rules.matches("aaabbb"));
+        assert!(!rules.matches("aabbb"));
+        assert_eq!(rules.get(1), Some(&Rule::Literal(b'a')));
+    }
+}
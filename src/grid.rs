@@ -0,0 +1,335 @@
+//! Grids
+//!
+//! `Grid<T>` is the 2D map of days 3, 11 and 17: a row-major `Vec` of
+//! cells addressed by `(x, y)`, with the neighbors, rows and columns those
+//! days need, and rotations and mirror images. `Grid::parse` reads a
+//! character a cell, pointing at the line of a ragged row or an unexpected
+//! character; a `Grid<bool>` parses from and prints as `#` and `.`, and
+//! packs into an `aoc::image::Bitmap`.
+
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Index, IndexMut};
+use core::str::FromStr;
+
+use crate::error::AocError;
+
+/// The offsets of the four neighbors across the sides of a cell.
+pub const NEIGHBORS4: [(isize, isize); 4] =
+    [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets of the eight neighbors across the sides and corners of a
+/// cell.
+pub const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// The rows, one after the other.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid of `cells` in rows; panics unless there
+    /// are `width * height` of them.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "a {width}x{height} grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// The cells of every line of `input`, each character mapped by `cell`,
+    /// or an error at the first ragged row or unexpected character.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, AocError> {
+        let mut lines = input.trim_end().lines().peekable();
+        let width = match lines.peek() {
+            Some(line) if !line.is_empty() => line.chars().count(),
+            _ => return Err(AocError::at(input, input, "expected a grid")),
+        };
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in lines {
+            let before = cells.len();
+            for c in line.chars() {
+                let Some(value) = cell(c) else {
+                    return Err(AocError::at(
+                        input,
+                        line,
+                        format!("unexpected `{c}` in `{line}`"),
+                    ));
+                };
+                cells.push(value);
+            }
+            if cells.len() - before != width {
+                return Err(AocError::at(
+                    input,
+                    line,
+                    format!("expected a row of {width} cells, got `{line}`"),
+                ));
+            }
+            height += 1;
+        }
+        Ok(Grid::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `(x, y)`, none off the grid.
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        let (x, y) = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        (x < self.width && y < self.height)
+            .then(|| &self.cells[y * self.width + x])
+    }
+
+    /// The cells in rows, one after the other.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a width of zero
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} of a {}-wide grid", self.width);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// The `(x, y)` of every cell, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its `(x, y)`, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The `(x, y)` of the neighbors of `(x, y)` across its sides that are
+    /// on the grid.
+    pub fn neighbors4(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &NEIGHBORS4)
+    }
+
+    /// The `(x, y)` of the neighbors of `(x, y)` across its sides and
+    /// corners that are on the grid.
+    pub fn neighbors8(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &NEIGHBORS8)
+    }
+
+    fn neighbors(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+            (x < self.width && y < self.height).then_some((x, y))
+        })
+    }
+
+    /// The grid of `f` of every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid of `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid::new(width, height, alloc::vec![value; width * height])
+    }
+
+    /// The grid mirrored in its top-left to bottom-right diagonal, its
+    /// columns as rows.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// The grid turned a quarter clockwise, row `i` being column `i` read
+    /// bottom up.
+    pub fn rotate(&self) -> Self {
+        let bottom = self.height.saturating_sub(1);
+        self.remap(self.height, self.width, |x, y| (y, bottom - x))
+    }
+
+    /// The grid mirrored left to right, every row reversed.
+    pub fn flip(&self) -> Self {
+        let right = self.width.saturating_sub(1);
+        self.remap(self.width, self.height, |x, y| (right - x, y))
+    }
+
+    /// A `width` by `height` grid of the cells `from(x, y)` of this one.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        from: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[from(x, y)].clone())
+            .collect();
+        Grid::new(width, height, cells)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width, "x {x} of a {}-wide grid", self.width);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width, "x {x} of a {}-wide grid", self.width);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Rows of `#` (true) and `.` (false).
+impl FromStr for Grid<bool> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, AocError> {
+        Grid::parse(s, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    }
+}
+
+impl fmt::Display for Grid<bool> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(self, f, |&on| if on { '#' } else { '.' })
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(self, f, |&c| c)
+    }
+}
+
+/// Every row of `grid` on a line of its own.
+fn write_rows<T>(
+    grid: &Grid<T>,
+    f: &mut fmt::Formatter<'_>,
+    char: impl Fn(&T) -> char,
+) -> fmt::Result {
+    for row in grid.rows() {
+        for cell in row {
+            write!(f, "{}", char(cell))?;
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        let grid: Grid<bool> = "#..\n.#.\n".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[(0, 0)] && grid[(1, 1)] && !grid[(2, 1)]);
+        assert_eq!(grid.get(1, 1), Some(&true));
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "#..\n.#.\n");
+
+        let error = "#..\n.#\n".parse::<Grid<bool>>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected a row of 3 cells, got `.#`"
+        );
+        let error = "#..\n.L.\n".parse::<Grid<bool>>().unwrap_err();
+        assert_eq!(error.to_string(), "line 2: unexpected `L` in `.L.`");
+        assert!("".parse::<Grid<bool>>().is_err());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::new(3, 2, (0..6).collect());
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[0, 1, 2], [3, 4, 5]]);
+        assert_eq!(grid.column(1).collect::<Vec<_>>(), [&1, &4]);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &4)));
+
+        let transposed = grid.transpose();
+        assert_eq!(transposed.cells(), [0, 3, 1, 4, 2, 5]);
+        assert_eq!(transposed.transpose(), grid);
+    }
+
+    #[test]
+    fn rotations() {
+        // 0 1 2      3 0
+        // 3 4 5  ->  4 1
+        //            5 2
+        let grid = Grid::new(3, 2, (0..6).collect());
+        let rotated = grid.rotate();
+        assert_eq!((rotated.width(), rotated.height()), (2, 3));
+        assert_eq!(rotated.cells(), [3, 0, 4, 1, 5, 2]);
+        assert_eq!(rotated.rotate().rotate().rotate(), grid);
+        assert_eq!(grid.flip().cells(), [2, 1, 0, 5, 4, 3]);
+        assert_eq!(grid.flip().flip(), grid);
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(grid.neighbors4(1, 1).count(), 4);
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(
+            grid.neighbors4(0, 0).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8(2, 2).count(), 3);
+    }
+}
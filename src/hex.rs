@@ -0,0 +1,286 @@
+//! Hex Grids
+//!
+//! `HexCoord` is a tile of a grid of hexagons with pointy tops, in cube
+//! coordinates (x, y, z) where x + y + z = 0, e.g. the lobby floor of day
+//! 24. East is +x -y and south-east is +z -y; `axial` and `from_axial`
+//! convert to and from the (q, r) = (x, z) of axial coordinates. Paths of
+//! `e`, `se`, `sw`, `w`, `nw` and `ne` parse into `Direction`s with
+//! `parse_path`.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, Sub};
+use core::str::FromStr;
+
+/// One of the six neighbors of a tile, clockwise from east.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 6] = [
+        Direction::East,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+        Direction::NorthEast,
+    ];
+
+    /// The step to the neighbor in this direction.
+    pub const fn offset(self) -> HexCoord {
+        let (x, y, z) = match self {
+            Direction::East => (1, -1, 0),
+            Direction::SouthEast => (0, -1, 1),
+            Direction::SouthWest => (-1, 0, 1),
+            Direction::West => (-1, 1, 0),
+            Direction::NorthWest => (0, 1, -1),
+            Direction::NorthEast => (1, 0, -1),
+        };
+        HexCoord { x, y, z }
+    }
+
+    /// The direction a sixth of a turn clockwise.
+    pub const fn clockwise(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 6]
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Direction::East => "e",
+            Direction::SouthEast => "se",
+            Direction::SouthWest => "sw",
+            Direction::West => "w",
+            Direction::NorthWest => "nw",
+            Direction::NorthEast => "ne",
+        }
+    }
+}
+
+/// `e`, `se`, `sw`, `w`, `nw` or `ne`.
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.name() == s)
+            .ok_or_else(|| format!("Invalid direction: {s}"))
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The directions of a path without separators, e.g. `esenee`.
+pub fn parse_path(line: &str) -> Result<Vec<Direction>, String> {
+    let mut directions = Vec::new();
+    let mut chars = line.char_indices();
+
+    while let Some((i, ch)) = chars.next() {
+        let len = match ch {
+            'e' | 'w' => 1,
+            'n' | 's' => match chars.next() {
+                Some((_, 'e' | 'w')) => 2,
+                Some(_) => {
+                    return Err(format!(
+                        "Invalid direction starting with {ch}"
+                    ))
+                }
+                None => {
+                    return Err(format!(
+                        "Incomplete direction starting with {ch}"
+                    ))
+                }
+            },
+            _ => {
+                return Err(format!("Invalid character in directions: {ch}"))
+            }
+        };
+        directions.push(line[i..i + len].parse()?);
+    }
+
+    Ok(directions)
+}
+
+/// A tile of a hex grid in cube coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HexCoord {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl HexCoord {
+    pub const ORIGIN: HexCoord = HexCoord { x: 0, y: 0, z: 0 };
+
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        debug_assert_eq!(x + y + z, 0);
+        Self { x, y, z }
+    }
+
+    /// The tile at axial coordinates `(q, r)`.
+    pub const fn from_axial(q: i32, r: i32) -> Self {
+        HexCoord {
+            x: q,
+            y: -q - r,
+            z: r,
+        }
+    }
+
+    /// The axial coordinates `(q, r)` of the tile.
+    pub const fn axial(self) -> (i32, i32) {
+        (self.x, self.z)
+    }
+
+    /// The neighbor in `direction`.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The tile at the end of `path` from this one.
+    pub fn walk(self, path: impl IntoIterator<Item = Direction>) -> Self {
+        path.into_iter().fold(self, HexCoord::step)
+    }
+
+    /// The six neighbors, clockwise from east.
+    pub fn neighbors(self) -> impl Iterator<Item = HexCoord> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// The number of steps to `other`.
+    pub fn distance(self, other: HexCoord) -> u32 {
+        let d = self - other;
+        d.x.unsigned_abs()
+            .max(d.y.unsigned_abs())
+            .max(d.z.unsigned_abs())
+    }
+
+    /// The tiles `radius` steps away, clockwise from the one `radius`
+    /// steps north-west; just this tile for a radius of zero.
+    pub fn ring(self, radius: u32) -> impl Iterator<Item = HexCoord> {
+        let r = radius as i32;
+        let nw = Direction::NorthWest.offset();
+        let start = self + HexCoord::new(nw.x * r, nw.y * r, nw.z * r);
+        let sides = if radius == 0 { 1 } else { 6 * radius as usize };
+        let mut tile = start;
+        (0..sides).map(move |i| {
+            let here = tile;
+            // along the side from one corner to the next
+            if radius > 0 {
+                tile = tile.step(Direction::ALL[i / radius as usize]);
+            }
+            here
+        })
+    }
+
+    /// The tiles at most `radius` steps away, ring by ring from this one.
+    pub fn spiral(self, radius: u32) -> impl Iterator<Item = HexCoord> {
+        (0..=radius).flat_map(move |r| self.ring(r))
+    }
+}
+
+impl Add for HexCoord {
+    type Output = HexCoord;
+
+    fn add(self, other: HexCoord) -> HexCoord {
+        HexCoord::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for HexCoord {
+    type Output = HexCoord;
+
+    fn sub(self, other: HexCoord) -> HexCoord {
+        HexCoord::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn directions() {
+        let east = HexCoord::ORIGIN.step(Direction::East);
+        assert_eq!(east, HexCoord::new(1, -1, 0));
+        assert_eq!(east.step(Direction::West), HexCoord::ORIGIN);
+        for direction in Direction::ALL {
+            let offset = direction.offset();
+            assert_eq!(offset.x + offset.y + offset.z, 0);
+            assert_eq!(direction.to_string().parse(), Ok(direction));
+            assert_eq!(offset.distance(HexCoord::ORIGIN), 1);
+        }
+        assert_eq!(Direction::NorthEast.clockwise(), Direction::East);
+    }
+
+    #[test]
+    fn paths() {
+        use Direction::*;
+        assert_eq!(
+            parse_path("esenee").unwrap(),
+            [East, SouthEast, NorthEast, East]
+        );
+        assert_eq!(parse_path("esew").unwrap(), [East, SouthEast, West]);
+        let path = parse_path("nwwswee").unwrap();
+        assert_eq!(path, [NorthWest, West, SouthWest, East, East]);
+        assert_eq!(HexCoord::ORIGIN.walk(path), HexCoord::ORIGIN);
+
+        assert_eq!(
+            parse_path("ex").unwrap_err(),
+            "Invalid character in directions: x"
+        );
+        assert_eq!(
+            parse_path("en").unwrap_err(),
+            "Incomplete direction starting with n"
+        );
+        assert!(parse_path("nn").is_err());
+    }
+
+    #[test]
+    fn axial() {
+        let tile = HexCoord::new(2, -5, 3);
+        assert_eq!(tile.axial(), (2, 3));
+        assert_eq!(HexCoord::from_axial(2, 3), tile);
+        assert_eq!(tile.distance(HexCoord::ORIGIN), 5);
+    }
+
+    #[test]
+    fn rings() {
+        let center = HexCoord::from_axial(3, -1);
+        assert_eq!(center.ring(0).collect::<Vec<_>>(), vec![center]);
+        let mut ring: Vec<_> = center.ring(1).collect();
+        assert_eq!(ring.len(), 6);
+        ring.sort();
+        let mut neighbors: Vec<_> = center.neighbors().collect();
+        neighbors.sort();
+        assert_eq!(ring, neighbors);
+
+        let ring: Vec<_> = center.ring(3).collect();
+        assert_eq!(ring.len(), 18);
+        assert!(ring.iter().all(|&tile| tile.distance(center) == 3));
+        // each tile steps to the next, all the way round
+        for (i, tile) in ring.iter().enumerate() {
+            assert_eq!(tile.distance(ring[(i + 1) % ring.len()]), 1);
+        }
+
+        let mut spiral: Vec<_> = center.spiral(3).collect();
+        assert_eq!(spiral.len(), 37);
+        spiral.sort();
+        spiral.dedup();
+        assert_eq!(spiral.len(), 37);
+    }
+}
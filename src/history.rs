@@ -0,0 +1,287 @@
+//! Timing History
+//!
+//! Every timed run appends one record per day and part to a local NDJSON
+//! file, together with the toolchain and git commit it was built from, so
+//! the runtime of a day can be followed across optimization work. Both are
+//! captured by the build script, not looked up when the binary runs.
+//!
+//! Records are flat JSON objects, one per line:
+//!
+//! ```text
+//! {"timestamp": 1700000000, "toolchain": "rustc 1.95.0", "commit": "c287c37",
+//!  "input": "input", "day": 15, "part": 2, "duration_ns": 512300000}
+//! ```
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::json::{parse_flat_object, quote};
+
+pub const DEFAULT_PATH: &str = ".aoc/history.jsonl";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// Seconds since the Unix epoch when the run started.
+    pub timestamp: u64,
+    pub toolchain: String,
+    pub commit: String,
+    /// Input file name, e.g. `input` or `example`.
+    pub input: String,
+    pub day: u8,
+    pub part: u8,
+    pub duration: Duration,
+}
+
+/// The version of the `rustc` the crate was built with, if known.
+pub fn toolchain() -> Option<String> {
+    option_env!("AOC_TOOLCHAIN").map(str::to_string)
+}
+
+/// The short hash of the git commit the crate was built from, if any.
+pub fn commit() -> Option<String> {
+    option_env!("AOC_COMMIT").map(str::to_string)
+}
+
+pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for r in records {
+        writeln!(
+            file,
+            "{{\"timestamp\": {}, \"toolchain\": {}, \"commit\": {}, \
+             \"input\": {}, \"day\": {}, \"part\": {}, \"duration_ns\": {}}}",
+            r.timestamp,
+            quote(&r.toolchain),
+            quote(&r.commit),
+            quote(&r.input),
+            r.day,
+            r.part,
+            r.duration.as_nanos()
+        )?;
+    }
+    Ok(())
+}
+
+/// Load all records, skipping lines that are not valid records.
+pub fn load(path: &Path) -> io::Result<Vec<Record>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    Ok(content.lines().filter_map(parse_record).collect())
+}
+
+fn parse_record(line: &str) -> Option<Record> {
+    let fields = parse_flat_object(line)?;
+    Some(Record {
+        timestamp: fields.get("timestamp")?.parse().ok()?,
+        toolchain: fields.get("toolchain")?.clone(),
+        commit: fields.get("commit")?.clone(),
+        input: fields.get("input")?.clone(),
+        day: fields.get("day")?.parse().ok()?,
+        part: fields.get("part")?.parse().ok()?,
+        duration: Duration::from_nanos(
+            fields.get("duration_ns")?.parse().ok()?,
+        ),
+    })
+}
+
+/// The days and parts whose latest recorded run on `input` took longer
+/// than `threshold`, in day and part order.
+pub fn slower_than(
+    records: &[Record],
+    input: &str,
+    threshold: Duration,
+) -> Vec<(u8, u8)> {
+    let mut latest: Vec<&Record> = Vec::new();
+    for r in records.iter().filter(|r| r.input == input) {
+        match latest
+            .iter_mut()
+            .find(|l| (l.day, l.part) == (r.day, r.part))
+        {
+            Some(l) => *l = r,
+            None => latest.push(r),
+        }
+    }
+    let mut slow: Vec<(u8, u8)> = latest
+        .iter()
+        .filter(|r| r.duration > threshold)
+        .map(|r| (r.day, r.part))
+        .collect();
+    slow.sort_unstable();
+    slow
+}
+
+/// `YYYY-MM-DD HH:MM` (UTC) for seconds since the Unix epoch.
+pub(crate) fn format_timestamp(timestamp: u64) -> String {
+    let (y, m, d) = civil_date(timestamp);
+    let secs = timestamp % 86400;
+    format!(
+        "{y:04}-{m:02}-{d:02} {:02}:{:02}",
+        secs / 3600,
+        secs % 3600 / 60
+    )
+}
+
+/// The `(year, month, day)` (UTC) of seconds since the Unix epoch.
+pub(crate) fn civil_date(timestamp: u64) -> (i64, i64, i64) {
+    // civil-from-days, see http://howardhinnant.github.io/date_algorithms.html
+    let days = (timestamp / 86400) as i64;
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    (y, m, d)
+}
+
+/// Table of the runs of one day, oldest first, followed by a sparkline of
+/// the total runtime per run.
+pub fn render(records: &[Record], day: u8, input: &str) -> String {
+    const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let mut runs: Vec<(&Record, [Option<Duration>; 2])> = Vec::new();
+    for r in records.iter().filter(|r| r.day == day && r.input == input) {
+        let slot = (r.part as usize).clamp(1, 2) - 1;
+        match runs.last_mut() {
+            Some((first, parts))
+                if first.timestamp == r.timestamp
+                    && first.commit == r.commit
+                    && parts[slot].is_none() =>
+            {
+                parts[slot] = Some(r.duration)
+            }
+            _ => {
+                let mut parts = [None, None];
+                parts[slot] = Some(r.duration);
+                runs.push((r, parts));
+            }
+        }
+    }
+
+    let mut output = format!("--- History of Day {day} ({input}) ---\n");
+    if runs.is_empty() {
+        output.push_str("No timed runs recorded.\n");
+        return output;
+    }
+
+    let total = |parts: &[Option<Duration>; 2]| -> Duration {
+        parts.iter().flatten().sum()
+    };
+    let part = |d: Option<Duration>| match d {
+        Some(d) => format!("{d:.1?}"),
+        None => "-".to_string(),
+    };
+    let mut previous: Option<Duration> = None;
+    for (r, parts) in &runs {
+        let t = total(parts);
+        let trend = match previous {
+            Some(p) if !p.is_zero() => format!(
+                "{:+.1}%",
+                (t.as_secs_f64() / p.as_secs_f64() - 1.0) * 100.0
+            ),
+            _ => String::new(),
+        };
+        let line = format!(
+            "{}  {:<9} {:<14} {:>10} {:>10} {trend:>8}",
+            format_timestamp(r.timestamp),
+            r.commit,
+            r.toolchain,
+            part(parts[0]),
+            part(parts[1]),
+        );
+        output.push_str(line.trim_end());
+        output.push('\n');
+        previous = Some(t);
+    }
+
+    let totals: Vec<u128> =
+        runs.iter().map(|(_, p)| total(p).as_nanos()).collect();
+    let (lo, hi) =
+        (*totals.iter().min().unwrap(), *totals.iter().max().unwrap());
+    let sparkline: String = totals
+        .iter()
+        .map(|&t| {
+            SPARKS[((t - lo) * 7).checked_div(hi - lo).unwrap_or(0) as usize]
+        })
+        .collect();
+    output.push_str(&format!("Trend: {sparkline}\n"));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(timestamp: u64, part: u8, millis: u64) -> Record {
+        Record {
+            timestamp,
+            toolchain: "rustc 1.95.0".to_string(),
+            commit: "c287c37".to_string(),
+            input: "input".to_string(),
+            day: 15,
+            part,
+            duration: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-history-{}.jsonl", std::process::id()));
+        let records =
+            vec![record(1700000000, 1, 3), record(1700000000, 2, 500)];
+        append(&path, &records).unwrap();
+        append(&path, &records[..1]).unwrap();
+        let loaded = load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.len(), 3);
+        assert_eq!(loaded[..2], records[..]);
+    }
+
+    #[test]
+    fn timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1608854400 + 3661), "2020-12-25 01:01");
+    }
+
+    #[test]
+    fn trend() {
+        let records = [
+            record(1608854400, 1, 2),
+            record(1608854400, 2, 8),
+            record(1608940800, 1, 1),
+            record(1608940800, 2, 4),
+        ];
+        let output = render(&records, 15, "input");
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].starts_with("2020-12-25 00:00  c287c37"));
+        assert!(lines[2].ends_with("-50.0%"));
+        assert_eq!(lines[3], "Trend: █▁");
+        assert!(render(&records, 1, "input").contains("No timed runs"));
+    }
+
+    #[test]
+    fn slow_parts() {
+        let mut records = vec![
+            record(1608854400, 1, 2),
+            record(1608854400, 2, 800),
+            record(1608940800, 1, 300),
+            record(1608940800, 2, 4),
+        ];
+        records[0].day = 1;
+        let threshold = Duration::from_millis(200);
+        assert_eq!(slower_than(&records, "input", threshold), [(15, 1)]);
+        assert!(slower_than(&records, "example", threshold).is_empty());
+    }
+}
@@ -0,0 +1,370 @@
+//! Images
+//!
+//! `Bitmap` is a black and white image packed a bit a pixel, e.g. the
+//! camera tiles of day 20 and the picture they assemble into, addressed by
+//! `(x, y)` like a `Grid`. An `Orientation` is one of the eight ways to lay
+//! an image down, a mirror image or not turned a number of quarters
+//! clockwise; `Bitmap::apply` lays one down so, and `orientations` tries
+//! them all. `then` and `inverse` compose them without touching pixels. A
+//! bitmap parses from and prints as `#` and `.`, and converts to and from
+//! a `Grid<bool>`.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::error::AocError;
+use crate::grid::Grid;
+
+/// How an image is laid down: mirrored left to right first or not, then
+/// turned clockwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    Flip,
+    FlipRotate90,
+    FlipRotate180,
+    FlipRotate270,
+}
+
+impl Orientation {
+    /// The four rotations, then those of the mirror image.
+    pub const ALL: [Orientation; 8] = [
+        Orientation::Identity,
+        Orientation::Rotate90,
+        Orientation::Rotate180,
+        Orientation::Rotate270,
+        Orientation::Flip,
+        Orientation::FlipRotate90,
+        Orientation::FlipRotate180,
+        Orientation::FlipRotate270,
+    ];
+
+    /// The orientation mirrored if `flipped`, then turned `quarter_turns`
+    /// clockwise.
+    pub const fn new(flipped: bool, quarter_turns: usize) -> Self {
+        Orientation::ALL[flipped as usize * 4 + quarter_turns % 4]
+    }
+
+    /// Whether the image is mirrored.
+    pub const fn flipped(self) -> bool {
+        self as usize >= 4
+    }
+
+    /// The number of quarter turns clockwise, after any mirroring.
+    pub const fn quarter_turns(self) -> usize {
+        self as usize % 4
+    }
+
+    /// This orientation and then `next`.
+    pub const fn then(self, next: Orientation) -> Orientation {
+        // a mirror image turns the other way, so mirroring after turning
+        // is turning back after mirroring
+        let turns = if next.flipped() {
+            4 - self.quarter_turns()
+        } else {
+            self.quarter_turns()
+        };
+        Orientation::new(
+            self.flipped() != next.flipped(),
+            turns + next.quarter_turns(),
+        )
+    }
+
+    /// The orientation that lays an image so oriented back down as it was.
+    pub const fn inverse(self) -> Orientation {
+        if self.flipped() {
+            self
+        } else {
+            Orientation::new(false, 4 - self.quarter_turns())
+        }
+    }
+
+    /// The width and height of a `width` by `height` image so laid down.
+    pub const fn size(self, width: usize, height: usize) -> (usize, usize) {
+        if self.quarter_turns().is_multiple_of(2) {
+            (width, height)
+        } else {
+            (height, width)
+        }
+    }
+
+    /// The pixel of a `width` by `height` image that ends up at `(x, y)`
+    /// once it is laid down so.
+    pub const fn source(
+        self,
+        (mut x, mut y): (usize, usize),
+        width: usize,
+        height: usize,
+    ) -> (usize, usize) {
+        // undo the turns, last first
+        let mut turn = self.quarter_turns();
+        while turn > 0 {
+            turn -= 1;
+            let bottom = (if turn.is_multiple_of(2) {
+                height
+            } else {
+                width
+            }) - 1;
+            (x, y) = (y, bottom - x);
+        }
+        if self.flipped() {
+            x = width - 1 - x;
+        }
+        (x, y)
+    }
+}
+
+/// A black and white image, a bit a pixel.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bitmap {
+    width: usize,
+    height: usize,
+    /// The rows one after the other, pixel `i` being bit `i % 64` of word
+    /// `i / 64`.
+    bits: Vec<u64>,
+}
+
+impl Bitmap {
+    /// A blank `width` by `height` image.
+    pub fn new(width: usize, height: usize) -> Self {
+        Bitmap {
+            width,
+            height,
+            bits: vec![0; (width * height).div_ceil(64)],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) outside a {}x{} bitmap",
+            self.width,
+            self.height
+        );
+        y * self.width + x
+    }
+
+    /// Whether the pixel at `(x, y)` is set; panics outside the image.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        let i = self.index(x, y);
+        self.bits[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    /// Set or clear the pixel at `(x, y)`; panics outside the image.
+    pub fn set(&mut self, x: usize, y: usize, on: bool) {
+        let i = self.index(x, y);
+        let mask = 1 << (i % 64);
+        if on {
+            self.bits[i / 64] |= mask;
+        } else {
+            self.bits[i / 64] &= !mask;
+        }
+    }
+
+    /// The number of pixels set.
+    pub fn count_ones(&self) -> usize {
+        self.bits.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// The image laid down in `orientation`.
+    pub fn apply(&self, orientation: Orientation) -> Bitmap {
+        let (width, height) = orientation.size(self.width, self.height);
+        let mut image = Bitmap::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let (sx, sy) =
+                    orientation.source((x, y), self.width, self.height);
+                if self.get(sx, sy) {
+                    image.set(x, y, true);
+                }
+            }
+        }
+        image
+    }
+
+    /// The image in every orientation, in the order of `Orientation::ALL`.
+    pub fn orientations(
+        &self,
+    ) -> impl Iterator<Item = (Orientation, Bitmap)> + '_ {
+        Orientation::ALL.into_iter().map(|o| (o, self.apply(o)))
+    }
+
+    /// The pixels of the four edges, clockwise from the top, each read
+    /// left to right or top to bottom.
+    pub fn edges(&self) -> [Vec<bool>; 4] {
+        let (right, bottom) = (self.width - 1, self.height - 1);
+        let row = |y| (0..self.width).map(|x| self.get(x, y)).collect();
+        let column = |x| (0..self.height).map(|y| self.get(x, y)).collect();
+        [row(0), column(right), row(bottom), column(0)]
+    }
+
+    /// The `width` by `height` part of the image from `(x, y)`.
+    pub fn crop(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Self {
+        let mut part = Bitmap::new(width, height);
+        part.paste(0, 0, self, x, y);
+        part
+    }
+
+    /// Copy the pixels of `other` from `(from_x, from_y)` to this image
+    /// from `(x, y)`, as many as fit in both.
+    fn paste(
+        &mut self,
+        x: usize,
+        y: usize,
+        other: &Bitmap,
+        from_x: usize,
+        from_y: usize,
+    ) {
+        let width = self
+            .width
+            .saturating_sub(x)
+            .min(other.width.saturating_sub(from_x));
+        let height = self
+            .height
+            .saturating_sub(y)
+            .min(other.height.saturating_sub(from_y));
+        for dy in 0..height {
+            for dx in 0..width {
+                let on = other.get(from_x + dx, from_y + dy);
+                self.set(x + dx, y + dy, on);
+            }
+        }
+    }
+
+    /// Draw `other` with its top-left corner at `(x, y)`, cut off at the
+    /// edges of this image.
+    pub fn draw(&mut self, x: usize, y: usize, other: &Bitmap) {
+        self.paste(x, y, other, 0, 0);
+    }
+}
+
+impl From<&Grid<bool>> for Bitmap {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut image = Bitmap::new(grid.width(), grid.height());
+        for ((x, y), &on) in grid.iter() {
+            image.set(x, y, on);
+        }
+        image
+    }
+}
+
+impl From<&Bitmap> for Grid<bool> {
+    fn from(image: &Bitmap) -> Self {
+        let (width, height) = (image.width, image.height);
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| image.get(x, y)))
+            .collect();
+        Grid::new(width, height, cells)
+    }
+}
+
+/// Rows of `#` and `.`, like a `Grid<bool>`.
+impl FromStr for Bitmap {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, AocError> {
+        Ok(Bitmap::from(&s.parse::<Grid<bool>>()?))
+    }
+}
+
+impl fmt::Display for Bitmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                f.write_str(if self.get(x, y) { "#" } else { "." })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    const L: &str = "#..\n#..\n##.\n...\n";
+
+    #[test]
+    fn bitmaps() {
+        let image: Bitmap = L.parse().unwrap();
+        assert_eq!((image.width(), image.height()), (3, 4));
+        assert!(image.get(1, 2) && !image.get(2, 2));
+        assert_eq!(image.count_ones(), 4);
+        assert_eq!(image.to_string(), L);
+        assert_eq!(image.edges()[1], [false; 4]);
+        assert_eq!(image.edges()[3], [true, true, true, false]);
+        assert_eq!(Bitmap::from(&Grid::from(&image)), image);
+
+        let mut image = image.crop(0, 1, 2, 2);
+        assert_eq!(image.to_string(), "#.\n##\n");
+        image.set(1, 0, true);
+        let mut canvas = Bitmap::new(3, 3);
+        canvas.draw(2, 2, &image);
+        assert_eq!(canvas.to_string(), "...\n...\n..#\n");
+        assert!("#x".parse::<Bitmap>().is_err());
+
+        // a bitmap of more than one word
+        let mut wide = Bitmap::new(100, 3);
+        wide.set(99, 2, true);
+        wide.set(64, 0, true);
+        assert_eq!(wide.apply(Orientation::Rotate180).count_ones(), 2);
+        assert!(wide.apply(Orientation::Rotate90).get(0, 99));
+    }
+
+    #[test]
+    fn orientations() {
+        let image: Bitmap = L.parse().unwrap();
+        let grid = Grid::from(&image);
+        // the same as turning and mirroring a grid
+        for (orientation, oriented) in image.orientations() {
+            let mut expected = grid.clone();
+            if orientation.flipped() {
+                expected = expected.flip();
+            }
+            for _ in 0..orientation.quarter_turns() {
+                expected = expected.rotate();
+            }
+            assert_eq!(Grid::from(&oriented), expected, "{orientation:?}");
+        }
+        assert_eq!(
+            image.apply(Orientation::Rotate90).to_string(),
+            ".###\n.#..\n....\n"
+        );
+    }
+
+    #[test]
+    fn composition() {
+        let image: Bitmap = L.parse().unwrap();
+        for a in Orientation::ALL {
+            assert_eq!(image.apply(a).apply(a.inverse()), image);
+            for b in Orientation::ALL {
+                assert_eq!(
+                    image.apply(a).apply(b),
+                    image.apply(a.then(b)),
+                    "{a:?} then {b:?}"
+                );
+            }
+        }
+    }
+}
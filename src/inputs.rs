@@ -0,0 +1,289 @@
+//! Input Files
+//!
+//! Where the puzzle inputs come from: the files of the data directory, any
+//! other path or URL, and the adventofcode.com session cookie for the
+//! downloads. The inputs of the default year are in the data directory
+//! itself, those of any other year in its `NNNN/` subdirectory. All of it needs the standard library, so the `std` feature
+//! gates this module; the crate root re-exports it.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use crate::error::AocError;
+use crate::provider::{Files, InputProvider};
+use crate::registry::DEFAULT_YEAR;
+use crate::{http, normalize};
+
+static DATA_DIR: OnceLock<String> = OnceLock::new();
+
+/// Read inputs from `dir` instead of `inputs`. Only the first call has an
+/// effect, so set it before reading any input.
+pub fn set_data_dir(dir: &str) {
+    let _ = DATA_DIR.set(dir.trim_end_matches('/').to_string());
+}
+
+/// The directory of the input files: the one set with `set_data_dir`, else
+/// `AOC_DATA_DIR`, else `inputs`.
+pub fn data_dir() -> &'static str {
+    DATA_DIR.get_or_init(|| {
+        let dir = std::env::var("AOC_DATA_DIR").unwrap_or_default();
+        match dir.trim_end_matches('/') {
+            "" => "inputs".to_string(),
+            dir => dir.to_string(),
+        }
+    })
+}
+
+static YEAR: OnceLock<u16> = OnceLock::new();
+
+/// Read and fetch the inputs of `year` instead of those of the
+/// `DEFAULT_YEAR`. Only the first call has an effect, so set it before
+/// reading any input.
+pub fn set_year(year: u16) {
+    let _ = YEAR.set(year);
+}
+
+/// The year of the inputs: the one set with `set_year`, else the
+/// `DEFAULT_YEAR`.
+pub fn year() -> u16 {
+    *YEAR.get_or_init(|| DEFAULT_YEAR)
+}
+
+/// Where the inputs of `year` are in `dir`.
+fn year_dir(dir: &str, year: u16) -> String {
+    match year {
+        DEFAULT_YEAR => dir.to_string(),
+        year => format!("{dir}/{year}"),
+    }
+}
+
+/// The directory of the inputs of the `year` in the `data_dir`, e.g.
+/// `inputs` or `inputs/2021`.
+pub fn inputs_dir() -> String {
+    year_dir(data_dir(), year())
+}
+
+/// The path of an input file, e.g. `inputs/14-example-2.txt`.
+pub fn input_path(day: u8, filename: &str) -> String {
+    format!("{}/{day:02}-{filename}.txt", inputs_dir())
+}
+
+/// Where input files are looked for, in order: the `data_dir`, `inputs` in
+/// the working directory and `inputs` in the crate root, so the binary
+/// finds them when run from another directory too; their `NNNN/`
+/// subdirectories for a `year` other than the default.
+pub fn data_dirs() -> Vec<String> {
+    let same =
+        |a: &str, b: &str| match (fs::canonicalize(a), fs::canonicalize(b)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => a == b,
+        };
+    let mut dirs = vec![data_dir().to_string()];
+    for dir in ["inputs", concat!(env!("CARGO_MANIFEST_DIR"), "/inputs")] {
+        if !dirs.iter().any(|d| same(d, dir)) {
+            dirs.push(dir.to_string());
+        }
+    }
+    dirs.iter().map(|dir| year_dir(dir, year())).collect()
+}
+
+/// The path of the first of the `data_dirs` holding an input file.
+pub fn find_input(day: u8, filename: &str) -> Option<String> {
+    data_dirs()
+        .into_iter()
+        .map(|dir| format!("{dir}/{day:02}-{filename}.txt"))
+        .find(|path| Path::new(path).is_file())
+}
+
+type Provider = Box<dyn InputProvider + Send + Sync>;
+
+static PROVIDER: OnceLock<Provider> = OnceLock::new();
+
+/// Read inputs through `provider` instead of the files of `data_dirs`.
+/// Only the first call has an effect, so set it before reading any input.
+pub fn set_provider(provider: impl InputProvider + Send + Sync + 'static) {
+    let _ = PROVIDER.set(Box::new(provider));
+}
+
+/// Read an input such as `inputs/01-example.txt` through the provider set
+/// with `set_provider`, by default from the first of the `data_dirs`
+/// holding it, normalized.
+pub fn read_as_string(day: u8, filename: &str) -> Result<String, AocError> {
+    let input = provider().input(day, filename)?;
+    Ok(normalize(&input).into_owned())
+}
+
+/// The provider set with `set_provider`, or the files of the `data_dirs`.
+fn provider() -> &'static Provider {
+    PROVIDER.get_or_init(|| {
+        #[cfg(feature = "embedded-examples")]
+        let provider = (Files, crate::provider::Embedded);
+        #[cfg(not(feature = "embedded-examples"))]
+        let provider = Files;
+        Box::new(provider)
+    })
+}
+
+/// Fetch an input over HTTP(S), e.g. from a private gist, sending `cookie`
+/// (such as `session=...`) when given.
+pub fn read_from_url(url: &str, cookie: Option<&str>) -> io::Result<String> {
+    let headers: Vec<(&str, &str)> =
+        cookie.map(|c| ("Cookie", c)).into_iter().collect();
+    http::get(url, &headers).map(|s| normalize(&s).into_owned())
+}
+
+/// Read an input from a URL, sending `cookie` as for `read_from_url`, or
+/// from any file path.
+pub fn read_from(source: &str, cookie: Option<&str>) -> io::Result<String> {
+    if source.contains("://") {
+        read_from_url(source, cookie)
+    } else {
+        let input = fs::read_to_string(source).map_err(|e| {
+            io::Error::new(e.kind(), format!("{source}: {e}"))
+        })?;
+        Ok(normalize(&input).into_owned())
+    }
+}
+
+/// Where the session cookie is kept when `AOC_COOKIE` is not set.
+pub const SESSION_PATH: &str = ".aoc/session";
+
+/// The puzzle input of a day of the `year` on adventofcode.com.
+pub fn input_url(day: u8) -> String {
+    format!("https://adventofcode.com/{}/day/{day}/input", year())
+}
+
+/// The adventofcode.com session cookie from `AOC_COOKIE` or the
+/// `.aoc/session` file, as `session=...` even if only the token is given.
+pub fn session_cookie() -> Option<String> {
+    session_cookie_from(Path::new(SESSION_PATH))
+}
+
+/// Like `session_cookie`, with the file at `path` as the fallback.
+pub fn session_cookie_from(path: &Path) -> Option<String> {
+    let cookie = std::env::var("AOC_COOKIE")
+        .ok()
+        .or_else(|| fs::read_to_string(path).ok())?;
+    cookie_header(&cookie)
+}
+
+/// `session=<token>` for a bare token, the cookie itself otherwise.
+pub fn cookie_header(cookie: &str) -> Option<String> {
+    match cookie.trim() {
+        "" => None,
+        c if c.contains('=') => Some(c.to_string()),
+        token => Some(format!("session={token}")),
+    }
+}
+
+pub fn read_input(day: u8) -> String {
+    read_as_string(day, "input").unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// The first example of `day`, e.g. `inputs/01-example.txt`.
+pub fn read_example(day: u8) -> Result<String, AocError> {
+    read_example_n(day, 1)
+}
+
+/// The `n`th example of `day`, counted from 1, e.g.
+/// `inputs/14-example-2.txt` for 2.
+pub fn read_example_n(day: u8, n: usize) -> Result<String, AocError> {
+    read_as_string(day, &example_name(n))
+}
+
+/// The name of the `n`th example file, `example` for the first and
+/// `example-N` for the others.
+pub fn example_name(n: usize) -> String {
+    match n {
+        0 | 1 => "example".to_string(),
+        n => format!("example-{n}"),
+    }
+}
+
+/// Which example `name` is, counted from 1, if it is one.
+fn example_number(name: &str) -> Option<usize> {
+    match name.strip_prefix("example")? {
+        "" => Some(1),
+        n => n.strip_prefix('-')?.parse().ok().filter(|&n| n > 1),
+    }
+}
+
+/// The names of the example files of `day` the provider has, in order,
+/// e.g. `example` and `example-2`.
+pub fn example_files(day: u8) -> Vec<String> {
+    let mut examples: Vec<(usize, String)> = provider()
+        .filenames(day)
+        .into_iter()
+        .filter_map(|name| Some((example_number(&name)?, name)))
+        .collect();
+    examples.sort();
+    examples.into_iter().map(|(_, name)| name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources() {
+        let path = "inputs/01-example.txt";
+        assert_eq!(read_from(path, None).unwrap(), read_example(1).unwrap());
+        let url = format!(
+            "file://{}",
+            std::env::current_dir().unwrap().join(path).display()
+        );
+        assert_eq!(read_from(&url, None).unwrap(), read_example(1).unwrap());
+        let error = read_from("inputs/missing.txt", None).unwrap_err();
+        assert!(error.to_string().starts_with("inputs/missing.txt: "));
+        let error = read_as_string(1, "missing").unwrap_err();
+        assert!(matches!(error, AocError::Missing { .. }));
+        assert!(error
+            .to_string()
+            .starts_with("expected file at inputs/01-missing.txt"));
+    }
+
+    #[test]
+    fn examples() {
+        assert_eq!(example_files(14), ["example", "example-2"]);
+        assert_eq!(example_files(1), ["example"]);
+        assert_eq!(
+            read_example_n(19, 2).unwrap(),
+            read_as_string(19, "example-2").unwrap()
+        );
+        assert!(matches!(
+            read_example_n(1, 2),
+            Err(AocError::Missing { .. })
+        ));
+        assert_eq!(
+            [
+                "example",
+                "example-2",
+                "example-10",
+                "example-1",
+                "examples"
+            ]
+            .map(example_number),
+            [Some(1), Some(2), Some(10), None, None]
+        );
+    }
+
+    #[test]
+    fn years() {
+        assert_eq!(year(), DEFAULT_YEAR);
+        assert_eq!(year_dir("inputs", DEFAULT_YEAR), "inputs");
+        assert_eq!(year_dir("inputs", 2021), "inputs/2021");
+        assert!(input_url(1).ends_with(".com/2020/day/1/input"));
+    }
+
+    #[test]
+    fn cookies() {
+        assert_eq!(cookie_header("53616c74\n").unwrap(), "session=53616c74");
+        assert_eq!(
+            cookie_header("session=53616c74").unwrap(),
+            "session=53616c74"
+        );
+        assert_eq!(cookie_header(" "), None);
+    }
+}
@@ -0,0 +1,97 @@
+//! Minimal JSON Helpers
+//!
+//! Just enough JSON to write reports and read back the flat, one-object-per
+//! line records this crate produces itself, without pulling in a serializer.
+
+use std::collections::HashMap;
+
+/// JSON string literal for `s`, also valid as a TOML basic string.
+pub fn quote(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for ch in s.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            ch if ch.is_control() => {
+                quoted.push_str(&format!("\\u{:04x}", ch as u32))
+            }
+            ch => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Parse a JSON object whose values are all strings or numbers.
+pub fn parse_flat_object(line: &str) -> Option<HashMap<String, String>> {
+    let mut chars = line.trim().strip_prefix('{')?.chars().peekable();
+    let mut fields = HashMap::new();
+
+    fn string(chars: &mut impl Iterator<Item = char>) -> Option<String> {
+        let mut s = String::new();
+        loop {
+            match chars.next()? {
+                '"' => return Some(s),
+                '\\' => match chars.next()? {
+                    'n' => s.push('\n'),
+                    't' => s.push('\t'),
+                    'u' => {
+                        let hex: String = chars.take(4).collect();
+                        s.push(char::from_u32(
+                            u32::from_str_radix(&hex, 16).ok()?,
+                        )?);
+                    }
+                    ch => s.push(ch),
+                },
+                ch => s.push(ch),
+            }
+        }
+    }
+
+    loop {
+        match chars.find(|ch| !ch.is_whitespace() && *ch != ',')? {
+            '}' => return Some(fields),
+            '"' => {
+                let key = string(&mut chars)?;
+                chars.find(|&ch| ch == ':')?;
+                while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+                let value = if chars.next_if_eq(&'"').is_some() {
+                    string(&mut chars)?
+                } else {
+                    let mut value = String::new();
+                    while let Some(ch) =
+                        chars.next_if(|ch| !matches!(ch, ',' | '}'))
+                    {
+                        value.push(ch);
+                    }
+                    value.trim().to_string()
+                };
+                fields.insert(key, value);
+            }
+            _ => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quoting() {
+        assert_eq!(quote("a\"b\\c\n\u{1}"), r#""a\"b\\c\n\u0001""#);
+    }
+
+    #[test]
+    fn flat_object() {
+        let fields =
+            parse_flat_object(r#"{"a": "x,\"y\"}", "b": 12 , "c":"µ"}"#)
+                .unwrap();
+        assert_eq!(fields["a"], "x,\"y\"}");
+        assert_eq!(fields["b"], "12");
+        assert_eq!(fields["c"], "µ");
+        assert!(parse_flat_object("not json").is_none());
+    }
+}
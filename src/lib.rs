@@ -1,39 +1,268 @@
-use std::fs;
-
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
-
-pub fn read_as_string(day: u8, filename: &str) -> String {
-    let filename = format!("inputs/{day:02}-{filename}.txt");
-    fs::read_to_string(filename).unwrap()
-}
-
-pub fn read_input(day: u8) -> String {
-    read_as_string(day, "input")
-}
-pub fn read_example(day: u8) -> String {
-    read_as_string(day, "example")
+//! Advent of Code
+//!
+//! The day modules of each year live under a module of their own, e.g.
+//! `aoc::year2020::day01`, and register with the `registry` keyed by year
+//! and day. Their solvers build on `core` and `alloc` alone, so without the
+//! default `std` feature the crate is `no_std` and runs on embedded and
+//! bare WASM targets. That core holds the days whose algorithms need no
+//! hash collections, the `Solver` trait and its registry, `Answer` and
+//! `AocError`. The `std` feature adds the days built on
+//! `FastMap`/`FastSet`, reading input files and the modules of the runner.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::any::Any;
+
+pub use answer::Answer;
+pub use error::AocError;
+#[cfg(feature = "std")]
+pub use inputs::*;
+#[cfg(feature = "std")]
+pub use run::{run_all, DayResult};
+pub use solver::{Part, Solver};
+
+/// Open a profiling scope and a `tracing` span, with any fields, e.g.
+/// `scope!("cycle", i)`, until the end of the enclosing block. Compiles to
+/// nothing unless the `profiling` or the `tracing` feature is enabled.
+macro_rules! scope {
+    ($name:literal $(, $($field:tt)+)?) => {
+        #[cfg(feature = "profiling")]
+        profiling::scope!($name);
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!($name $(, $($field)+)?).entered();
+    };
+}
+
+/// Register the `Solution` of a day module as its `PUZZLE` in the `YEAR`
+/// of the parent module, e.g. `register!(1, "Report Repair");`.
+macro_rules! register {
+    ($day:expr, $title:expr) => {
+        /// This day in the `PUZZLES` of its year.
+        pub const PUZZLE: $crate::registry::Puzzle =
+            $crate::registry::Puzzle::new::<Solution>(
+                super::YEAR,
+                $day,
+                $title,
+            );
+    };
+}
+
+/// Declare the day modules of a year and collect their registrations in
+/// its `PUZZLES`.
+macro_rules! days {
+    ($($(#[$meta:meta])* $day:ident),* $(,)?) => {
+        $($(#[$meta])* pub mod $day;)*
+
+        /// Every registered puzzle, in order of day.
+        pub const PUZZLES: &[registry::Puzzle] =
+            &[$($(#[$meta])* $day::PUZZLE,)*];
+    };
+}
+
+/// A test of the `Solution` in scope against example files, each part
+/// reading `NN-example.txt` unless it names another and as many files as
+/// it is listed with, e.g.
+/// `aoc_example_tests!(day = 14, part_one = 165, part_two = 208 in "example-2");`.
+/// Answers are numbers or strings, compared as the runner prints them.
+#[cfg(all(test, feature = "std"))]
+macro_rules! aoc_example_tests {
+    (day = $day:literal
+        $(, $part:ident = $answer:literal $(in $file:literal)?)* $(,)?) => {
+        #[test]
+        fn examples() {
+            $({
+                let file: &str = [$($file,)? "example"][0];
+                let part = aoc_example_tests!(@part $part);
+                let input = $crate::read_as_string($day, file).unwrap();
+                let answer =
+                    $crate::solver::solve::<Solution>(part, &input).unwrap();
+                let expected = stringify!($answer).trim_matches('"');
+                assert_eq!(
+                    answer.to_string(),
+                    expected,
+                    "day {} part {part} of {file}",
+                    $day
+                );
+            })*
+        }
+    };
+    (@part part_one) => { 1 };
+    (@part part_two) => { 2 };
+}
+
+/// Declare the modules that need the standard library.
+macro_rules! with_std {
+    ($($item:item)*) => {
+        $(#[cfg(feature = "std")] $item)*
+    };
+}
+
+pub mod answer;
+pub mod arena;
+pub mod automaton;
+pub mod cancel;
+pub mod circular;
+pub mod csp;
+pub mod error;
+pub mod expr;
+pub mod grammar;
+pub mod grid;
+pub mod hex;
+pub mod image;
+pub mod math;
+pub mod provider;
+pub mod registry;
+pub mod solver;
+pub mod vm;
+
+pub mod year2020;
+
+/// Every registered year, in order.
+pub const YEARS: &[registry::Year] = &[registry::Year {
+    year: year2020::YEAR,
+    puzzles: year2020::PUZZLES,
+}];
+
+with_std! {
+    pub mod bench;
+    pub mod cache;
+    pub mod collections;
+    pub mod config;
+    pub mod crosscheck;
+    pub mod doctor;
+    pub mod expectations;
+    pub mod expected;
+    pub mod generate;
+    pub mod history;
+    pub mod http;
+    mod inputs;
+    mod json;
+    pub mod memory;
+    pub mod metrics;
+    pub mod report;
+    pub mod rng;
+    pub mod run;
+    pub mod sandbox;
+    pub mod scramble;
+    pub mod search;
+    pub mod selection;
+    pub mod server;
+    pub mod stress;
+    pub mod submit;
+    pub mod term;
+    pub mod timeout;
+    pub mod timing;
+    pub mod validate;
+    pub mod variants;
+}
+
+#[cfg(feature = "embedded-examples")]
+pub mod examples;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+/// The answer of `part` of `day` of the default year for `input`, e.g.
+/// `aoc::solve(1, Part::One, &input)`, after `normalize`-ing its line
+/// endings. Other years are `registry::find(year, day)`.
+pub fn solve(day: u8, part: Part, input: &str) -> Result<Answer, AocError> {
+    let year = registry::DEFAULT_YEAR;
+    registry::find(year, day)
+        .ok_or(AocError::UnknownPuzzle { year, day })?
+        .solve(part.number(), &normalize(input))
+}
+
+/// The message a panic was raised with, e.g. the error of an `unwrap`.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<String>() {
+        Some(message) => message.clone(),
+        None => match payload.downcast_ref::<&str>() {
+            Some(message) => message.to_string(),
+            None => "panicked".to_string(),
+        },
+    }
+}
+
+/// Strip a leading byte order mark and convert CRLF line endings to LF, so
+/// inputs saved on Windows parse like downloaded ones.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    if input.contains('\r') {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Trim surrounding whitespace and a leading byte order mark.
+pub(crate) fn trim(input: &str) -> &str {
+    input.trim_start_matches('\u{feff}').trim()
+}
+
+/// The blocks of `input` separated by blank lines, with LF or CRLF line
+/// endings.
+pub(crate) fn paragraphs(input: &str) -> Vec<&str> {
+    let input = trim(input);
+    let mut blocks = Vec::new();
+    let (mut start, mut end) = (0, 0);
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if end > start {
+                blocks.push(input[start..end].trim_end());
+            }
+            start = end + line.len();
+        }
+        end += line.len();
+    }
+    if start < input.len() {
+        blocks.push(&input[start..]);
+    }
+    blocks
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch() {
+        let input = read_as_string(1, "example").unwrap();
+        let crlf = input.replace('\n', "\r\n");
+        let answer = solve(1, Part::One, &crlf).unwrap();
+        assert_eq!(answer.to_string(), "514579");
+        assert_eq!(
+            solve(21, Part::Two, &read_as_string(21, "example").unwrap())
+                .unwrap()
+                .to_string(),
+            "mxmxvkd,sqjhc,fvjkl"
+        );
+        let error = solve(26, Part::One, &input).unwrap_err();
+        assert_eq!(error.to_string(), "no puzzle for day 26 of 2020");
+    }
+
+    #[test]
+    fn line_endings() {
+        assert_eq!(normalize("\u{feff}1\r\n2\r\n"), "1\n2\n");
+        assert!(matches!(normalize("1\n2\n"), Cow::Borrowed(_)));
+        assert_eq!(trim("\u{feff}1\r\n2\r\n"), "1\r\n2");
+    }
+
+    #[test]
+    fn blank_lines() {
+        assert_eq!(paragraphs("a\nb\n\nc\n"), ["a\nb", "c"]);
+        assert_eq!(
+            paragraphs("\u{feff}a\r\nb\r\n\r\nc\r\n"),
+            ["a\r\nb", "c"]
+        );
+        assert_eq!(paragraphs("a\n\n\n\nb"), ["a", "b"]);
+        assert!(paragraphs("").is_empty());
+    }
 }
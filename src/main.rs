@@ -1,86 +1,2045 @@
+use std::cell::{Cell, OnceCell};
+use std::collections::BTreeMap;
 use std::env;
-use std::fmt::Display;
-use std::time::SystemTime;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::panic;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc::cancel::CancelToken;
+use aoc::memory::{format_bytes, CountingAllocator};
+use aoc::metrics::{Metrics, Outcome};
+use aoc::registry::{Puzzle, DEFAULT_YEAR};
+use aoc::solver::Prepared;
+use aoc::term::{paint, Style};
+use aoc::timing::{format_duration, Phases, Stopwatch};
+use aoc::{Answer, AocError};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 fn main() {
-    macro_rules! puzzle {
-        ($mod:ident, $title:expr) => {
-            (
-                $title,
-                |input| Box::new(aoc::$mod::part_one(input)),
-                |input| Box::new(aoc::$mod::part_two(input)),
-            )
+    #[cfg(feature = "profile-with-puffin")]
+    let _server = {
+        profiling::puffin::set_scopes_on(true);
+        let server = puffin_http::Server::new("127.0.0.1:8585").unwrap();
+        eprintln!("waiting for puffin_viewer to connect to 127.0.0.1:8585");
+        while server.num_clients() == 0 {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        server
+    };
+    #[cfg(feature = "profile-with-tracy")]
+    profiling::tracy_client::Client::start();
+
+    init_logging();
+    init_panic_hook();
+    let data_dir = flag_value("--data-dir")
+        .or_else(|| env::var("AOC_DATA_DIR").ok())
+        .or_else(|| config().data_dir.clone());
+    if let Some(dir) = data_dir {
+        aoc::set_data_dir(&dir);
+    }
+    aoc::set_year(year());
+    preamble();
+    match env::args().nth(1).as_deref() {
+        Some("scramble") => return scramble(),
+        Some("answers") => return answers(),
+        Some("history") => return history(),
+        Some("crosscheck") => return crosscheck(),
+        Some("difftest") => return difftest(),
+        Some("stress") => return stress(),
+        Some("list") => return list(),
+        Some("generate") => return generate(),
+        Some("doctor") => return doctor(),
+        Some("validate") => return validate(),
+        Some("selftest") => return selftest(),
+        Some("fetch") => return fetch(),
+        Some("submit") => return submit(),
+        Some("bench") => return bench(),
+        Some("sandboxed") => return sandboxed_child(),
+        Some("serve") => return serve(),
+        _ => {}
+    }
+    if let Some(path) = flag_value("--replay") {
+        return replay(&path);
+    }
+    aoc::cancel::cancel_on_interrupt(interrupt());
+
+    let filename = &input_filename();
+    let stats = env::args().any(|a| a == "--stats");
+    let show_time = env::args().any(|a| a == "--time")
+        || stats
+        || phases()
+        || repeat() > 1
+        || config().time == Some(true);
+    let save = env::args().any(|a| a == "--save-answers");
+    profile();
+    if save && (filename != "input" || flag_value("--input").is_some()) {
+        eprintln!(
+            "--save-answers only saves answers of the real inputs; \
+             example answers are declared in src/expected.rs"
+        );
+        std::process::exit(2);
+    }
+
+    let mut days = selected_days(puzzles().len());
+    let parts = selected_parts();
+    let slow = flag_value("--slower-than").map(|v| slow_parts(&v, filename));
+    if let Some(slow) = &slow {
+        days.retain(|&d| slow.iter().any(|&(day, _)| day as usize == d));
+    }
+    let jobs = jobs();
+    if stats && jobs > 1 {
+        eprintln!(
+            "--stats is ignored with --jobs: all threads share the heap"
+        );
+    }
+    let stats = stats && jobs <= 1;
+
+    let mut entries = Vec::new();
+    let markdown = env::args().any(|a| a == "--markdown")
+        || config().format.as_deref() == Some("markdown");
+    let report = |(output, results): (String, Vec<aoc::report::Entry>)| {
+        if !markdown && !ndjson() {
+            print!("{output}");
+        }
+        entries.extend(results);
+        #[cfg(feature = "profiling")]
+        profiling::finish_frame!();
+    };
+    // answers of other inputs are unknown
+    let known = match flag_value("--input") {
+        Some(_) => Vec::new(),
+        None => known_answers(filename).unwrap_or_default(),
+    };
+    let solve = |day: usize| {
+        let parts: Vec<u8> = match &slow {
+            Some(slow) => parts
+                .iter()
+                .copied()
+                .filter(|&p| slow.contains(&(day as u8, p)))
+                .collect(),
+            None => parts.clone(),
         };
+        solve_day(day, &parts, filename, show_time, stats, &known)
+    };
+    if jobs <= 1 {
+        days.iter()
+            .take_while(|_| !interrupt().is_cancelled())
+            .map(|&day| solve(day))
+            .for_each(report);
+    } else {
+        in_parallel(&days, jobs, solve, report);
+    }
+    // an interrupted run is neither recorded nor checked
+    if interrupt().is_cancelled() {
+        std::process::exit(130);
+    }
+    #[cfg(feature = "profile-with-puffin")]
+    {
+        // give the server a moment to send the last frame
+        std::thread::sleep(std::time::Duration::from_secs(1));
     }
 
-    type SolverFn = fn(&str) -> Box<dyn Display>;
-
-    let puzzles: Vec<(&str, SolverFn, SolverFn)> = vec![
-        puzzle!(day01, "Historian Hysteria"),
-        puzzle!(day02, "Password Philosophy"),
-        puzzle!(day03, "Toboggan Trajectory"),
-        puzzle!(day04, "Passport Processing"),
-        puzzle!(day05, "Binary Boarding"),
-        puzzle!(day06, "Custom Customs"),
-        puzzle!(day07, "Handy Haversacks"),
-        puzzle!(day08, "Handheld Halting"),
-        puzzle!(day09, "Encoding Error"),
-        puzzle!(day10, "Adapter Array"),
-        puzzle!(day11, "Seating System"),
-        puzzle!(day12, "Rain Risk"),
-        puzzle!(day13, "Shuttle Search"),
-        puzzle!(day14, "Docking Data"),
-        puzzle!(day15, "Rambunctious Recitation"),
-        puzzle!(day16, "Ticket Translation"),
-        puzzle!(day17, "Conway Cubes"),
-        puzzle!(day18, "Operation Order"),
-        puzzle!(day19, "Monster Messages"),
-        puzzle!(day20, "Jurassic Jigsaw"),
-        puzzle!(day21, "Allergen Assessment"),
-        puzzle!(day22, "Crab Combat"),
-        puzzle!(day23, "Crab Cups"),
-        puzzle!(day24, "Lobby Layout"),
-        puzzle!(day25, "Combo Breaker"),
-    ];
-
-    let filename = match env::args().find(|a| a == "--example") {
-        None => "input",
-        Some(_) => "example",
-    };
-
-    let show_time = env::args().any(|a| a == "--time");
+    if markdown {
+        print!("{}", aoc::report::to_markdown(&entries));
+    } else if show_time
+        && days.len() > 1
+        && !quiet()
+        && !ndjson()
+        && template().is_none()
+    {
+        print!("{}", aoc::report::bar_chart(&entries, 40));
+        print!("\n{}", aoc::report::summary(&entries));
+    }
+    // other inputs would skew the history of the real ones, and cached
+    // answers were recorded when they were solved
+    if show_time && flag_value("--input").is_none() && cache().is_none() {
+        record_history(filename, &entries);
+    }
+    if let Some(path) = flag_value("--record-run") {
+        if let Err(e) = fs::write(&path, aoc::report::to_ndjson(&entries)) {
+            eprintln!("failed to write {path}: {e}");
+            std::process::exit(2);
+        }
+    }
+    if save {
+        save_answers(&entries);
+    }
+    if let Some(path) = flag_value("--html") {
+        let html = aoc::report::to_html(&entries, &known);
+        if let Err(e) = fs::write(&path, html) {
+            eprintln!("failed to write {path}: {e}");
+            std::process::exit(2);
+        }
+    }
+    if let Some(url) = flag_value("--notify") {
+        let json = aoc::report::to_notification(&entries, &known);
+        if let Err(e) = aoc::http::post_json(&url, &json) {
+            eprintln!("failed to notify {url}: {e}");
+        }
+    }
+    if env::args().any(|a| a == "--save-baseline") {
+        save_baseline(filename, &entries);
+    }
+    if env::args().any(|a| a == "--compare-baseline") {
+        compare_baseline(filename, &entries);
+    }
+    if env::args().any(|a| a == "--check") {
+        check_answers(filename, &entries);
+    }
+    if entries.iter().any(|e| failed(&e.answer)) {
+        std::process::exit(1);
+    }
+}
 
-    let mut days: Vec<usize> =
-        env::args().filter_map(|a| a.parse().ok()).collect();
+/// The days and parts whose latest recorded `--time` run on `filename`
+/// inputs took longer than `threshold`, e.g. `200ms`.
+fn slow_parts(threshold: &str, filename: &str) -> Vec<(u8, u8)> {
+    let threshold =
+        aoc::timeout::parse_duration(threshold).unwrap_or_else(|e| {
+            eprintln!("--slower-than: {e}");
+            std::process::exit(2);
+        });
+    let path = history_path();
+    let path = Path::new(&path);
+    let records = aoc::history::load(path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+    if !records.iter().any(|r| r.input == filename) {
+        eprintln!("no recorded timings; run with --time first");
+        std::process::exit(2);
+    }
+    let slow = aoc::history::slower_than(&records, filename, threshold);
+    if slow.is_empty() {
+        eprintln!("no part took longer than {threshold:?} in its last run");
+    }
+    slow
+}
+
+/// The file of the known answers of real inputs, `--answers` or
+/// `answers.toml`, `answers-NNNN.toml` for a year other than the default.
+fn answers_path() -> String {
+    flag_value("--answers").unwrap_or_else(|| match year() {
+        DEFAULT_YEAR => "answers.toml".to_string(),
+        year => format!("answers-{year}.toml"),
+    })
+}
+
+/// The timing history of the `year`, kept apart from the default year's
+/// `.aoc/history.jsonl` as its days are other puzzles.
+fn history_path() -> String {
+    match year() {
+        DEFAULT_YEAR => aoc::history::DEFAULT_PATH.to_string(),
+        year => format!(".aoc/history-{year}.jsonl"),
+    }
+}
+
+/// The known answers for `filename` inputs: the example answers of
+/// `expected.rs` for the example files the parts read, or those of the
+/// `--answers` file (`answers.toml` by default) for real inputs.
+fn known_answers(filename: &str) -> io::Result<Vec<(u8, u8, String)>> {
+    if filename != "input" {
+        return Ok(aoc::expected::examples()
+            .iter()
+            .filter(|e| e.file == part_file(e.day as usize, e.part, filename))
+            .map(|e| (e.day, e.part, e.answer.to_string()))
+            .collect());
+    }
+    let path = answers_path();
+    let content = fs::read_to_string(&path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{path}: {e}")))?;
+    Ok(aoc::report::from_toml(&content)
+        .into_iter()
+        .map(|e| (e.day, e.part, e.answer))
+        .collect())
+}
+
+/// Solve the selected parts of a day, returning what to print and the
+/// entries of the parts.
+fn solve_day(
+    day: usize,
+    parts: &[u8],
+    filename: &str,
+    show_time: bool,
+    stats: bool,
+    known: &[(u8, u8, String)],
+) -> (String, Vec<aoc::report::Entry>) {
+    let color = aoc::term::enabled();
+    let puzzle = puzzle(day);
+    let title = puzzle.title;
+    let input = match flag_value("--input") {
+        Some(source) => {
+            let cookie = session_cookie();
+            aoc::read_from(&source, cookie.as_deref()).unwrap_or_else(|e| {
+                eprintln!("failed to read input: {e}");
+                std::process::exit(2);
+            })
+        }
+        None => read(day as u8, filename),
+    };
+
+    let mut out = String::new();
+    writeln!(out, "--- Day {day}: {title} ---").unwrap();
+    let mut results = Vec::new();
+    let mut means = Vec::new();
+    let repeat = repeat();
+    // both parts on one parse, unless each run is to parse as well or
+    // `--algo` or `--preamble` pick a solver of their own
+    let shared = OnceCell::new();
+    let shared = (repeat == 1
+        && flag_value("--algo").is_none()
+        && flag_value("--preamble").is_none())
+    .then_some(&shared);
+    for &part in parts {
+        if interrupt().is_cancelled() {
+            break;
+        }
+        // the best of the runs is the least disturbed one
+        let runs: Vec<aoc::report::Entry> = (0..repeat)
+            .map(|_| {
+                run_shared(puzzle, day, part, filename, &input, stats, shared)
+            })
+            .collect();
+        let durations: Vec<Duration> =
+            runs.iter().map(|e| e.duration).collect();
+        means.push(aoc::bench::summarize(&durations).mean);
+        let entry = runs.into_iter().min_by_key(|e| e.duration).unwrap();
+        if ndjson() {
+            // stdout is line buffered, so each part shows up immediately
+            print!(
+                "{}",
+                aoc::report::to_ndjson(std::slice::from_ref(&entry))
+            );
+        }
+        let name = if part == 1 { "One" } else { "Two" };
+        let answer = match known
+            .iter()
+            .find(|(d, p, _)| *d as usize == day && *p == part)
+        {
+            _ if !color => entry.answer.clone(),
+            _ if failed(&entry.answer) => paint(&entry.answer, Style::Red),
+            Some((_, _, a)) if *a == entry.answer => {
+                paint(&entry.answer, Style::Green)
+            }
+            Some(_) => paint(&entry.answer, Style::Red),
+            None => entry.answer.clone(),
+        };
+        writeln!(out, "Part {name}: {answer}").unwrap();
+        results.push(entry);
+    }
+    if let Some(template) = template() {
+        let out = results
+            .iter()
+            .map(|e| aoc::report::format_entry(&template, e).unwrap() + "\n")
+            .collect();
+        return (out, results);
+    }
+    if quiet() {
+        let out = results
+            .iter()
+            .map(|e| format!("{} {} {}\n", e.day, e.part, e.answer))
+            .collect();
+        return (out, results);
+    }
 
-    if days.is_empty() {
-        days = (1..=puzzles.len()).collect();
+    if show_time {
+        let durations: Vec<String> = results
+            .iter()
+            .zip(&means)
+            .map(|(e, &mean)| match repeat {
+                1 => format_duration(e.duration),
+                _ => format!(
+                    "{} best / {} avg",
+                    format_duration(e.duration),
+                    format_duration(mean)
+                ),
+            })
+            .collect();
+        let durations = format!("({})", durations.join(", "));
+        let durations = match color {
+            true => paint(&durations, Style::Dim),
+            false => durations,
+        };
+        writeln!(out, "Duration: {durations}").unwrap();
+        if phases() {
+            // parts of --algo and --preamble are only timed as a whole
+            let phases: Vec<String> = results
+                .iter()
+                .map(|e| e.phases.map_or("-".to_string(), |p| p.to_string()))
+                .collect();
+            writeln!(out, "Phases: ({})", phases.join(", ")).unwrap();
+        }
+        // expectations hold for the default implementations on the real
+        // inputs and optimized builds only
+        if filename == "input"
+            && flag_value("--input").is_none()
+            && !cfg!(debug_assertions)
+            && flag_value("--algo").is_none()
+        {
+            for e in &results {
+                let check = aoc::expectations::check;
+                if let Some(warning) = check(e.day, e.part, e.duration) {
+                    let warning = format!("Warning: {warning}");
+                    let warning = match color {
+                        true => paint(&warning, Style::Yellow),
+                        false => warning,
+                    };
+                    writeln!(out, "{warning}").unwrap();
+                }
+            }
+        }
     }
+    let peaks: Option<Vec<String>> = results
+        .iter()
+        .map(|e| e.peak_memory.map(format_bytes))
+        .collect();
+    if let Some(peaks) = peaks {
+        writeln!(out, "Peak memory: ({})", peaks.join(", ")).unwrap();
+    }
+    let peaks: Option<Vec<String>> = results
+        .iter()
+        .map(|e| e.peak_rss.map(format_bytes))
+        .collect();
+    if let Some(peaks) = peaks {
+        writeln!(out, "Peak RSS: ({})", peaks.join(", ")).unwrap();
+    }
+    writeln!(out).unwrap();
+    (out, results)
+}
+
+/// Call `solve` for every day on `jobs` threads, handing the results to
+/// `report` in the order of `days` as soon as all earlier days are done.
+fn in_parallel<T: Send>(
+    days: &[usize],
+    jobs: usize,
+    solve: impl Fn(usize) -> T + Sync,
+    mut report: impl FnMut(T),
+) {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let (tx, next, solve) = (tx.clone(), &next, &solve);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(i) else { break };
+                if tx.send((i, solve(day))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut done = 0;
+        for (i, result) in rx {
+            pending.insert(i, result);
+            while let Some(result) = pending.remove(&done) {
+                report(result);
+                done += 1;
+            }
+        }
+    });
+}
+
+/// `--check [--answers FILE]`: compare the computed answers with the known
+/// ones, taken from `expected.rs` for examples and from an `aoc answers
+/// --out answers.toml` export (`answers.toml` by default) for real inputs,
+/// exiting non-zero on any mismatch.
+fn check_answers(filename: &str, entries: &[aoc::report::Entry]) {
+    let expected = known_answers(filename).unwrap_or_else(|e| {
+        eprintln!("failed to read answers: {e}");
+        std::process::exit(2);
+    });
 
+    let mut failures = 0;
+    for e in entries {
+        let label = format!("Day {:>2} Part {}", e.day, e.part);
+        match expected
+            .iter()
+            .find(|(d, p, _)| *d == e.day && *p == e.part)
+        {
+            Some((_, _, answer)) if *answer == e.answer => {
+                println!("{label}  PASS")
+            }
+            Some((_, _, answer)) => {
+                failures += 1;
+                println!("{label}  FAIL  {} != {answer}", e.answer);
+            }
+            None => println!("{label}  SKIP  no expected answer"),
+        }
+    }
+    println!("{failures} failure(s)");
+    if failures > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Write the answers of `entries` into the `--answers` file for `--check`,
+/// keeping the saved answers of other days and parts.
+fn save_answers(entries: &[aoc::report::Entry]) {
+    let path = answers_path();
+    let saved = match fs::read_to_string(&path) {
+        Ok(content) => aoc::report::from_toml(&content),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => {
+            eprintln!("failed to read answers: {path}: {e}");
+            std::process::exit(2);
+        }
+    };
+    let merged = aoc::report::merge(&saved, entries);
+    fs::write(&path, aoc::report::to_toml(&merged)).unwrap();
+    println!("saved {} answer(s) to {path}", entries.len());
+}
+
+/// The timing baseline of `filename` inputs, `--baseline FILE` or
+/// `.aoc/baseline-input.jsonl`.
+fn baseline_path(filename: &str) -> String {
+    flag_value("--baseline")
+        .unwrap_or_else(|| format!(".aoc/baseline-{filename}.jsonl"))
+}
+
+/// Store the answers and timings of `entries` in the baseline, keeping
+/// those of other days and parts.
+fn save_baseline(filename: &str, entries: &[aoc::report::Entry]) {
+    let path = baseline_path(filename);
+    let saved = match fs::read_to_string(&path) {
+        Ok(content) => aoc::report::from_ndjson(&content),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => {
+            eprintln!("failed to read baseline: {path}: {e}");
+            std::process::exit(2);
+        }
+    };
+    let merged = aoc::report::merge(&saved, entries);
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir).unwrap();
+    }
+    fs::write(&path, aoc::report::to_ndjson(&merged)).unwrap();
+    println!("saved {} timing(s) to {path}", entries.len());
+}
+
+/// Compare `entries` with the baseline, exiting non-zero when a part got
+/// slower by more than `--threshold PCT` (default 25%) or changed its
+/// answer.
+fn compare_baseline(filename: &str, entries: &[aoc::report::Entry]) {
+    let path = baseline_path(filename);
+    let baseline = fs::read_to_string(&path)
+        .map(|content| aoc::report::from_ndjson(&content))
+        .unwrap_or_else(|e| {
+            eprintln!("failed to read baseline {path}: {e}");
+            std::process::exit(2);
+        });
+    let threshold = threshold();
+    let (output, regressions) =
+        aoc::report::regressions(&baseline, entries, threshold);
+    print!("{output}");
+    println!("{regressions} regression(s) against {path}");
+    if regressions > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// `aoc --replay run.jsonl [--example] [--threshold PCT]`: re-run the days of
+/// a `--record-run` recording and diff answers and timings against it,
+/// exiting non-zero on changed answers or shifts beyond `PCT` (default 25%).
+fn replay(path: &str) {
+    let recorded = match fs::read_to_string(path) {
+        Ok(content) => aoc::report::from_ndjson(&content),
+        Err(e) => {
+            eprintln!("failed to read {path}: {e}");
+            std::process::exit(2);
+        }
+    };
+    let threshold = threshold();
+    let filename = &input_filename();
+    let stats = env::args().any(|a| a == "--stats");
+
+    let mut days: Vec<usize> =
+        recorded.iter().map(|e| e.day as usize).collect();
+    days.dedup();
+    let mut entries = Vec::new();
     for day in days {
-        let (title, part1, part2) = &puzzles[day - 1];
-        let input = aoc::read_as_string(day as u8, filename);
-        let input = input.as_str();
-
-        println!("--- Day {day}: {title} ---");
-        let t0 = SystemTime::now();
-        println!("Part One: {}", part1(input));
-        let t1 = SystemTime::now();
-        if filename == "example" && day == 14 {
-            // example of day 14 part two has different input
-            let input = aoc::read_as_string(day as u8, "example-2");
-            let input = input.as_str();
-            println!("Part Two: {}", part2(input));
+        let puzzle = puzzle(day);
+        let input = read(day as u8, filename);
+        for e in recorded.iter().filter(|e| e.day as usize == day) {
+            entries.push(run(puzzle, day, e.part, filename, &input, stats));
+        }
+    }
+
+    let (output, differences) =
+        aoc::report::diff(&recorded, &entries, threshold);
+    print!("{output}");
+    println!("{differences} difference(s) against {path}");
+    if differences > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Append the timings of this run to the local history.
+fn record_history(filename: &str, entries: &[aoc::report::Entry]) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let toolchain = aoc::history::toolchain().unwrap_or_default();
+    let commit = aoc::history::commit().unwrap_or_default();
+    let records: Vec<aoc::history::Record> = entries
+        .iter()
+        // the duration of an abandoned part is only the timeout
+        .filter(|e| e.answer != TIMEOUT && !failed(&e.answer))
+        .filter(|e| !aoc::sandbox::is_violation(&e.answer))
+        .map(|e| aoc::history::Record {
+            timestamp,
+            toolchain: toolchain.clone(),
+            commit: commit.clone(),
+            input: filename.to_string(),
+            day: e.day,
+            part: e.part,
+            duration: e.duration,
+        })
+        .collect();
+    let path = history_path();
+    let path = Path::new(&path);
+    if let Err(e) = aoc::history::append(path, &records) {
+        eprintln!("failed to record history in {}: {e}", path.display());
+    }
+}
+
+/// The parsed input of a day, shared by its parts.
+type Shared<'a> = OnceCell<Box<dyn Prepared + 'a>>;
+
+/// Solve one part of a day, timing the solver alone and, with `stats`,
+/// measuring its heap high-water mark and the peak resident set size.
+fn run(
+    puzzle: Puzzle,
+    day: usize,
+    part: u8,
+    filename: &str,
+    input: &str,
+    stats: bool,
+) -> aoc::report::Entry {
+    run_shared(puzzle, day, part, filename, input, stats, None)
+}
+
+/// Like `run`, solving on the parsed input in `shared` if the other part
+/// left one there, and leaving its own otherwise.
+fn run_shared<'a>(
+    puzzle: Puzzle,
+    day: usize,
+    part: u8,
+    filename: &str,
+    input: &'a str,
+    stats: bool,
+    shared: Option<&Shared<'a>>,
+) -> aoc::report::Entry {
+    let alternate;
+    let file = part_file(day, part, filename);
+    // only for the input of the day, not a file of the part's own
+    let shared = shared.filter(|_| file == filename).map(|s| (s, input));
+    let input = match file != filename {
+        true => {
+            alternate = read(day as u8, &file);
+            &alternate
+        }
+        false => input,
+    };
+    let key = cache_key(day, part, input);
+    if let Some((cache, build)) = cache() {
+        let cache = cache.lock().unwrap();
+        if let Some(cached) = aoc::cache::lookup(&cache, key, build) {
+            return aoc::report::Entry {
+                day: day as u8,
+                title: puzzle.title.to_string(),
+                part,
+                answer: cached.answer.clone(),
+                duration: cached.duration,
+                peak_memory: None,
+                peak_rss: None,
+                phases: None,
+            };
+        }
+    }
+    let timeout = timeout();
+    let token = interrupt().child();
+    // the worker thread of a timeout needs an input of its own
+    let owned = timeout.map(|_| input.to_string());
+    let baseline = aoc::memory::current();
+    aoc::memory::reset_peak();
+    let rss = stats && aoc::memory::reset_peak_rss();
+    #[cfg(feature = "flamegraph")]
+    let profiler = profile().then(|| {
+        pprof::ProfilerGuard::new(997).expect("failed to start profiler")
+    });
+    let (answer, duration, phases) = match (sandbox(), timeout, owned) {
+        #[cfg(unix)]
+        (Some(limits), _, _) => {
+            let (answer, duration) = sandboxed(day, part, input, limits);
+            (answer, duration, None)
+        }
+        (None, Some(limit), Some(input)) => {
+            let timed =
+                move || timed(day, part, || solve(puzzle, day, part, &input));
+            aoc::timeout::run_with_timeout(limit, &token, timed)
+                .unwrap_or_else(|| (TIMEOUT.to_string(), limit, None))
+        }
+        _ => aoc::cancel::with(&token, || match shared {
+            Some((shared, input)) => {
+                timed(day, part, || solve_shared(puzzle, part, input, shared))
+            }
+            None => timed(day, part, || solve(puzzle, day, part, input)),
+        }),
+    };
+    let peak_memory = stats.then(|| aoc::memory::peak() - baseline);
+    let peak_rss = rss.then(aoc::memory::peak_rss).flatten();
+    #[cfg(feature = "flamegraph")]
+    if let Some(profiler) = profiler {
+        write_flamegraph(profiler, day, part);
+    }
+    if let (Some((cache, build)), true) = (cache(), solved(&answer)) {
+        let mut cache = cache.lock().unwrap();
+        let cached = aoc::cache::Cached {
+            key,
+            build,
+            day: day as u8,
+            part,
+            answer: answer.clone(),
+            duration,
+        };
+        aoc::cache::insert(&mut cache, cached);
+        let path = Path::new(aoc::cache::DEFAULT_PATH);
+        if let Err(e) = aoc::cache::save(path, &cache) {
+            eprintln!("failed to cache answers in {}: {e}", path.display());
+        }
+    }
+    aoc::report::Entry {
+        day: day as u8,
+        title: puzzle.title.to_string(),
+        part,
+        answer,
+        duration,
+        peak_memory,
+        peak_rss,
+        phases,
+    }
+}
+
+/// The cache key of a part solved on `input` with the solver options.
+fn cache_key(day: usize, part: u8, input: &str) -> u64 {
+    let options: Vec<String> = ["--algo", "--preamble"]
+        .into_iter()
+        .filter_map(|flag| Some(format!("{flag} {}", flag_value(flag)?)))
+        .collect();
+    let options: Vec<&str> = options.iter().map(String::as_str).collect();
+    aoc::cache::key(year(), day as u8, part, input, &options)
+}
+
+/// Whether `answer` is an answer, not the outcome of a part that failed,
+/// timed out, was cancelled or broke the limits of its sandbox.
+fn solved(answer: &str) -> bool {
+    answer != TIMEOUT
+        && answer != CANCELLED
+        && !failed(answer)
+        && !aoc::sandbox::is_violation(answer)
+}
+
+/// The answers cached by earlier runs and the build id of this executable,
+/// when `--cached` reuses them.
+fn cache() -> Option<(&'static Mutex<Vec<aoc::cache::Cached>>, u64)> {
+    static CACHE: OnceLock<(Mutex<Vec<aoc::cache::Cached>>, u64)> =
+        OnceLock::new();
+    if !env::args().any(|a| a == "--cached") {
+        return None;
+    }
+    let (cache, build) = CACHE.get_or_init(|| {
+        let path = Path::new(aoc::cache::DEFAULT_PATH);
+        let cache = aoc::cache::load(path).unwrap_or_else(|e| {
+            eprintln!("failed to load {}: {e}", path.display());
+            Vec::new()
+        });
+        (Mutex::new(cache), aoc::cache::build_id())
+    });
+    Some((cache, *build))
+}
+
+/// Solve one part with `solve`, returning the answer, how long it took
+/// and, unless `--algo` or `--preamble` picked another solver, how long its
+/// phases took.
+fn timed(
+    day: usize,
+    part: u8,
+    solve: impl FnOnce() -> Result<(Answer, Option<Phases>), AocError>,
+) -> (String, Duration, Option<Phases>) {
+    let stopwatch = Stopwatch::start();
+    #[cfg(feature = "profiling")]
+    profiling::scope!("solve", &format!("day {day} part {part}"));
+    SOLVING.set(true);
+    // a part that panics leaves nothing half-done behind: the parsed input
+    // it shares is never mutated
+    let solved = panic::catch_unwind(panic::AssertUnwindSafe(solve));
+    SOLVING.set(false);
+    let duration = stopwatch.elapsed();
+    match solved {
+        Ok(Ok((answer, phases))) => (answer.to_string(), duration, phases),
+        Ok(Err(AocError::Cancelled)) => {
+            (CANCELLED.to_string(), duration, None)
+        }
+        Ok(Err(error)) => {
+            let message = format!("Day {day} part {part}: {error}");
+            match aoc::term::enabled() {
+                true => eprintln!("{}", paint(&message, Style::Red)),
+                false => eprintln!("{message}"),
+            }
+            (ERROR.to_string(), duration, None)
+        }
+        Err(payload) => {
+            let location = PANIC_LOCATION.take().unwrap_or_default();
+            let message = format!(
+                "Day {day} part {part} panicked{location}: {}",
+                aoc::panic_message(payload.as_ref())
+            );
+            match aoc::term::enabled() {
+                true => eprintln!("{}", paint(&message, Style::Red)),
+                false => eprintln!("{message}"),
+            }
+            (PANICKED.to_string(), duration, None)
+        }
+    }
+}
+
+/// The answer of a part whose solver panicked.
+const PANICKED: &str = "PANICKED";
+
+/// The answer of a part whose input is malformed or has no solution.
+const ERROR: &str = "ERROR";
+
+/// The answer of a part stopped by Ctrl-C.
+const CANCELLED: &str = "CANCELLED";
+
+/// The token of the whole run, which the first Ctrl-C cancels; every part
+/// runs with a child of it.
+fn interrupt() -> &'static CancelToken {
+    static INTERRUPT: OnceLock<CancelToken> = OnceLock::new();
+    INTERRUPT.get_or_init(CancelToken::new)
+}
+
+/// Whether a part panicked or failed with an error.
+fn failed(answer: &str) -> bool {
+    answer == PANICKED || answer == ERROR
+}
+
+/// Read an input file, exiting with the error if it cannot be read.
+fn read(day: u8, filename: &str) -> String {
+    aoc::read_as_string(day, filename).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        std::process::exit(2);
+    })
+}
+
+thread_local! {
+    /// Whether the thread is running a solver, whose panics are reported
+    /// by `timed` instead of the panic hook.
+    static SOLVING: Cell<bool> = const { Cell::new(false) };
+    /// Where the last panic of a solver was raised, e.g.
+    /// ` at src/day19.rs:42:10`.
+    static PANIC_LOCATION: Cell<Option<String>> = const { Cell::new(None) };
+}
+
+/// Keep the default panic output for the runner itself, but only note where
+/// a solver panicked so `timed` can report it and move on to the next part.
+fn init_panic_hook() {
+    let default = panic::take_hook();
+    panic::set_hook(Box::new(move |info| match SOLVING.get() {
+        true => {
+            let location = info.location().map(|l| format!(" at {l}"));
+            PANIC_LOCATION.set(location);
+        }
+        false => default(info),
+    }));
+}
+
+/// The limits of `--sandbox`: `--cpu-limit`, e.g. `10s`, and
+/// `--memory-limit` in MiB, by default a minute and 4 GiB. Unix only.
+fn sandbox() -> Option<aoc::sandbox::Limits> {
+    if !env::args().any(|a| a == "--sandbox") {
+        return None;
+    }
+    if cfg!(not(unix)) {
+        eprintln!("--sandbox is only supported on Unix");
+        std::process::exit(2);
+    }
+    let cpu = flag_value("--cpu-limit")
+        .map_or(Ok(Duration::from_secs(60)), |v| {
+            aoc::timeout::parse_duration(&v)
+        });
+    let memory = flag_value("--memory-limit").map_or(Ok(4096), |v| v.parse());
+    match (cpu, memory) {
+        (Ok(cpu), Ok(mib)) => Some(aoc::sandbox::Limits {
+            cpu,
+            memory: mib << 20,
+        }),
+        (Err(e), _) => {
+            eprintln!("--cpu-limit: {e}");
+            std::process::exit(2);
+        }
+        (_, Err(e)) => {
+            eprintln!("--memory-limit: {e}");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Solve one part in a child process within `limits`, reporting a
+/// violation on stderr and in place of the answer.
+#[cfg(unix)]
+fn sandboxed(
+    day: usize,
+    part: u8,
+    input: &str,
+    limits: aoc::sandbox::Limits,
+) -> (String, Duration) {
+    let exe = env::current_exe().expect("failed to locate the executable");
+    let mut args = vec!["sandboxed".to_string(), day.to_string()];
+    args.push(part.to_string());
+    for flag in ["--algo", "--preamble", "--year"] {
+        if let Some(value) = flag_value(flag) {
+            args.extend([flag.to_string(), value]);
+        }
+    }
+    let stopwatch = Stopwatch::start();
+    match aoc::sandbox::run(&exe, &args, input, limits) {
+        Ok(output) => {
+            let (nanos, answer) = output
+                .trim_end_matches('\n')
+                .split_once(' ')
+                .expect("invalid output of the sandboxed part");
+            let nanos = nanos.parse().expect("invalid duration");
+            (answer.to_string(), Duration::from_nanos(nanos))
+        }
+        Err(violation) => {
+            eprintln!("Day {day} part {part} {violation}");
+            let answer = violation.answer().to_string();
+            (answer, stopwatch.elapsed())
+        }
+    }
+}
+
+/// `aoc sandboxed <day> <part>`: the child process of `--sandbox`, which
+/// solves the input on stdin and prints the duration in nanoseconds and the
+/// answer.
+fn sandboxed_child() {
+    let args: Vec<String> = env::args().collect();
+    let (Some(day), Some(part)) = (args.get(2), args.get(3)) else {
+        eprintln!("usage: aoc sandboxed <day> <part>");
+        std::process::exit(2);
+    };
+    let day: usize = day.parse().expect("invalid day");
+    let part: u8 = part.parse().expect("invalid part");
+    let input = io::read_to_string(io::stdin()).expect("failed to read");
+    // Ctrl-C reaches the child too, which reports the part as cancelled
+    aoc::cancel::cancel_on_interrupt(interrupt());
+    let puzzle = puzzle(day);
+    let (answer, duration, _) = aoc::cancel::with(interrupt(), || {
+        timed(day, part, || solve(puzzle, day, part, &input))
+    });
+    println!("{} {answer}", duration.as_nanos());
+}
+
+/// Whether `--profile` asks for a flamegraph of every part, which needs the
+/// `flamegraph` feature.
+fn profile() -> bool {
+    let profile = env::args().any(|a| a == "--profile");
+    if profile && !cfg!(feature = "flamegraph") {
+        eprintln!("--profile needs a build with --features flamegraph");
+        std::process::exit(2);
+    }
+    profile
+}
+
+/// Write the samples of a part to `.aoc/flamegraphs/dayNN-partN.svg`.
+#[cfg(feature = "flamegraph")]
+fn write_flamegraph(profiler: pprof::ProfilerGuard, day: usize, part: u8) {
+    let dir = Path::new(".aoc/flamegraphs");
+    let path = dir.join(format!("day{day:02}-part{part}.svg"));
+    let result = profiler
+        .report()
+        .build()
+        .map_err(|e| e.to_string())
+        .and_then(|report| {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+            let file = fs::File::create(&path).map_err(|e| e.to_string())?;
+            report.flamegraph(file).map_err(|e| e.to_string())
+        });
+    match result {
+        Ok(()) => eprintln!("flamegraph: {}", path.display()),
+        Err(e) => eprintln!("failed to write {}: {e}", path.display()),
+    }
+}
+
+/// The answer of a part abandoned by `--timeout`.
+const TIMEOUT: &str = "TIMEOUT";
+
+/// How long a part may run with `--timeout`, e.g. `5s`, before it is
+/// abandoned and reported as TIMEOUT.
+fn timeout() -> Option<Duration> {
+    let value = flag_value("--timeout")?;
+    match aoc::timeout::parse_duration(&value) {
+        Ok(timeout) => Some(timeout),
+        Err(e) => {
+            eprintln!("--timeout: {e}");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Day 9's preamble strategy given with `--preamble`, e.g. `auto` or
+/// `25`, parsed once; an invalid one is a usage error.
+fn preamble() -> Option<aoc::year2020::day09::PreambleStrategy> {
+    static PREAMBLE: OnceLock<
+        Option<aoc::year2020::day09::PreambleStrategy>,
+    > = OnceLock::new();
+    *PREAMBLE.get_or_init(|| {
+        let value = flag_value("--preamble")?;
+        match value.parse() {
+            Ok(preamble) => Some(preamble),
+            Err(e) => {
+                eprintln!("--preamble: {e}");
+                std::process::exit(2);
+            }
+        }
+    })
+}
+
+/// Solve one part, honoring `--algo` and day options such as day 9's
+/// `--preamble`, with the phases of the registered solver.
+fn solve(
+    puzzle: Puzzle,
+    day: usize,
+    part: u8,
+    input: &str,
+) -> Result<(Answer, Option<Phases>), AocError> {
+    let extras = puzzle.year == DEFAULT_YEAR;
+    if let (true, 9, Some(preamble)) = (extras, day, preamble()) {
+        let answer = match part {
+            1 => aoc::year2020::day09::part_one_with(input, preamble)?,
+            _ => aoc::year2020::day09::part_two_with(input, preamble)?,
+        };
+        return Ok((answer.into(), None));
+    }
+    let variant = flag_value("--algo")
+        .filter(|_| extras)
+        .and_then(|name| aoc::variants::find(day as u8, &name));
+    match variant {
+        Some(variant) => Ok((variant.solve(part, input), None)),
+        None => {
+            let (answer, phases) = puzzle.solve_timed(part, input)?;
+            Ok((answer, Some(phases)))
+        }
+    }
+}
+
+/// Like `solve`, on the parsed input in `shared`, parsing it there first
+/// if the other part has not; only the part that parses has a parse time.
+fn solve_shared<'a>(
+    puzzle: Puzzle,
+    part: u8,
+    input: &'a str,
+    shared: &Shared<'a>,
+) -> Result<(Answer, Option<Phases>), AocError> {
+    let mut stopwatch = Stopwatch::start();
+    let prepared = match shared.get() {
+        Some(prepared) => prepared,
+        None => {
+            let prepared = puzzle.prepare(input)?;
+            shared.get_or_init(|| prepared)
+        }
+    };
+    let parse = stopwatch.lap();
+    let answer = prepared.solve(part)?;
+    let solve = stopwatch.lap();
+    Ok((answer, Some(Phases { parse, solve })))
+}
+
+/// Whether `--phases` asks for how long parsing and solving each part
+/// took, which implies `--time`.
+fn phases() -> bool {
+    env::args().any(|a| a == "--phases")
+}
+
+/// How many times `--repeat N` runs each part, reporting the best and the
+/// average duration; once by default.
+fn repeat() -> usize {
+    match flag_value("--repeat").map(|v| v.parse()) {
+        None => 1,
+        Some(Ok(n)) if n > 0 => n,
+        Some(_) => {
+            eprintln!("--repeat needs a positive number of runs");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// How far, in percent, `--threshold PCT` lets the time of a part move from
+/// the recorded one before it counts; 25% by default.
+fn threshold() -> f64 {
+    let pct =
+        flag_value("--threshold").map(|v| v.trim_end_matches('%').parse());
+    match pct {
+        None => 25.0,
+        Some(Ok(pct)) if pct >= 0.0 => pct,
+        Some(_) => {
+            eprintln!("--threshold needs a non-negative percentage");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// The `--seed` of the random inputs, the current time by default.
+fn seed() -> u64 {
+    match flag_value("--seed").map(|v| v.parse()) {
+        Some(Ok(seed)) => seed,
+        Some(Err(_)) => {
+            eprintln!("--seed needs a number");
+            std::process::exit(2);
+        }
+        None => SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as u64,
+    }
+}
+
+/// How many days `--jobs N` solves at once; one by default.
+fn jobs() -> usize {
+    match flag_value("--jobs").map(|v| v.parse()) {
+        None => 1,
+        Some(Ok(n)) if n > 0 => n,
+        Some(_) => {
+            eprintln!("--jobs needs a positive number of threads");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Whether `--ndjson` asks for one JSON object per part as soon as it is
+/// solved, instead of the usual output.
+fn ndjson() -> bool {
+    env::args().any(|a| a == "--ndjson")
+}
+
+/// Writes the log records of the solvers to stderr, so they never mix with
+/// the answers.
+struct StderrLogger;
+
+impl log::Log for StderrLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            let target = record.target().trim_start_matches("aoc::");
+            eprintln!("[{} {target}] {}", record.level(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Log warnings, or debug records of the solvers with `-v` and trace
+/// records with `-vv`. With the `tracing` feature, `-v` also prints when
+/// each span of the solvers closes and how long it took.
+fn init_logging() {
+    let level = if env::args().any(|a| a == "-vv") {
+        log::LevelFilter::Trace
+    } else if env::args().any(|a| a == "-v" || a == "--verbose") {
+        log::LevelFilter::Debug
+    } else {
+        log::LevelFilter::Warn
+    };
+    #[cfg(feature = "tracing")]
+    if level >= log::LevelFilter::Debug {
+        use tracing_subscriber::fmt::format::FmtSpan;
+        // the log records become events inside the spans
+        tracing_subscriber::fmt()
+            .with_writer(io::stderr)
+            .with_max_level(match level {
+                log::LevelFilter::Trace => tracing::Level::TRACE,
+                _ => tracing::Level::DEBUG,
+            })
+            .with_span_events(FmtSpan::CLOSE)
+            .init();
+        return;
+    }
+    log::set_logger(&StderrLogger).expect("logger already set");
+    log::set_max_level(level);
+}
+
+/// Whether `-q` asks for bare `day part answer` lines.
+fn quiet() -> bool {
+    env::args().any(|a| a == "-q" || a == "--quiet")
+}
+
+/// The `--format` template of the line printed for each part, e.g.
+/// `{day},{part},{answer},{micros}`.
+fn template() -> Option<String> {
+    let template = flag_value("--format")?;
+    let probe = aoc::report::Entry {
+        day: 1,
+        title: String::new(),
+        part: 1,
+        answer: String::new(),
+        duration: Duration::ZERO,
+        peak_memory: None,
+        peak_rss: None,
+        phases: None,
+    };
+    if let Err(e) = aoc::report::format_entry(&template, &probe) {
+        eprintln!("--format: {e}");
+        std::process::exit(2);
+    }
+    Some(template)
+}
+
+/// The configuration from `--config FILE` or `aoc.toml`, loaded once.
+fn config() -> &'static aoc::config::Config {
+    static CONFIG: OnceLock<aoc::config::Config> = OnceLock::new();
+    CONFIG.get_or_init(|| {
+        let path = flag_value("--config")
+            .unwrap_or_else(|| aoc::config::DEFAULT_PATH.to_string());
+        aoc::config::load(Path::new(&path)).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            std::process::exit(2);
+        })
+    })
+}
+
+/// The session cookie from `--cookie`, `AOC_COOKIE`, or the session file
+/// of the configuration, `.aoc/session` by default.
+fn session_cookie() -> Option<String> {
+    flag_value("--cookie").or_else(|| match &config().session_file {
+        Some(path) => aoc::session_cookie_from(Path::new(path)),
+        None => aoc::session_cookie(),
+    })
+}
+
+/// The year of the puzzles to solve: `--year`, else `AOC_YEAR`, else the
+/// `year` of the configuration, else the default.
+fn year() -> u16 {
+    static YEAR: OnceLock<u16> = OnceLock::new();
+    *YEAR.get_or_init(|| {
+        let year = match flag_value("--year")
+            .or_else(|| env::var("AOC_YEAR").ok())
+        {
+            Some(year) => year.parse().unwrap_or_else(|_| {
+                eprintln!("invalid year: {year}");
+                std::process::exit(2);
+            }),
+            None => config().year.unwrap_or(DEFAULT_YEAR),
+        };
+        if aoc::registry::puzzles(year).is_none() {
+            let years: Vec<String> =
+                aoc::YEARS.iter().map(|y| y.year.to_string()).collect();
+            eprintln!("no puzzles for {year}, try {}", years.join(", "));
+            std::process::exit(2);
+        }
+        year
+    })
+}
+
+/// The registered puzzles of the `year`, in order of day.
+fn puzzles() -> &'static [Puzzle] {
+    aoc::registry::puzzles(year()).unwrap()
+}
+
+/// The registered puzzle of `day` in the `year`, exiting if there is none.
+fn puzzle(day: usize) -> Puzzle {
+    let found = u8::try_from(day)
+        .ok()
+        .and_then(|day| aoc::registry::find(year(), day));
+    match found {
+        Some(puzzle) => *puzzle,
+        None => {
+            eprintln!("no puzzle for day {day} of {}", year());
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Exit unless the `year` is the default one: `command` relies on tables
+/// of its puzzles, such as the generators of `generate.rs`.
+fn default_year_only(command: &str) {
+    if year() != DEFAULT_YEAR {
+        eprintln!("aoc {command} only knows the puzzles of {DEFAULT_YEAR}");
+        std::process::exit(2);
+    }
+}
+
+/// The argument following `flag`, if the flag is present.
+fn flag_value(flag: &str) -> Option<String> {
+    let args: Vec<String> = env::args().collect();
+    let i = args.iter().position(|a| a == flag)?;
+    match args.get(i + 1) {
+        Some(value) => Some(value.clone()),
+        None => {
+            eprintln!("{flag} needs a value");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// The input file the days read: `input`, `example` with `--example` or
+/// `--example-file-N`, or the name given with `--example-file`, e.g.
+/// `example-2` for `inputs/NN-example-2.txt`.
+fn input_filename() -> String {
+    if let Some(name) = flag_value("--example-file") {
+        return name;
+    }
+    let example = env::args()
+        .any(|a| a == "--example" || a.starts_with("--example-file-"));
+    match example {
+        true => "example".to_string(),
+        false => "input".to_string(),
+    }
+}
+
+/// The file one part reads instead of `filename`: the one given with
+/// `--example-file-N`, or with `--example` the example the part has of its
+/// own, such as day 14 part two's `example-2`.
+fn part_file(day: usize, part: u8, filename: &str) -> String {
+    if filename == "input" {
+        return filename.to_string();
+    }
+    flag_value(&format!("--example-file-{part}")).unwrap_or_else(|| {
+        match filename {
+            "example" => aoc::expected::example_file(day as u8, part),
+            _ => filename,
+        }
+        .to_string()
+    })
+}
+
+/// Flags followed by a value, which must not be mistaken for a day.
+const VALUE_FLAGS: &[&str] = &[
+    "--algo",
+    "--answers",
+    "--baseline",
+    "--cmd",
+    "--config",
+    "--cookie",
+    "--count",
+    "--cpu-limit",
+    "--data-dir",
+    "--example-file",
+    "--example-file-1",
+    "--example-file-2",
+    "--filter",
+    "--format",
+    "--html",
+    "--input",
+    "--jobs",
+    "--memory-limit",
+    "--notify",
+    "--out",
+    "--part",
+    "--port",
+    "--preamble",
+    "--record-run",
+    "--runs",
+    "--repeat",
+    "--replay",
+    "--seed",
+    "--slower-than",
+    "--threshold",
+    "--timeout",
+    "--warmup",
+    "--year",
+];
+
+/// The positional arguments, skipping flags and their values.
+fn positional_args() -> Vec<String> {
+    let mut args = Vec::new();
+    let mut iter = env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        if VALUE_FLAGS.contains(&arg.as_str()) {
+            iter.next();
+        } else if !arg.starts_with("--") {
+            args.push(arg);
+        }
+    }
+    args
+}
+
+/// The days selected by the positional arguments, e.g. `5-12`, `1,3,9` or
+/// `!23`, or today's day with `--today`; the `days` of the configuration or
+/// all days by default. `--filter` keeps those whose title matches.
+fn selected_days(count: usize) -> Vec<usize> {
+    if env::args().any(|a| a == "--today") {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        return match aoc::selection::puzzle_day(now) {
+            Ok(day) if day <= count => vec![day],
+            Ok(day) => {
+                eprintln!("no solution for day {day} of {}", year());
+                std::process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(2);
+            }
+        };
+    }
+    let args = positional_args();
+    let mut specs: Vec<&str> = args
+        .iter()
+        .map(String::as_str)
+        .filter(|a| aoc::selection::is_selector(a))
+        .collect();
+    if specs.is_empty() {
+        if let Some(days) = &config().days {
+            specs = days.iter().map(String::as_str).collect();
+        }
+    }
+    let mut days =
+        aoc::selection::parse_days(&specs, count).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            std::process::exit(2);
+        });
+    if let Some(filter) = flag_value("--filter") {
+        days.retain(|&day| {
+            aoc::selection::title_matches(&filter, puzzle(day).title)
+        });
+        if days.is_empty() {
+            eprintln!("no selected day has a title matching {filter}");
+            std::process::exit(2);
+        }
+    }
+    days
+}
+
+/// The parts selected with `--part 1` or `--part 2`, both by default.
+fn selected_parts() -> Vec<u8> {
+    match flag_value("--part").as_deref() {
+        None => vec![1, 2],
+        Some("1") => vec![1],
+        Some("2") => vec![2],
+        Some(part) => {
+            eprintln!("invalid part: {part}, expected 1 or 2");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// `aoc answers [days] [--part N] [--example] [--stats] [--out FILE]`: run
+/// the selected days and write their answers, durations and, with `--stats`,
+/// peak heap usage as JSON, or TOML for `*.toml` files.
+fn answers() {
+    let out = flag_value("--out");
+
+    let filename = &input_filename();
+    let stats = env::args().any(|a| a == "--stats");
+    let mut entries = Vec::new();
+    for day in selected_days(puzzles().len()) {
+        let puzzle = puzzle(day);
+        let input = read(day as u8, filename);
+        for part in selected_parts() {
+            entries.push(run(puzzle, day, part, filename, &input, stats));
+        }
+    }
+
+    let Some(path) = out else {
+        print!("{}", aoc::report::to_json(&entries));
+        return;
+    };
+    let content = match path.ends_with(".toml") {
+        true => aoc::report::to_toml(&entries),
+        false => aoc::report::to_json(&entries),
+    };
+    if let Err(e) = fs::write(&path, content) {
+        eprintln!("failed to write {path}: {e}");
+        std::process::exit(1);
+    }
+}
+
+/// `aoc bench [days] [--part N] [--example] [--warmup N] [--runs N]`: run
+/// each selected part `--warmup` times (default 3) unmeasured, then `--runs`
+/// times (default 10), and report the spread of the measured durations.
+fn bench() {
+    let warmup = match flag_value("--warmup").map(|v| v.parse::<usize>()) {
+        None => 3,
+        Some(Ok(n)) => n,
+        Some(Err(_)) => {
+            eprintln!("--warmup needs a number of runs");
+            std::process::exit(2);
+        }
+    };
+    let runs = match flag_value("--runs").map(|v| v.parse::<usize>()) {
+        None => 10,
+        Some(Ok(n)) if n > 0 => n,
+        Some(_) => {
+            eprintln!("--runs needs a positive number of runs");
+            std::process::exit(2);
+        }
+    };
+    let filename = &input_filename();
+
+    println!(
+        "{:<13} {:>10} {:>10} {:>10} {:>10}",
+        "", "min", "median", "mean", "stddev"
+    );
+    for day in selected_days(puzzles().len()) {
+        let puzzle = puzzle(day);
+        let input = read(day as u8, filename);
+        for part in selected_parts() {
+            for _ in 0..warmup {
+                run(puzzle, day, part, filename, &input, false);
+            }
+            let durations: Vec<_> = (0..runs)
+                .map(|_| run(puzzle, day, part, filename, &input, false))
+                .map(|e| e.duration)
+                .collect();
+            let s = aoc::bench::summarize(&durations);
+            let [min, median, mean, stddev] =
+                [s.min, s.median, s.mean, s.stddev].map(format_duration);
+            println!(
+                "Day {day:>2} Part {part} {min:>10} {median:>10} {mean:>10} \
+                 {stddev:>10}"
+            );
+        }
+    }
+}
+
+/// `aoc crosscheck [days] [--example] --cmd "python3 ref.py {day} {part}"`:
+/// compare the answers with those of an external reference solution. The
+/// command may also come from `AOC_CROSSCHECK_CMD`.
+fn crosscheck() {
+    let Some(template) =
+        flag_value("--cmd").or_else(|| env::var("AOC_CROSSCHECK_CMD").ok())
+    else {
+        eprintln!("usage: aoc crosscheck [days] [--example] --cmd COMMAND");
+        std::process::exit(2);
+    };
+    let filename = &input_filename();
+
+    let mut disagreements = 0;
+    for day in selected_days(puzzles().len()) {
+        let puzzle = puzzle(day);
+        let input = read(day as u8, filename);
+        for part in selected_parts() {
+            let ours = run(puzzle, day, part, filename, &input, false);
+            let name = part_file(day, part, filename);
+            let path = aoc::input_path(day as u8, &name);
+            let theirs = aoc::crosscheck::reference_answer(
+                &template,
+                day as u8,
+                part,
+                &path,
+                &read(day as u8, &name),
+            );
+            let label = format!("Day {day:>2} Part {part}");
+            match theirs {
+                Ok(theirs) if theirs == ours.answer => {
+                    println!("{label}  ok        {theirs}")
+                }
+                Ok(theirs) => {
+                    disagreements += 1;
+                    println!(
+                        "{label}  DIFFERS   {} != {theirs}",
+                        ours.answer
+                    );
+                }
+                Err(e) => {
+                    disagreements += 1;
+                    println!("{label}  FAILED    {e}");
+                }
+            }
+        }
+    }
+    println!("{disagreements} disagreement(s)");
+    if disagreements > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// `aoc stress <day> [--count N] [--seed S]`: compare the optimized core of
+/// a day with its slow reference on `N` random inputs of growing size
+/// (default 20), saving the first input they disagree on.
+fn stress() {
+    default_year_only("stress");
+    let Some(day) = positional_args().get(1).and_then(|a| a.parse().ok())
+    else {
+        eprintln!("usage: aoc stress <day> [--count N] [--seed S]");
+        std::process::exit(2);
+    };
+    let count = match flag_value("--count").map(|v| v.parse::<usize>()) {
+        None => 20,
+        Some(Ok(n)) if n > 0 => n,
+        Some(_) => {
+            eprintln!("--count needs a positive number of rounds");
+            std::process::exit(2);
+        }
+    };
+    let seed = seed();
+    if !aoc::stress::DAYS.contains(&day) {
+        let days: Vec<String> =
+            aoc::stress::DAYS.iter().map(|d| d.to_string()).collect();
+        eprintln!("Day {day} has no reference, try {}", days.join(", "));
+        std::process::exit(2);
+    }
+
+    for round in 1..=count {
+        let seed = seed.wrapping_add(round as u64);
+        let case = aoc::stress::case(day, round, seed).unwrap();
+        let label = format!("round {round:>3}  --seed {seed}");
+        if case.fast == case.reference {
+            println!("{label}  ok  {}", case.label);
+            continue;
+        }
+        let path = format!(".aoc/stress/{day:02}-seed-{seed}.txt");
+        fs::create_dir_all(".aoc/stress").unwrap();
+        fs::write(&path, &case.input).unwrap();
+        println!("{label}  MISMATCH  {}, input saved to {path}", case.label);
+        println!("       fast: {}", case.fast);
+        println!("  reference: {}", case.reference);
+        std::process::exit(1);
+    }
+}
+
+/// `aoc difftest <day> [--count N] [--seed S]`: run all `--algo` variants of
+/// a day against the real input and `N` generated ones (default 10), saving
+/// the first input they disagree on. Days without a generator use scrambled
+/// variants of the real input.
+fn difftest() {
+    default_year_only("difftest");
+    let Some(day) = positional_args().get(1).and_then(|a| a.parse().ok())
+    else {
+        eprintln!("usage: aoc difftest <day> [--count N] [--seed S]");
+        std::process::exit(2);
+    };
+    let count = match flag_value("--count").map(|v| v.parse::<u64>()) {
+        None => 10,
+        Some(Ok(n)) => n,
+        Some(Err(_)) => {
+            eprintln!("--count needs a number of inputs");
+            std::process::exit(2);
+        }
+    };
+    let seed = seed();
+    let variants = aoc::variants::variants(day);
+    if variants.len() < 2 {
+        println!("Day {day} has a single implementation");
+        return;
+    }
+    let names: Vec<&str> = variants.iter().map(|v| v.name).collect();
+    println!("Day {day}: {}", names.join(" vs "));
+
+    let input = read(day, "input");
+    for i in 0..=count {
+        let (label, name, input) = match i {
+            0 => ("input".to_string(), "input".to_string(), input.clone()),
+            _ => {
+                let seed = seed.wrapping_add(i);
+                let input = aoc::generate::generate(day, seed)
+                    .unwrap_or_else(|| {
+                        aoc::scramble::scramble(day, &input, seed)
+                    });
+                (format!("--seed {seed}"), format!("seed-{seed}"), input)
+            }
+        };
+        let Some((part, answers)) =
+            aoc::variants::divergence(day, &[1, 2], &input)
+        else {
+            println!("{label}  ok");
+            continue;
+        };
+        let path = format!(".aoc/difftest/{day:02}-{name}.txt");
+        fs::create_dir_all(".aoc/difftest").unwrap();
+        fs::write(&path, &input).unwrap();
+        println!("{label}  part {part} DIVERGES, input saved to {path}");
+        for (name, answer) in answers {
+            println!("  {name:>10}: {answer}");
+        }
+        std::process::exit(1);
+    }
+}
+
+/// `aoc list`: every day with its title, which of its input files are in
+/// the data directory, how many answers are known for the examples and the
+/// real input, and the complexity and typical runtime of each part.
+fn list() {
+    let real = known_answers("input").unwrap_or_default();
+    for puzzle in puzzles() {
+        let day = puzzle.day;
+        println!("Day {day:>2}: {}", puzzle.title);
+        let files: Vec<String> = aoc::expected::files(day)
+            .into_iter()
+            .map(|file| match aoc::find_input(day, file) {
+                Some(_) => format!("{file} ok"),
+                None => format!("{file} missing"),
+            })
+            .collect();
+        println!("  Files:   {}", files.join(", "));
+        let examples = aoc::expected::examples()
+            .iter()
+            .filter(|e| e.day == day)
+            .count();
+        let answers = real.iter().filter(|(d, _, _)| *d == day).count();
+        println!("  Answers: {examples} example, {answers} real");
+        for part in [1, 2] {
+            if let Some(e) = aoc::expectations::expectation(day, part) {
+                println!(
+                    "  Part {part}:  {:<40} {:?}..{:?}",
+                    e.complexity,
+                    e.min(),
+                    e.max()
+                );
+            }
+        }
+    }
+}
+
+/// `aoc generate <day> [--seed N]`: print a random input for the day that
+/// satisfies the invariants of real inputs.
+fn generate() {
+    default_year_only("generate");
+    let Some(day) = positional_args().get(1).and_then(|a| a.parse().ok())
+    else {
+        eprintln!("usage: aoc generate <day> [--seed N]");
+        std::process::exit(2);
+    };
+    let seed = seed();
+    match aoc::generate::generate(day, seed) {
+        Some(input) => {
+            eprintln!("generated day {day} with --seed {seed}");
+            println!("{input}");
+        }
+        None => {
+            eprintln!("day {day} has no input generator");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// `aoc fetch [days] [--force] [--cookie session=...]`: download the puzzle
+/// inputs of the selected days into the data directory, keeping existing files unless
+/// `--force` is given. The cookie may also come from `AOC_COOKIE` or the
+/// `.aoc/session` file.
+fn fetch() {
+    let cookie = session_cookie()
+        .and_then(|c| aoc::cookie_header(&c))
+        .expect(
+            "no session cookie: set AOC_COOKIE or save it in .aoc/session",
+        );
+    let force = env::args().any(|a| a == "--force");
+    fs::create_dir_all(aoc::inputs_dir()).unwrap();
+
+    let mut failures = 0;
+    for day in selected_days(puzzles().len()) {
+        let path = aoc::input_path(day as u8, "input");
+        if !force && Path::new(&path).exists() {
+            println!("Day {day:>2}  exists    {path}");
+            continue;
+        }
+        match aoc::read_from_url(&aoc::input_url(day as u8), Some(&cookie)) {
+            Ok(input) => {
+                fs::write(&path, input).unwrap();
+                println!("Day {day:>2}  fetched   {path}");
+            }
+            Err(e) => {
+                failures += 1;
+                println!("Day {day:>2}  FAILED    {e}");
+            }
+        }
+    }
+    if failures > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// `aoc submit <day> <part> [--cookie session=...]`: solve one part of the
+/// real input and submit the answer to adventofcode.com, printing the
+/// verdict.
+fn submit() {
+    const USAGE: &str = "usage: aoc submit <day> <part>";
+    let args = positional_args();
+    let (Some(day), Some(part @ (1 | 2))) = (
+        args.get(1).and_then(|a| a.parse().ok()),
+        args.get(2).and_then(|a| a.parse().ok()),
+    ) else {
+        eprintln!("{USAGE}");
+        std::process::exit(2);
+    };
+    let cookie = session_cookie()
+        .and_then(|c| aoc::cookie_header(&c))
+        .expect(
+            "no session cookie: set AOC_COOKIE or save it in .aoc/session",
+        );
+
+    let puzzle = puzzle(day);
+    let input = read(day as u8, "input");
+    let entry = run(puzzle, day, part, "input", &input, false);
+    println!("Day {day} Part {part}: {}", entry.answer);
+    if !solved(&entry.answer) {
+        // a failure marker is no answer to send
+        std::process::exit(1);
+    }
+    match aoc::submit::submit(day as u8, part, &entry.answer, &cookie) {
+        Ok(verdict) => {
+            println!("{verdict}");
+            if verdict != aoc::submit::Verdict::Right {
+                std::process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("failed to submit: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// `aoc doctor [--cookie session=...]`: check that the inputs, the session
+/// cookie and the local directories are in place, printing how to fix what
+/// is not.
+fn doctor() {
+    let cookie = session_cookie();
+    let inputs = aoc::inputs_dir();
+    let inputs = Path::new(&inputs);
+    let mut checks = aoc::doctor::input_files(inputs, puzzles().len() as u8);
+    checks.push(aoc::doctor::session(cookie.as_deref()));
+    checks.push(aoc::doctor::writable(inputs));
+    checks.push(aoc::doctor::writable(Path::new(".aoc")));
+
+    let mut problems = 0;
+    for check in &checks {
+        let status = if check.ok { "ok  " } else { "FAIL" };
+        println!("{status}  {}", check.summary);
+        if let Some(fix) = &check.fix {
+            println!("      fix: {fix}");
+            problems += 1;
+        }
+    }
+    println!("{problems} problem(s)");
+    if problems > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// `aoc selftest`: solve every part with an example against it and compare
+/// with the example answers of `expected.rs`, as a quick end-to-end check of
+/// an optimized build.
+fn selftest() {
+    let stopwatch = Stopwatch::start();
+    let mut failures = 0;
+    for e in aoc::expected::examples() {
+        let day = e.day as usize;
+        let input = read(e.day, e.file);
+        let entry = run(puzzle(day), day, e.part, e.file, &input, false);
+        let label = format!("Day {day:>2} Part {}", e.part);
+        if entry.answer == e.answer {
+            println!("{label}  ok    {}", entry.answer);
         } else {
-            println!("Part Two: {}", part2(input));
+            failures += 1;
+            println!("{label}  FAIL  {} != {}", entry.answer, e.answer);
+        }
+    }
+    let total = aoc::expected::examples().len();
+    println!(
+        "{} passed, {failures} failed in {}",
+        total - failures,
+        format_duration(stopwatch.elapsed())
+    );
+    if failures > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// `aoc validate [days] [--example]`: run the parsers of the selected days
+/// on their input files without solving, with `--example` on every example
+/// file found for the day, reporting files that are missing or fail to
+/// parse, and line endings or byte order marks that get normalized on
+/// reading.
+fn validate() {
+    let filename = &input_filename();
+    let mut failures = 0;
+    for day in selected_days(puzzles().len()) {
+        let day = day as u8;
+        let files = match filename.as_str() {
+            // a day without any still reports its missing `example`
+            "example" => match aoc::example_files(day) {
+                files if files.is_empty() => vec![aoc::example_name(1)],
+                files => files,
+            },
+            _ => vec![filename.clone()],
+        };
+        for file in &files {
+            let path = aoc::find_input(day, file)
+                .unwrap_or_else(|| aoc::input_path(day, file));
+            let raw = match fs::read_to_string(&path) {
+                Ok(raw) => raw,
+                Err(e) => {
+                    failures += 1;
+                    println!("Day {day:>2}  FAILED  {path}: {e}");
+                    continue;
+                }
+            };
+            let mut notes = Vec::new();
+            if raw.starts_with('\u{feff}') {
+                notes.push("byte order mark");
+            }
+            if raw.contains("\r\n") {
+                notes.push("CRLF line endings");
+            }
+            let notes = match notes.is_empty() {
+                true => String::new(),
+                false => format!(" ({})", notes.join(", ")),
+            };
+            match aoc::validate::validate(day, &aoc::normalize(&raw)) {
+                Ok(()) => println!("Day {day:>2}  ok      {path}{notes}"),
+                Err(e) => {
+                    failures += 1;
+                    println!("Day {day:>2}  FAILED  {path}{notes}: {e}");
+                }
+            }
         }
-        let t2 = SystemTime::now();
+    }
+    if failures > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// `aoc history <day> [--example]`: show how the runtime of a day evolved
+/// across the recorded `--time` runs.
+fn history() {
+    let path = history_path();
+    let records = aoc::history::load(Path::new(&path)).unwrap();
+    for day in positional_args().iter().filter_map(|a| a.parse().ok()) {
+        print!("{}", aoc::history::render(&records, day, &input_filename()));
+    }
+}
+
+/// `aoc scramble <day> [--example] [--seed N]`: print a shareable variant of
+/// the day's input to stdout.
+fn scramble() {
+    default_year_only("scramble");
+    let Some(day) = positional_args().get(1).and_then(|a| a.parse().ok())
+    else {
+        eprintln!("usage: aoc scramble <day> [--example] [--seed N]");
+        std::process::exit(2);
+    };
+    let filename = &input_filename();
+    let seed = seed();
 
-        if show_time {
-            let d1 = t1.duration_since(t0).unwrap_or_default();
-            let d2 = t2.duration_since(t1).unwrap_or_default();
-            println!("Duration: {:?}", (d1, d2));
+    let input = read(day, filename);
+    eprintln!("scrambling day {day} {filename} with --seed {seed}");
+    print!("{}", aoc::scramble::scramble(day, &input, seed));
+}
+
+/// `aoc serve [--port N] [--cached]`: solve the inputs posted to
+/// `/solve/<day>/<part>` on 127.0.0.1, 8080 by default, a connection a
+/// thread, and expose the requests, errors, cache hits and solve durations
+/// of each part on `/metrics` for Prometheus.
+fn serve() {
+    let port = match flag_value("--port").map(|v| v.parse::<u16>()) {
+        None => 8080,
+        Some(Ok(port)) => port,
+        Some(Err(_)) => {
+            eprintln!("--port needs a port number");
+            std::process::exit(2);
         }
-        println!();
+    };
+    let listener =
+        TcpListener::bind(("127.0.0.1", port)).unwrap_or_else(|e| {
+            eprintln!("failed to listen on port {port}: {e}");
+            std::process::exit(1);
+        });
+    eprintln!("serving on http://127.0.0.1:{port}");
+    let metrics: &'static Metrics = Box::leak(Box::default());
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else { continue };
+        thread::spawn(move || {
+            if let Err(e) = respond(&stream, metrics) {
+                eprintln!("failed to respond: {e}");
+            }
+        });
     }
 }
+
+/// Answer the request on `stream`, counting the parts it solves.
+fn respond(stream: &TcpStream, metrics: &Metrics) -> io::Result<()> {
+    use aoc::server::{read_request, route, write_response, Route};
+    const TEXT: &str = "text/plain; charset=utf-8";
+    let mut writer = stream;
+    let request = match read_request(&mut io::BufReader::new(stream)) {
+        Ok(request) => request,
+        Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+            let message = format!("{e}\n");
+            return write_response(
+                &mut writer,
+                "400 Bad Request",
+                TEXT,
+                &message,
+            );
+        }
+        Err(e) => return Err(e),
+    };
+    let (day, part) = match route(&request) {
+        Route::Solve { day, part } => (day, part),
+        Route::Metrics => {
+            let exposition = "text/plain; version=0.0.4";
+            return write_response(
+                &mut writer,
+                "200 OK",
+                exposition,
+                &metrics.render(),
+            );
+        }
+        Route::NotFound => {
+            return write_response(
+                &mut writer,
+                "404 Not Found",
+                TEXT,
+                "not found\n",
+            );
+        }
+        Route::MethodNotAllowed => {
+            let status = "405 Method Not Allowed";
+            return write_response(
+                &mut writer,
+                status,
+                TEXT,
+                "method not allowed\n",
+            );
+        }
+    };
+    let Some(puzzle) = aoc::registry::find(year(), day) else {
+        let message = format!("no day {day} in {}\n", year());
+        return write_response(&mut writer, "404 Not Found", TEXT, &message);
+    };
+    let input = aoc::normalize(&request.body);
+    let cache_hit = cache().is_some_and(|(cache, build)| {
+        let key = cache_key(day as usize, part, &input);
+        aoc::cache::lookup(&cache.lock().unwrap(), key, build).is_some()
+    });
+    let entry = run(*puzzle, day as usize, part, "input", &input, false);
+    let error = !solved(&entry.answer);
+    let outcome = Outcome {
+        duration: entry.duration,
+        error,
+        cache_hit,
+    };
+    metrics.record(day, part, outcome);
+    let status = match error {
+        true => "422 Unprocessable Entity",
+        false => "200 OK",
+    };
+    write_response(&mut writer, status, TEXT, &format!("{}\n", entry.answer))
+}
@@ -0,0 +1,261 @@
+//! Modular Arithmetic
+//!
+//! The number theory of days 13 and 25: greatest common divisors, the
+//! extended Euclidean algorithm, modular inverses and powers, and the
+//! Chinese remainder theorem for moduli that need not be coprime. All of
+//! it is `const`, so a day can evaluate its example at compile time, and
+//! works on `u128` without overflowing: `mul_mod` falls back to doubling
+//! when a product does not fit.
+
+/// The greatest common divisor of `a` and `b`, 0 for two zeros.
+pub const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple of `a` and `b`, none past `u128`.
+pub const fn lcm(a: u128, b: u128) -> Option<u128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// `(g, x, y)` with `g` the greatest common divisor of `a` and `b` and
+/// `a * x + b * y == g`, by the extended Euclidean algorithm.
+pub const fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// `(a + b) % m` for `a` and `b` below `m`.
+const fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `(a - b) % m` for `a` and `b` below `m`, never negative.
+const fn sub_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+/// `(a * b) % m` for a modulus of at least 1.
+pub const fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+    // double and add, every intermediate below `m`
+    let (mut a, mut b) = (a % m, b % m);
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            product = add_mod(product, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    product
+}
+
+/// `base ^ exp % m` by squaring, for a modulus of at least 1.
+pub const fn mod_pow(base: u128, mut exp: u128, m: u128) -> u128 {
+    let mut base = base % m;
+    let mut power = 1 % m;
+    while exp != 0 {
+        if exp & 1 == 1 {
+            power = mul_mod(power, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    power
+}
+
+/// The `x` below `m` with `a * x % m == 1`, none unless `a` and `m` are
+/// coprime.
+pub const fn mod_inverse(a: u128, m: u128) -> Option<u128> {
+    if m == 0 {
+        return None;
+    }
+    // the extended Euclidean algorithm with the coefficients of `a` kept
+    // modulo `m`, so they never go negative
+    let (mut r0, mut r1) = (m, a % m);
+    let (mut s0, mut s1) = (0, 1 % m);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, sub_mod(s0, mul_mod(q, s1, m), m));
+    }
+    if r0 == 1 {
+        Some(s0)
+    } else {
+        None
+    }
+}
+
+/// Why a system of congruences has no solution in `u128`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// Two congruences contradict each other, e.g. x ≡ 0 (mod 2) and
+    /// x ≡ 1 (mod 4), or a modulus is 0.
+    Inconsistent,
+    /// The least solution, or the modulus of one that another congruence
+    /// joins, is past `u128`.
+    Overflow,
+}
+
+/// The solutions x ≡ `residue` (mod `modulus`) of a system of
+/// congruences, `residue` being the least.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Congruence {
+    pub residue: u128,
+    /// The least common multiple of the moduli so far, none once it is
+    /// past `u128`, when `residue` is still the least solution but no
+    /// other congruence can join.
+    pub modulus: Option<u128>,
+}
+
+impl Congruence {
+    /// Every number: x ≡ 0 (mod 1).
+    pub const ANY: Congruence = Congruence {
+        residue: 0,
+        modulus: Some(1),
+    };
+
+    /// x ≡ `residue` (mod `modulus`), with `residue` reduced.
+    pub const fn new(residue: u128, modulus: u128) -> Result<Self, CrtError> {
+        if modulus == 0 {
+            return Err(CrtError::Inconsistent);
+        }
+        Ok(Congruence {
+            residue: residue % modulus,
+            modulus: Some(modulus),
+        })
+    }
+
+    /// The solutions of both, by the Chinese remainder theorem.
+    pub const fn and(self, other: Congruence) -> Result<Self, CrtError> {
+        let (Some(m), Some(n)) = (self.modulus, other.modulus) else {
+            return Err(CrtError::Overflow);
+        };
+        let (a, b) = (self.residue, other.residue);
+        // x = a + m * k for the k with m * k ≡ b - a (mod n)
+        let g = gcd(m, n);
+        let difference = sub_mod(b % n, a % n, n);
+        if !difference.is_multiple_of(g) {
+            return Err(CrtError::Inconsistent);
+        }
+        let n_g = n / g;
+        let Some(inverse) = mod_inverse(m / g % n_g, n_g) else {
+            return Err(CrtError::Inconsistent);
+        };
+        let k = mul_mod(difference / g, inverse, n_g);
+        // below the least common multiple, so it only overflows with it
+        let Some(residue) = m.checked_mul(k) else {
+            return Err(CrtError::Overflow);
+        };
+        let Some(residue) = residue.checked_add(a) else {
+            return Err(CrtError::Overflow);
+        };
+        Ok(Congruence {
+            residue,
+            modulus: (m / g).checked_mul(n),
+        })
+    }
+}
+
+/// The least x ≥ 0 with x ≡ r (mod m) for every `(r, m)` of
+/// `congruences`, by the Chinese remainder theorem.
+pub const fn crt(congruences: &[(u128, u128)]) -> Result<u128, CrtError> {
+    let mut system = Congruence::ANY;
+    let mut i = 0;
+    while i < congruences.len() {
+        let (residue, modulus) = congruences[i];
+        system = match Congruence::new(residue, modulus) {
+            Ok(congruence) => match system.and(congruence) {
+                Ok(system) => system,
+                Err(e) => return Err(e),
+            },
+            Err(e) => return Err(e),
+        };
+        i += 1;
+    }
+    Ok(system.residue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn euclid() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(u128::MAX, 2), None);
+        for (a, b) in [(240, 46), (-240, 46), (17, 0), (0, 5), (3, -7)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(a * x + b * y, g);
+            assert_eq!(g as u128, gcd(a.unsigned_abs(), b.unsigned_abs()));
+        }
+    }
+
+    #[test]
+    fn inverses_and_powers() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+        assert_eq!(mod_pow(7, 8, 20201227), 5764801);
+        assert_eq!(mod_pow(2, 0, 1), 0);
+
+        // past u64 and u128 products
+        let m = u128::MAX - 158; // the largest prime below 2^128
+        let a = u128::MAX / 3;
+        assert_eq!(mul_mod(a, mod_inverse(a, m).unwrap(), m), 1);
+        assert_eq!(mod_pow(a, m - 1, m), 1); // Fermat
+                                             // 2^127 ≡ -1
+        let m = (1 << 127) + 1;
+        assert_eq!(mul_mod(1 << 100, 1 << 100, m), m - (1 << 73));
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Ok(23));
+        // moduli that share a factor
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Ok(10));
+        assert_eq!(crt(&[(0, 2), (1, 4)]), Err(CrtError::Inconsistent));
+        assert_eq!(crt(&[(1, 0)]), Err(CrtError::Inconsistent));
+        assert_eq!(crt(&[]), Ok(0));
+
+        // the least solution fits although the modulus does not
+        let (p, q) = (u128::MAX - 158, 3);
+        let system = Congruence::new(5, p).unwrap();
+        let system = system.and(Congruence::new(2, q).unwrap()).unwrap();
+        assert_eq!(system.modulus, None);
+        assert_eq!(system.residue, 5);
+        let next = system.and(Congruence::new(0, 2).unwrap());
+        assert_eq!(next, Err(CrtError::Overflow));
+    }
+}
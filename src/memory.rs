@@ -0,0 +1,154 @@
+//! Heap Usage Tracking
+//!
+//! `CountingAllocator` wraps the system allocator and keeps track of the
+//! bytes currently allocated and their high-water mark. The `aoc` binary
+//! installs it as the global allocator so the runner can report the peak
+//! heap usage of each part; without it the counters simply stay at zero.
+//!
+//! The peak resident set size covers what the heap counters miss, such as
+//! memory mapped by the system allocator and the stacks, but only on Linux,
+//! where `/proc/self/clear_refs` can reset it between parts.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn grow(size: usize) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn shrink(size: usize) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::shrink(layout.size());
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                Self::grow(new_size - layout.size());
+            } else {
+                Self::shrink(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// Bytes currently allocated through `CountingAllocator`.
+pub fn current() -> usize {
+    CURRENT.load(Ordering::Relaxed)
+}
+
+/// Restart high-water mark tracking from the current usage.
+pub fn reset_peak() {
+    PEAK.store(current(), Ordering::Relaxed);
+}
+
+/// Highest number of bytes allocated since the last `reset_peak`.
+pub fn peak() -> usize {
+    PEAK.load(Ordering::Relaxed)
+}
+
+/// Restart peak resident set size tracking from the current size, returning
+/// whether that is supported.
+pub fn reset_peak_rss() -> bool {
+    std::fs::write("/proc/self/clear_refs", "5").is_ok()
+}
+
+/// Highest resident set size of the process in bytes since the last
+/// `reset_peak_rss`, where supported.
+pub fn peak_rss() -> Option<usize> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    vm_hwm(&status)
+}
+
+// The `VmHWM:   1234 kB` line of /proc/self/status, in bytes
+fn vm_hwm(status: &str) -> Option<usize> {
+    let line = status.lines().find_map(|l| l.strip_prefix("VmHWM:"))?;
+    let kb: usize = line.trim().strip_suffix(" kB")?.trim().parse().ok()?;
+    Some(kb * 1024)
+}
+
+/// Human-friendly byte count, e.g. `7.6 MiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(8_000_004), "7.6 MiB");
+    }
+
+    #[test]
+    fn resident_set() {
+        let status = "VmPeak:\t  12000 kB\nVmHWM:\t    2048 kB\n";
+        assert_eq!(vm_hwm(status), Some(2 * 1024 * 1024));
+        assert_eq!(vm_hwm("VmRSS:\t 1 kB\n"), None);
+    }
+
+    #[test]
+    fn high_water_mark() {
+        // the test harness uses the system allocator, so only the counter
+        // bookkeeping is exercised here
+        let layout = Layout::from_size_align(4096, 8).unwrap();
+        reset_peak();
+        let before = peak();
+        unsafe {
+            let ptr = CountingAllocator.alloc(layout);
+            CountingAllocator.dealloc(ptr, layout);
+        }
+        assert!(peak() >= before + 4096);
+    }
+}
@@ -0,0 +1,165 @@
+//! Service Metrics
+//!
+//! The counters and histograms `aoc serve` exposes on `/metrics` in the
+//! Prometheus text format, one series per day and part: the requests, the
+//! errors among them, the answers taken from a cache rather than solved
+//! and how long the parts took to solve, so a long-running solver service
+//! can be scraped like any other.
+//!
+//! ```text
+//! aoc_requests_total{day="15",part="2"} 3
+//! aoc_solve_duration_seconds_bucket{day="15",part="2",le="1"} 3
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// The upper bounds of the duration buckets, in seconds.
+pub const BUCKETS: [f64; 8] = [0.0001, 0.001, 0.01, 0.1, 0.5, 1.0, 5.0, 15.0];
+
+/// How one request for a part went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    pub duration: Duration,
+    pub error: bool,
+    pub cache_hit: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Series {
+    requests: u64,
+    errors: u64,
+    cache_hits: u64,
+    /// The solves of at most each bound of `BUCKETS`, not cumulative.
+    buckets: [u64; BUCKETS.len()],
+    seconds: f64,
+}
+
+/// The metrics of every day and part requested so far, shared between the
+/// threads of the service.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    series: Mutex<BTreeMap<(u8, u8), Series>>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Metrics::default()
+    }
+
+    /// Count a request for `part` of `day`; only solves, not cache hits,
+    /// go into the duration histogram.
+    pub fn record(&self, day: u8, part: u8, outcome: Outcome) {
+        let mut series = self.series.lock().unwrap();
+        let series = series.entry((day, part)).or_default();
+        series.requests += 1;
+        series.errors += outcome.error as u64;
+        series.cache_hits += outcome.cache_hit as u64;
+        if !outcome.cache_hit {
+            let seconds = outcome.duration.as_secs_f64();
+            if let Some(i) = BUCKETS.iter().position(|&le| seconds <= le) {
+                series.buckets[i] += 1;
+            }
+            series.seconds += seconds;
+        }
+    }
+
+    /// The metrics in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let series = self.series.lock().unwrap();
+        let mut out = String::new();
+        let counters = [
+            ("aoc_requests_total", "Requests to solve a part."),
+            ("aoc_errors_total", "Requests that failed to solve a part."),
+            ("aoc_cache_hits_total", "Answers taken from the cache."),
+        ];
+        for (i, (name, help)) in counters.into_iter().enumerate() {
+            writeln!(out, "# HELP {name} {help}\n# TYPE {name} counter")
+                .unwrap();
+            for (&(day, part), s) in series.iter() {
+                let value = [s.requests, s.errors, s.cache_hits][i];
+                let labels = format!("day=\"{day}\",part=\"{part}\"");
+                writeln!(out, "{name}{{{labels}}} {value}").unwrap();
+            }
+        }
+        let name = "aoc_solve_duration_seconds";
+        writeln!(
+            out,
+            "# HELP {name} How long solving a part took.\n\
+             # TYPE {name} histogram"
+        )
+        .unwrap();
+        for (&(day, part), s) in series.iter() {
+            let labels = format!("day=\"{day}\",part=\"{part}\"");
+            let mut count = 0;
+            for (le, n) in BUCKETS.iter().zip(s.buckets) {
+                count += n;
+                writeln!(
+                    out,
+                    "{name}_bucket{{{labels},le=\"{le}\"}} {count}"
+                )
+                .unwrap();
+            }
+            let solves = s.requests - s.cache_hits;
+            writeln!(out, "{name}_bucket{{{labels},le=\"+Inf\"}} {solves}")
+                .unwrap();
+            writeln!(out, "{name}_sum{{{labels}}} {}", s.seconds).unwrap();
+            writeln!(out, "{name}_count{{{labels}}} {solves}").unwrap();
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exposition() {
+        let metrics = Metrics::new();
+        let solve = |millis, error| Outcome {
+            duration: Duration::from_millis(millis),
+            error,
+            cache_hit: false,
+        };
+        metrics.record(15, 2, solve(20, false));
+        metrics.record(15, 2, solve(20_000, true));
+        metrics.record(
+            15,
+            2,
+            Outcome {
+                duration: Duration::from_millis(20),
+                error: false,
+                cache_hit: true,
+            },
+        );
+        metrics.record(1, 1, solve(0, false));
+
+        let text = metrics.render();
+        assert!(text.contains("# TYPE aoc_requests_total counter\n"));
+        assert!(
+            text.contains("aoc_requests_total{day=\"15\",part=\"2\"} 3\n")
+        );
+        assert!(text.contains("aoc_errors_total{day=\"15\",part=\"2\"} 1\n"));
+        assert!(
+            text.contains("aoc_cache_hits_total{day=\"1\",part=\"1\"} 0\n")
+        );
+        let bucket = |le| {
+            format!(
+                "aoc_solve_duration_seconds_bucket\
+                 {{day=\"15\",part=\"2\",le=\"{le}\"}} "
+            )
+        };
+        assert!(text.contains(&(bucket("0.01") + "0\n")));
+        assert!(text.contains(&(bucket("0.1") + "1\n")));
+        assert!(text.contains(&(bucket("15") + "1\n")));
+        assert!(text.contains(&(bucket("+Inf") + "2\n")));
+        assert!(text.contains(
+            "aoc_solve_duration_seconds_sum{day=\"15\",part=\"2\"} 20.02\n"
+        ));
+        // day 1 comes first
+        assert!(text.find("day=\"1\"") < text.find("day=\"15\""));
+    }
+}
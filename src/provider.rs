@@ -0,0 +1,218 @@
+//! Input Providers
+//!
+//! Where `read_as_string` gets a puzzle input from. The files of the data
+//! directories are the default, with the embedded examples as a fallback
+//! under `embedded-examples`; `set_provider` swaps in another source, such
+//! as inputs held in memory for tests without a data directory or for a
+//! WASM build without a file system.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::error::AocError;
+
+pub trait InputProvider {
+    /// The input `filename` of `day`, e.g. `input` or `example-2`, as
+    /// stored; `read_as_string` normalizes it.
+    fn input(&self, day: u8, filename: &str) -> Result<String, AocError>;
+
+    /// The names of the inputs of `day` it has, where it can tell.
+    fn filenames(&self, _day: u8) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// `A`, falling back to `B` for the inputs `A` does not have.
+impl<A: InputProvider, B: InputProvider> InputProvider for (A, B) {
+    fn input(&self, day: u8, filename: &str) -> Result<String, AocError> {
+        match self.0.input(day, filename) {
+            Err(AocError::Missing { mut paths }) => {
+                self.1.input(day, filename).map_err(|e| match e {
+                    AocError::Missing { paths: more } => {
+                        paths.extend(more);
+                        AocError::Missing { paths }
+                    }
+                    e => e,
+                })
+            }
+            input => input,
+        }
+    }
+
+    fn filenames(&self, day: u8) -> Vec<String> {
+        let mut names = self.0.filenames(day);
+        for name in self.1.filenames(day) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+}
+
+/// Inputs held in memory.
+#[derive(Debug, Clone, Default)]
+pub struct Memory {
+    inputs: Vec<(u8, String, String)>,
+}
+
+impl Memory {
+    /// Add the input `filename` of `day`, replacing any earlier one.
+    pub fn with(mut self, day: u8, filename: &str, input: &str) -> Self {
+        self.inputs
+            .retain(|(d, f, _)| (*d, f.as_str()) != (day, filename));
+        self.inputs
+            .push((day, filename.to_string(), input.to_string()));
+        self
+    }
+}
+
+impl InputProvider for Memory {
+    fn input(&self, day: u8, filename: &str) -> Result<String, AocError> {
+        self.inputs
+            .iter()
+            .find(|(d, f, _)| (*d, f.as_str()) == (day, filename))
+            .map(|(_, _, input)| input.clone())
+            .ok_or_else(|| AocError::Missing {
+                paths: vec![format!("memory:{day:02}-{filename}")],
+            })
+    }
+
+    fn filenames(&self, day: u8) -> Vec<String> {
+        self.inputs
+            .iter()
+            .filter(|(d, _, _)| *d == day)
+            .map(|(_, f, _)| f.clone())
+            .collect()
+    }
+}
+
+/// The files of `data_dirs`, e.g. `inputs/01-example.txt`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Files;
+
+#[cfg(feature = "std")]
+impl InputProvider for Files {
+    fn input(&self, day: u8, filename: &str) -> Result<String, AocError> {
+        let Some(path) = crate::find_input(day, filename) else {
+            let paths = crate::data_dirs()
+                .into_iter()
+                .map(|dir| format!("{dir}/{day:02}-{filename}.txt"))
+                .collect();
+            return Err(AocError::Missing { paths });
+        };
+        std::fs::read_to_string(&path)
+            .map_err(|source| AocError::Io { path, source })
+    }
+
+    fn filenames(&self, day: u8) -> Vec<String> {
+        let prefix = format!("{day:02}-");
+        let mut names: Vec<String> = Vec::new();
+        for dir in crate::data_dirs() {
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let file = entry.file_name();
+                let name = file
+                    .to_str()
+                    .and_then(|f| f.strip_prefix(&prefix))
+                    .and_then(|f| f.strip_suffix(".txt"));
+                match name {
+                    Some(name) if !names.iter().any(|n| n == name) => {
+                        names.push(name.to_string())
+                    }
+                    _ => {}
+                }
+            }
+        }
+        names.sort();
+        names
+    }
+}
+
+/// The example inputs of the default year compiled into the library, and
+/// the real ones too under `embed-inputs`.
+#[cfg(feature = "embedded-examples")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Embedded;
+
+#[cfg(feature = "embedded-examples")]
+impl InputProvider for Embedded {
+    fn input(&self, day: u8, filename: &str) -> Result<String, AocError> {
+        crate::examples::embedded(day, filename)
+            .filter(|_| crate::year() == crate::registry::DEFAULT_YEAR)
+            .map(str::to_string)
+            .ok_or_else(|| AocError::Missing {
+                paths: vec![format!("embedded:{day:02}-{filename}")],
+            })
+    }
+
+    fn filenames(&self, day: u8) -> Vec<String> {
+        match crate::year() == crate::registry::DEFAULT_YEAR {
+            true => crate::examples::filenames(day),
+            false => Vec::new(),
+        }
+    }
+}
+
+/// The real inputs downloaded from adventofcode.com with a session cookie,
+/// such as `session_cookie()`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct Download {
+    pub cookie: String,
+}
+
+#[cfg(feature = "std")]
+impl InputProvider for Download {
+    fn input(&self, day: u8, filename: &str) -> Result<String, AocError> {
+        let url = crate::input_url(day);
+        if filename != "input" {
+            return Err(AocError::Missing {
+                paths: vec![format!("{url} (no {filename})")],
+            });
+        }
+        crate::http::get(&url, &[("Cookie", &self.cookie)])
+            .map_err(|source| AocError::Io { path: url, source })
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn memory() {
+        let memory = Memory::default()
+            .with(1, "example", "1\n")
+            .with(1, "example", "1721\n");
+        assert_eq!(memory.input(1, "example").unwrap(), "1721\n");
+        assert_eq!(memory.filenames(1), ["example"]);
+        let error = memory.input(2, "example").unwrap_err();
+        assert_eq!(error.to_string(), "expected file at memory:02-example");
+    }
+
+    #[test]
+    fn fallback() {
+        let provider = (Memory::default().with(1, "input", "1721\n"), Files);
+        assert_eq!(provider.input(1, "input").unwrap(), "1721\n");
+        assert_eq!(
+            provider.input(1, "example").unwrap(),
+            read_example(1).unwrap()
+        );
+        let names = provider.filenames(14);
+        assert!(names.contains(&"example-2".to_string()));
+        assert_eq!(provider.filenames(1)[0], "input");
+        let error = provider.input(1, "missing").unwrap_err().to_string();
+        assert!(error.starts_with("expected file at memory:01-missing or "));
+        let download = Download {
+            cookie: String::new(),
+        };
+        assert!(download.input(1, "example").is_err());
+    }
+}
@@ -0,0 +1,131 @@
+//! Puzzle Registry
+//!
+//! Every day module registers its `Solution` with `register!`, which
+//! declares the module's `PUZZLE`: the year, the day, the title and the
+//! entry points of its solver. The `PUZZLES` of a year module such as
+//! `aoc::year2020` collect them in order of day, and `aoc::YEARS` the
+//! years, so the runner and library users enumerate the puzzles from the
+//! day modules themselves rather than from a table of their own.
+
+use alloc::boxed::Box;
+
+use crate::error::AocError;
+use crate::solver::{self, Prepared, Solver};
+#[cfg(feature = "std")]
+use crate::timing::Phases;
+use crate::Answer;
+
+/// Solves a part, timing its parsing and solving.
+#[cfg(feature = "std")]
+type SolveTimed = fn(u8, &str) -> Result<(Answer, Phases), AocError>;
+
+/// Parses an input for solving both parts.
+type Prepare =
+    for<'a> fn(&'a str) -> Result<Box<dyn Prepared + 'a>, AocError>;
+
+/// The year the runner and the input files default to.
+pub const DEFAULT_YEAR: u16 = 2020;
+
+/// The puzzles of one year's event.
+#[derive(Debug, Clone, Copy)]
+pub struct Year {
+    pub year: u16,
+    pub puzzles: &'static [Puzzle],
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    solve: fn(u8, &str) -> Result<Answer, AocError>,
+    #[cfg(feature = "std")]
+    solve_timed: SolveTimed,
+    parse: fn(&str) -> Result<(), AocError>,
+    prepare: Prepare,
+}
+
+impl Puzzle {
+    pub const fn new<S: Solver + 'static>(
+        year: u16,
+        day: u8,
+        title: &'static str,
+    ) -> Self {
+        Puzzle {
+            year,
+            day,
+            title,
+            solve: solver::solve::<S>,
+            #[cfg(feature = "std")]
+            solve_timed: solver::solve_timed::<S>,
+            parse: |input| S::parse(input).map(drop),
+            prepare: solver::prepare::<S>,
+        }
+    }
+
+    /// The answer of `part` for `input`.
+    pub fn solve(&self, part: u8, input: &str) -> Result<Answer, AocError> {
+        scope!("puzzle", year = self.year, day = self.day, part);
+        (self.solve)(part, input)
+    }
+
+    /// Like `solve`, with how long parsing and solving took.
+    #[cfg(feature = "std")]
+    pub fn solve_timed(
+        &self,
+        part: u8,
+        input: &str,
+    ) -> Result<(Answer, Phases), AocError> {
+        scope!("puzzle", year = self.year, day = self.day, part);
+        (self.solve_timed)(part, input)
+    }
+
+    /// Parse `input` without solving, for the error of a malformed one.
+    pub fn parse(&self, input: &str) -> Result<(), AocError> {
+        (self.parse)(input)
+    }
+
+    /// Parse `input` once for solving both parts, e.g.
+    /// `puzzle.prepare(input)?.solve(2)`.
+    pub fn prepare<'a>(
+        &self,
+        input: &'a str,
+    ) -> Result<Box<dyn Prepared + 'a>, AocError> {
+        scope!("puzzle", year = self.year, day = self.day);
+        (self.prepare)(input)
+    }
+}
+
+/// The registered puzzles of `year`, in order of day.
+pub fn puzzles(year: u16) -> Option<&'static [Puzzle]> {
+    crate::YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .map(|y| y.puzzles)
+}
+
+/// The registered puzzle of `day` in `year`.
+pub fn find(year: u16, day: u8) -> Option<&'static Puzzle> {
+    puzzles(year)?.iter().find(|puzzle| puzzle.day == day)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn every_day() {
+        let year = puzzles(DEFAULT_YEAR).unwrap();
+        let days: Vec<u8> = year.iter().map(|p| p.day).collect();
+        assert_eq!(days, (1..=25).collect::<Vec<u8>>());
+        assert!(year.iter().all(|p| p.year == 2020));
+        let puzzle = find(2020, 1).unwrap();
+        assert_eq!(puzzle.title, "Report Repair");
+        let answer = puzzle.solve(1, &read_example(1).unwrap()).unwrap();
+        assert_eq!(answer.to_string(), "514579");
+        assert!(find(2020, 20).unwrap().parse("Tile x:").is_err());
+        assert!(find(2020, 26).is_none());
+        assert!(find(2019, 1).is_none() && puzzles(2019).is_none());
+    }
+}
@@ -0,0 +1,774 @@
+//! Answer Reports
+//!
+//! Structured documents of computed answers, one entry per day and part,
+//! suitable for diffing between machines or feeding external dashboards.
+//!
+//! Answers are always written as strings since days return a mix of numbers
+//! and text (e.g. day 21's ingredient list). Durations are in nanoseconds.
+//! Exports also carry the per-day timing chart from `bar_chart`, the
+//! expected complexity of each part from `expectations`, and the peak heap
+//! usage and resident set size in bytes of each part when they were
+//! measured. `to_markdown` renders a compact table for READMEs instead,
+//! `to_html` a standalone page from the `report.html` template,
+//! `format_entry` a line of a `--format` template, and `summary` ranks the
+//! days by their share of the total runtime.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::expectations::expectation;
+use crate::json::{parse_flat_object, quote};
+use crate::timing::Phases;
+
+/// The answer of one part of one day, with how long it took to compute.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub day: u8,
+    pub title: String,
+    pub part: u8,
+    pub answer: String,
+    pub duration: Duration,
+    /// Heap high-water mark in bytes, when it was measured.
+    pub peak_memory: Option<usize>,
+    /// Peak resident set size of the process in bytes, when it was
+    /// measured.
+    pub peak_rss: Option<usize>,
+    /// How long parsing and solving took, when they were timed apart.
+    pub phases: Option<Phases>,
+}
+
+/// The total duration of each day, in the order of `entries`.
+fn day_totals(entries: &[Entry]) -> Vec<(u8, Duration)> {
+    let mut days: Vec<(u8, Duration)> = Vec::new();
+    for e in entries {
+        match days.iter_mut().find(|(day, _)| *day == e.day) {
+            Some((_, total)) => *total += e.duration,
+            None => days.push((e.day, e.duration)),
+        }
+    }
+    days
+}
+
+/// The total runtime, followed by the total of each day and its share of
+/// the runtime, slowest day first.
+pub fn summary(entries: &[Entry]) -> String {
+    let mut days = day_totals(entries);
+    days.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let total: Duration = days.iter().map(|&(_, d)| d).sum();
+    let mut output = format!("Total: {total:.1?} for {} days\n", days.len());
+    for (day, duration) in days {
+        let share = match total.is_zero() {
+            true => 0.0,
+            false => duration.as_secs_f64() / total.as_secs_f64() * 100.0,
+        };
+        output += &format!("Day {day:>2} {duration:>10.1?} {share:>5.1}%\n");
+    }
+    output
+}
+
+/// Horizontal bar chart of the total duration of each day, scaled so the
+/// slowest day spans `width` cells.
+pub fn bar_chart(entries: &[Entry], width: usize) -> String {
+    let days = day_totals(entries);
+    let max = days.iter().map(|(_, d)| d.as_nanos()).max().unwrap_or(0);
+
+    days.iter()
+        .map(|&(day, total)| {
+            let bar = bar(total.as_nanos(), max, width);
+            format!("Day {day:>2} {bar} {total:.1?}\n")
+        })
+        .collect()
+}
+
+/// A bar of `value` in eighths of a cell, `width` cells for `max`, padded
+/// to `width + 1` cells.
+pub(crate) fn bar(value: u128, max: u128, width: usize) -> String {
+    const BLOCKS: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+    let eighths =
+        (value * width as u128 * 8).checked_div(max).unwrap_or(0) as usize;
+    let (full, rest) = (eighths / 8, eighths % 8);
+    let mut bar = BLOCKS[7].to_string().repeat(full);
+    if rest > 0 || full == 0 {
+        bar.push(BLOCKS[rest.saturating_sub(1)]);
+    }
+    let pad = width + 1 - bar.chars().count();
+    bar + &" ".repeat(pad)
+}
+
+fn entry_json(e: &Entry) -> String {
+    let peak = match e.peak_memory {
+        Some(bytes) => format!(", \"peak_bytes\": {bytes}"),
+        None => String::new(),
+    };
+    let rss = match e.peak_rss {
+        Some(bytes) => format!(", \"peak_rss_bytes\": {bytes}"),
+        None => String::new(),
+    };
+    let phases = match e.phases {
+        Some(p) => format!(
+            ", \"parse_ns\": {}, \"solve_ns\": {}",
+            p.parse.as_nanos(),
+            p.solve.as_nanos()
+        ),
+        None => String::new(),
+    };
+    let complexity = match expectation(e.day, e.part) {
+        Some(x) => format!(", \"complexity\": {}", quote(x.complexity)),
+        None => String::new(),
+    };
+    format!(
+        "{{\"day\": {}, \"title\": {}, \"part\": {}, \
+         \"answer\": {}, \"duration_ns\": {}{peak}{rss}{phases}\
+         {complexity}}}",
+        e.day,
+        quote(&e.title),
+        e.part,
+        quote(&e.answer),
+        e.duration.as_nanos()
+    )
+}
+
+pub fn to_json(entries: &[Entry]) -> String {
+    let items: Vec<String> = entries
+        .iter()
+        .map(|e| format!("    {}", entry_json(e)))
+        .collect();
+    format!(
+        "{{\n  \"answers\": [\n{}\n  ],\n  \"chart\": {}\n}}\n",
+        items.join(",\n"),
+        quote(&bar_chart(entries, 40))
+    )
+}
+
+/// One JSON object per line, as written by `--record-run` and streamed by
+/// `--ndjson`.
+pub fn to_ndjson(entries: &[Entry]) -> String {
+    entries.iter().map(|e| entry_json(e) + "\n").collect()
+}
+
+/// Read back `to_ndjson` output, skipping lines that are not entries.
+pub fn from_ndjson(s: &str) -> Vec<Entry> {
+    s.lines()
+        .filter_map(|line| entry_from_fields(&parse_flat_object(line)?))
+        .collect()
+}
+
+fn entry_from_fields(fields: &HashMap<String, String>) -> Option<Entry> {
+    Some(Entry {
+        day: fields.get("day")?.parse().ok()?,
+        title: fields.get("title")?.clone(),
+        part: fields.get("part")?.parse().ok()?,
+        answer: fields.get("answer")?.clone(),
+        duration: Duration::from_nanos(
+            fields.get("duration_ns")?.parse().ok()?,
+        ),
+        peak_memory: match fields.get("peak_bytes") {
+            Some(bytes) => Some(bytes.parse().ok()?),
+            None => None,
+        },
+        peak_rss: match fields.get("peak_rss_bytes") {
+            Some(bytes) => Some(bytes.parse().ok()?),
+            None => None,
+        },
+        phases: match (fields.get("parse_ns"), fields.get("solve_ns")) {
+            (Some(parse), Some(solve)) => Some(Phases {
+                parse: Duration::from_nanos(parse.parse().ok()?),
+                solve: Duration::from_nanos(solve.parse().ok()?),
+            }),
+            _ => None,
+        },
+    })
+}
+
+/// Compare a re-run against a recording. Every part gets one line; changed
+/// answers and timing shifts beyond `threshold` percent are marked and
+/// counted.
+pub fn diff(
+    recorded: &[Entry],
+    current: &[Entry],
+    threshold: f64,
+) -> (String, usize) {
+    let mut output = String::new();
+    let mut differences = 0;
+    for old in recorded {
+        let label = format!("Day {:>2} Part {}", old.day, old.part);
+        let Some(new) = current
+            .iter()
+            .find(|e| e.day == old.day && e.part == old.part)
+        else {
+            output.push_str(&format!("{label}  MISSING\n"));
+            differences += 1;
+            continue;
+        };
+        if new.answer != old.answer {
+            output.push_str(&format!(
+                "{label}  CHANGED  {} -> {}\n",
+                old.answer, new.answer
+            ));
+            differences += 1;
+            continue;
+        }
+        let shift = shift(old, new);
+        let status = if shift.abs() > threshold {
+            differences += 1;
+            if shift > 0.0 {
+                "SLOWER "
+            } else {
+                "FASTER "
+            }
+        } else {
+            "ok     "
+        };
+        output.push_str(&format!(
+            "{label}  {status}  {:>10.1?} -> {:>10.1?}  {shift:+.1}%\n",
+            old.duration, new.duration
+        ));
+    }
+    (output, differences)
+}
+
+/// Compare a run against a timing baseline. Parts slower by more than
+/// `threshold` percent and changed answers count as regressions; faster
+/// parts and parts not in both are fine.
+pub fn regressions(
+    baseline: &[Entry],
+    current: &[Entry],
+    threshold: f64,
+) -> (String, usize) {
+    let mut output = String::new();
+    let mut regressions = 0;
+    for new in current {
+        let label = format!("Day {:>2} Part {}", new.day, new.part);
+        let Some(old) = baseline
+            .iter()
+            .find(|e| e.day == new.day && e.part == new.part)
+        else {
+            output.push_str(&format!(
+                "{label}  NEW      {:>10.1?}\n",
+                new.duration
+            ));
+            continue;
+        };
+        if new.answer != old.answer {
+            output.push_str(&format!(
+                "{label}  CHANGED  {} -> {}\n",
+                old.answer, new.answer
+            ));
+            regressions += 1;
+            continue;
+        }
+        let shift = shift(old, new);
+        let status = if shift > threshold {
+            regressions += 1;
+            "SLOWER "
+        } else {
+            "ok     "
+        };
+        output.push_str(&format!(
+            "{label}  {status}  {:>10.1?} -> {:>10.1?}  {shift:+.1}%\n",
+            old.duration, new.duration
+        ));
+    }
+    (output, regressions)
+}
+
+// The change in duration from `old` to `new` in percent
+fn shift(old: &Entry, new: &Entry) -> f64 {
+    if old.duration.is_zero() {
+        0.0
+    } else {
+        (new.duration.as_secs_f64() / old.duration.as_secs_f64() - 1.0)
+            * 100.0
+    }
+}
+
+/// A JSON summary of a finished run for webhooks: the total runtime, the
+/// parts whose answers differ from `known` ones, and every entry. The
+/// `text` and `content` fields repeat the gist for Slack and Discord.
+pub fn to_notification(
+    entries: &[Entry],
+    known: &[(u8, u8, String)],
+) -> String {
+    let total: Duration = entries.iter().map(|e| e.duration).sum();
+    let failures: Vec<String> = entries
+        .iter()
+        .filter_map(|e| {
+            let (_, _, expected) =
+                known.iter().find(|(d, p, _)| *d == e.day && *p == e.part)?;
+            (*expected != e.answer).then(|| {
+                format!(
+                    "{{\"day\": {}, \"part\": {}, \"answer\": {}, \
+                     \"expected\": {}}}",
+                    e.day,
+                    e.part,
+                    quote(&e.answer),
+                    quote(expected)
+                )
+            })
+        })
+        .collect();
+    let gist = format!(
+        "aoc: {} part(s) in {total:.1?}, {} failure(s)",
+        entries.len(),
+        failures.len()
+    );
+    let answers: Vec<String> = entries.iter().map(entry_json).collect();
+    format!(
+        "{{\"text\": {gist}, \"content\": {gist}, \"total_ns\": {}, \
+         \"failures\": [{}], \"answers\": [{}]}}",
+        total.as_nanos(),
+        failures.join(", "),
+        answers.join(", "),
+        gist = quote(&gist)
+    )
+}
+
+/// The chart as a top-level key, ahead of the `[[answers]]` tables, since
+/// any key after them would belong to the last answer.
+pub fn to_toml(entries: &[Entry]) -> String {
+    let chart = format!("chart = \"\"\"\n{}\"\"\"\n", bar_chart(entries, 40));
+    let answers = entries.iter().map(|e| {
+        let peak = match e.peak_memory {
+            Some(bytes) => format!("peak_bytes = {bytes}\n"),
+            None => String::new(),
+        };
+        let rss = match e.peak_rss {
+            Some(bytes) => format!("peak_rss_bytes = {bytes}\n"),
+            None => String::new(),
+        };
+        let complexity = match expectation(e.day, e.part) {
+            Some(x) => format!("complexity = {}\n", quote(x.complexity)),
+            None => String::new(),
+        };
+        format!(
+            "[[answers]]\nday = {}\ntitle = {}\npart = {}\n\
+                 answer = {}\nduration_ns = {}\n{peak}{rss}{complexity}",
+            e.day,
+            quote(&e.title),
+            e.part,
+            quote(&e.answer),
+            e.duration.as_nanos()
+        )
+    });
+    [chart]
+        .into_iter()
+        .chain(answers)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A Markdown table with one row per day: its title, both answers and the
+/// total time, ready to paste into a README.
+pub fn to_markdown(entries: &[Entry]) -> String {
+    let cell = |s: &str| s.replace('|', "\\|");
+    let mut table = String::from(
+        "| Day | Title | Part 1 | Part 2 | Time |\n\
+         |----:|-------|--------|--------|-----:|\n",
+    );
+    let mut days: Vec<u8> = entries.iter().map(|e| e.day).collect();
+    days.dedup();
+    for day in days {
+        let parts: Vec<&Entry> =
+            entries.iter().filter(|e| e.day == day).collect();
+        let answer = |part| {
+            parts
+                .iter()
+                .find(|e| e.part == part)
+                .map_or(String::new(), |e| format!("`{}`", cell(&e.answer)))
+        };
+        let time: Duration = parts.iter().map(|e| e.duration).sum();
+        table.push_str(&format!(
+            "| {day} | {} | {} | {} | {time:.1?} |\n",
+            cell(&parts[0].title),
+            answer(1),
+            answer(2)
+        ));
+    }
+    table
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A standalone HTML page with the answers, a pass/fail badge for each
+/// part with a `known` answer, and a bar chart of the time of each day.
+pub fn to_html(entries: &[Entry], known: &[(u8, u8, String)]) -> String {
+    let mut answers = String::new();
+    for e in entries {
+        let badge = match known
+            .iter()
+            .find(|(d, p, _)| *d == e.day && *p == e.part)
+        {
+            Some((_, _, expected)) if *expected == e.answer => {
+                "<span class=\"badge pass\">pass</span>".to_string()
+            }
+            Some((_, _, expected)) => format!(
+                "<span class=\"badge fail\" title=\"expected {}\">\
+                 fail</span>",
+                escape_html(expected)
+            ),
+            None => "<span class=\"badge unknown\">?</span>".to_string(),
+        };
+        answers += &format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td><code>{}</code></td>\
+             <td>{badge}</td><td class=\"time\">{:.1?}</td></tr>\n",
+            e.day,
+            escape_html(&e.title),
+            e.part,
+            escape_html(&e.answer),
+            e.duration
+        );
+    }
+
+    let days = day_totals(entries);
+    let max = days
+        .iter()
+        .map(|(_, d)| d.as_secs_f64())
+        .fold(0.0, f64::max);
+    let mut chart = String::new();
+    for (day, total) in &days {
+        let width = match max > 0.0 {
+            true => total.as_secs_f64() / max * 100.0,
+            false => 0.0,
+        };
+        chart += &format!(
+            "<tr><td>Day {day}</td><td style=\"width: 100%\">\
+             <div class=\"bar\" style=\"width: {width:.1}%\"></div></td>\
+             <td class=\"time\">{total:.1?}</td></tr>\n"
+        );
+    }
+
+    let total: Duration = days.iter().map(|&(_, d)| d).sum();
+    let failed = entries
+        .iter()
+        .filter(|e| {
+            known.iter().any(|(d, p, expected)| {
+                *d == e.day && *p == e.part && *expected != e.answer
+            })
+        })
+        .count();
+    let summary = format!(
+        "{} parts of {} days in {total:.1?}, {failed} failed",
+        entries.len(),
+        days.len()
+    );
+    include_str!("report.html")
+        .replace("{{summary}}", &summary)
+        .replace("{{answers}}", &answers)
+        .replace("{{chart}}", &chart)
+}
+
+/// `template` with the `{day}`, `{part}`, `{title}`, `{answer}`,
+/// `{duration}`, `{nanos}`, `{micros}` and `{millis}` of `entry` filled in,
+/// and `\t` and `\n` turned into tabs and newlines.
+pub fn format_entry(template: &str, entry: &Entry) -> Result<String, String> {
+    let mut output = String::new();
+    let mut rest = template.replace("\\t", "\t").replace("\\n", "\n");
+    while let Some(start) = rest.find('{') {
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| format!("unclosed placeholder in {template}"))?;
+        output.push_str(&rest[..start]);
+        let value = match &rest[start + 1..start + end] {
+            "day" => entry.day.to_string(),
+            "part" => entry.part.to_string(),
+            "title" => entry.title.clone(),
+            "answer" => entry.answer.clone(),
+            "duration" => format!("{:?}", entry.duration),
+            "nanos" => entry.duration.as_nanos().to_string(),
+            "micros" => entry.duration.as_micros().to_string(),
+            "millis" => entry.duration.as_millis().to_string(),
+            name => return Err(format!("unknown placeholder {{{name}}}")),
+        };
+        output.push_str(&value);
+        rest = rest.split_off(start + end + 1);
+    }
+    output.push_str(&rest);
+    Ok(output)
+}
+
+/// The `saved` entries updated with `current` ones of the same day and
+/// part, sorted by day and part.
+pub fn merge(saved: &[Entry], current: &[Entry]) -> Vec<Entry> {
+    let mut merged: Vec<Entry> = saved
+        .iter()
+        .filter(|s| {
+            !current.iter().any(|c| c.day == s.day && c.part == s.part)
+        })
+        .chain(current)
+        .cloned()
+        .collect();
+    merged.sort_by_key(|e| (e.day, e.part));
+    merged
+}
+
+/// Read back the `[[answers]]` tables of `to_toml` output, skipping tables
+/// that are not entries and the chart, which older reports wrote last.
+pub fn from_toml(s: &str) -> Vec<Entry> {
+    let mut tables: Vec<Vec<String>> = Vec::new();
+    let mut lines = s.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        if line == "[[answers]]" {
+            tables.push(Vec::new());
+        } else if line.starts_with("chart =") {
+            lines.by_ref().find(|l| l.ends_with("\"\"\""));
+        } else if let (Some(table), Some((key, value))) =
+            (tables.last_mut(), line.split_once(" = "))
+        {
+            // basic strings and integers read the same as in JSON
+            table.push(format!("{}: {value}", quote(key)));
+        }
+    }
+    tables
+        .iter()
+        .filter_map(|table| {
+            let object = format!("{{{}}}", table.join(", "));
+            entry_from_fields(&parse_flat_object(&object)?)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries() -> Vec<Entry> {
+        vec![
+            Entry {
+                day: 21,
+                title: "Allergen Assessment".to_string(),
+                part: 1,
+                answer: "5".to_string(),
+                duration: Duration::from_micros(12),
+                peak_memory: None,
+                peak_rss: None,
+                phases: Some(Phases {
+                    parse: Duration::from_micros(2),
+                    solve: Duration::from_micros(10),
+                }),
+            },
+            Entry {
+                day: 21,
+                title: "Allergen Assessment".to_string(),
+                part: 2,
+                answer: "mxmxvkd,\"sqjhc\"".to_string(),
+                duration: Duration::from_nanos(7),
+                peak_memory: Some(1024),
+                peak_rss: Some(4096),
+                phases: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            to_json(&entries()),
+            concat!(
+                "{\n",
+                "  \"answers\": [\n",
+                "    {\"day\": 21, \"title\": \"Allergen Assessment\", ",
+                "\"part\": 1, \"answer\": \"5\", \"duration_ns\": 12000, ",
+                "\"parse_ns\": 2000, \"solve_ns\": 10000, ",
+                "\"complexity\": \"O(f·i), f = foods, i = ingredients\"},\n",
+                "    {\"day\": 21, \"title\": \"Allergen Assessment\", ",
+                "\"part\": 2, \"answer\": \"mxmxvkd,\\\"sqjhc\\\"\", ",
+                "\"duration_ns\": 7, \"peak_bytes\": 1024, ",
+                "\"peak_rss_bytes\": 4096, ",
+                "\"complexity\": \"O(f·i + a²), a = allergens\"}\n",
+                "  ],\n",
+                "  \"chart\": \"Day 21 ",
+                "████████████████████████████████████████  12.0µs\\n\"\n",
+                "}\n",
+            )
+        );
+    }
+
+    #[test]
+    fn ndjson() {
+        let entries = entries();
+        let recorded = from_ndjson(&to_ndjson(&entries));
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(recorded[1].answer, entries[1].answer);
+        assert_eq!(recorded[1].duration, entries[1].duration);
+        assert_eq!(recorded[0].peak_memory, None);
+        assert_eq!(recorded[1].peak_memory, Some(1024));
+        assert_eq!(recorded[1].peak_rss, Some(4096));
+        assert_eq!(recorded[0].phases, entries[0].phases);
+        assert_eq!(recorded[1].phases, None);
+    }
+
+    #[test]
+    fn replay_diff() {
+        let recorded = entries();
+        let mut current = entries();
+        assert_eq!(diff(&recorded, &current, 25.0).1, 0);
+
+        current[0].duration = Duration::from_micros(24);
+        current[1].answer = "mxmxvkd".to_string();
+        let (output, differences) = diff(&recorded, &current, 25.0);
+        assert_eq!(differences, 2);
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[0].starts_with("Day 21 Part 1  SLOWER"));
+        assert!(lines[0].ends_with("+100.0%"));
+        assert!(lines[1].starts_with("Day 21 Part 2  CHANGED"));
+
+        let (output, differences) = diff(&recorded, &current[..1], 200.0);
+        assert_eq!(differences, 1);
+        assert!(output.ends_with("Day 21 Part 2  MISSING\n"));
+    }
+
+    #[test]
+    fn toml() {
+        let toml = to_toml(&entries());
+        assert!(toml.starts_with("chart = \"\"\"\nDay 21 █"));
+        assert!(toml.contains("\"\"\"\n\n[[answers]]\nday = 21\n"));
+        assert!(toml.contains("answer = \"mxmxvkd,\\\"sqjhc\\\"\"\n"));
+        assert_eq!(toml.matches("[[answers]]").count(), 2);
+        assert_eq!(toml.matches("peak_bytes = 1024\n").count(), 1);
+        assert_eq!(toml.matches("peak_rss_bytes = 4096\n").count(), 1);
+        assert!(
+            toml.contains("complexity = \"O(f·i + a²), a = allergens\"\n")
+        );
+
+        let entries = entries();
+        let recorded = from_toml(&toml);
+        assert_eq!(recorded.len(), 2);
+        // reports written with the chart last read the same
+        let (chart, answers) = toml.split_once("\n\n").unwrap();
+        let old = format!("{answers}\n{chart}\n");
+        assert_eq!(from_toml(&old).len(), 2);
+        assert_eq!(recorded[1].answer, entries[1].answer);
+        assert_eq!(recorded[1].duration, entries[1].duration);
+        assert_eq!(recorded[1].peak_memory, Some(1024));
+    }
+
+    #[test]
+    fn template() {
+        let e = &entries()[0];
+        assert_eq!(
+            format_entry("{day},{part},{answer},{micros}", e),
+            Ok("21,1,5,12".to_string())
+        );
+        assert_eq!(
+            format_entry("{title}\\t{nanos}ns {duration}", e),
+            Ok("Allergen Assessment\t12000ns 12µs".to_string())
+        );
+        assert!(format_entry("{year}", e).is_err());
+        assert!(format_entry("{day", e).is_err());
+    }
+
+    #[test]
+    fn html() {
+        let known = [(21, 1, "5".to_string()), (21, 2, "x".to_string())];
+        let html = to_html(&entries(), &known);
+        assert!(html.contains("<p>2 parts of 1 days in 12.0µs, 1 failed</p>"));
+        assert!(html.contains("<code>mxmxvkd,&quot;sqjhc&quot;</code>"));
+        assert!(html.contains("<span class=\"badge pass\">pass</span>"));
+        assert!(html.contains("title=\"expected x\">fail</span>"));
+        assert!(html.contains("style=\"width: 100.0%\""));
+        assert!(!html.contains("{{"));
+    }
+
+    #[test]
+    fn notification() {
+        let known = [(21, 1, "5".to_string()), (21, 2, "x".to_string())];
+        let json = to_notification(&entries(), &known);
+        assert!(json.starts_with(
+            "{\"text\": \"aoc: 2 part(s) in 12.0µs, 1 failure(s)\", "
+        ));
+        assert!(json.contains(", \"total_ns\": 12007, \"failures\": [{"));
+        assert!(json.contains("\"expected\": \"x\"}], \"answers\": [{"));
+        assert!(to_notification(&entries(), &[]).contains("0 failure(s)"));
+    }
+
+    #[test]
+    fn baseline() {
+        let baseline = entries();
+        let mut current = entries();
+        current[0].duration = Duration::from_micros(6);
+        assert_eq!(regressions(&baseline, &current, 25.0).1, 0);
+
+        current[1].duration = Duration::from_nanos(14);
+        current.push(Entry {
+            day: 22,
+            ..current[0].clone()
+        });
+        let (output, regressions) = regressions(&baseline, &current, 25.0);
+        assert_eq!(regressions, 1);
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[0].starts_with("Day 21 Part 1  ok"));
+        assert!(lines[0].ends_with("-50.0%"));
+        assert!(lines[1].starts_with("Day 21 Part 2  SLOWER"));
+        assert!(lines[2].starts_with("Day 22 Part 1  NEW"));
+    }
+
+    #[test]
+    fn merging() {
+        let saved = entries();
+        let mut current = entries();
+        current.remove(0);
+        current[0].answer = "sqjhc".to_string();
+        current.push(Entry {
+            day: 1,
+            ..current[0].clone()
+        });
+        let merged = merge(&saved, &current);
+        let keys: Vec<_> = merged.iter().map(|e| (e.day, e.part)).collect();
+        assert_eq!(keys, [(1, 2), (21, 1), (21, 2)]);
+        assert_eq!(merged[1].answer, "5");
+        assert_eq!(merged[2].answer, "sqjhc");
+    }
+
+    #[test]
+    fn markdown() {
+        let mut entries = entries();
+        entries[1].answer = "a|b".to_string();
+        assert_eq!(
+            to_markdown(&entries),
+            concat!(
+                "| Day | Title | Part 1 | Part 2 | Time |\n",
+                "|----:|-------|--------|--------|-----:|\n",
+                "| 21 | Allergen Assessment | `5` | `a\\|b` | 12.0µs |\n",
+            )
+        );
+        assert!(to_markdown(&entries[..1]).ends_with("| `5` |  | 12.0µs |\n"));
+    }
+
+    #[test]
+    fn ranking() {
+        let mut entries = entries();
+        entries[1].day = 15;
+        entries[1].duration = Duration::from_micros(36);
+        assert_eq!(
+            summary(&entries),
+            concat!(
+                "Total: 48.0µs for 2 days\n",
+                "Day 15     36.0µs  75.0%\n",
+                "Day 21     12.0µs  25.0%\n",
+            )
+        );
+    }
+
+    #[test]
+    fn chart() {
+        let mut entries = entries();
+        entries[0].day = 15;
+        entries[0].duration = Duration::from_millis(4);
+        entries[1].duration = Duration::from_millis(1);
+        assert_eq!(
+            bar_chart(&entries, 4),
+            concat!("Day 15 ████  4.0ms\n", "Day 21 █     1.0ms\n")
+        );
+        entries[1].duration = Duration::from_micros(250);
+        assert_eq!(
+            bar_chart(&entries, 4),
+            concat!("Day 15 ████  4.0ms\n", "Day 21 ▎     250.0µs\n")
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! Running Every Day
+//!
+//! `run_all` solves the days of the year one at a time as its iterator is
+//! advanced, so a GUI or a server embedding the library can show each day
+//! as soon as it is solved, or stop early, without the loop of the runner.
+//! The inputs come from any `InputProvider`, e.g. `provider::Files` or
+//! `provider::Memory`. Each day's input is parsed once, with
+//! `Puzzle::prepare`, for both parts; a solver that panics panics through
+//! the iterator, and one run inside `cancel::with` stops with
+//! `AocError::Cancelled`.
+
+use std::time::Duration;
+
+use crate::error::AocError;
+use crate::provider::InputProvider;
+use crate::registry::{self, Puzzle};
+use crate::solver::Part;
+use crate::timing::{Phases, Stopwatch};
+use crate::{normalize, Answer};
+
+/// The answers of both parts of one day.
+#[derive(Debug)]
+pub struct DayResult {
+    pub day: u8,
+    pub title: &'static str,
+    /// Part one, then part two, or why the input could not be read, e.g.
+    /// `AocError::Missing` for a day the provider has no input of.
+    pub parts: Result<[PartResult; 2], AocError>,
+}
+
+/// The answer of one part, or why there is none.
+#[derive(Debug)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Result<Answer, AocError>,
+    /// Zero without an answer; part two parses nothing, reusing the
+    /// parsed input of part one.
+    pub phases: Phases,
+}
+
+/// The results of the days of the `year` with an `input` in `inputs`, in
+/// order of day, each solved when the iterator gets to it.
+pub fn run_all(
+    inputs: impl InputProvider,
+) -> impl Iterator<Item = DayResult> {
+    let puzzles = registry::puzzles(crate::year()).unwrap_or(&[]);
+    puzzles.iter().map(move |puzzle| run_day(puzzle, &inputs))
+}
+
+fn run_day(puzzle: &Puzzle, inputs: &impl InputProvider) -> DayResult {
+    scope!("run", day = puzzle.day);
+    let parts = inputs.input(puzzle.day, "input").map(|input| {
+        let input = normalize(&input);
+        let mut stopwatch = Stopwatch::start();
+        let prepared = puzzle.prepare(&input);
+        let mut parse = stopwatch.lap();
+        Part::ALL.map(|part| {
+            let answer = match &prepared {
+                Ok(prepared) => prepared.solve(part.number()),
+                // each part gets an error of its own by parsing again
+                Err(_) => puzzle.solve(part.number(), &input),
+            };
+            let solve = stopwatch.lap();
+            let phases = match answer {
+                Ok(_) => Phases { parse, solve },
+                Err(_) => Phases::default(),
+            };
+            parse = Duration::ZERO;
+            PartResult {
+                part,
+                answer,
+                phases,
+            }
+        })
+    });
+    DayResult {
+        day: puzzle.day,
+        title: puzzle.title,
+        parts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::Memory;
+    use crate::read_example;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// `Memory` that records the days asked for.
+    struct Recording(Memory, Rc<RefCell<Vec<u8>>>);
+
+    impl InputProvider for Recording {
+        fn input(&self, day: u8, filename: &str) -> Result<String, AocError> {
+            self.1.borrow_mut().push(day);
+            self.0.input(day, filename)
+        }
+    }
+
+    #[test]
+    fn lazily() {
+        let inputs = Memory::default()
+            .with(1, "input", &read_example(1).unwrap().replace('\n', "\r\n"))
+            .with(2, "input", "1-3 a: abcde\nx\n");
+        let asked = Rc::new(RefCell::new(Vec::new()));
+        let mut results = run_all(Recording(inputs, asked.clone()));
+        assert!(asked.borrow().is_empty());
+
+        let day = results.next().unwrap();
+        assert_eq!((day.day, day.title), (1, "Report Repair"));
+        let [one, two] = day.parts.unwrap();
+        assert_eq!((one.part, two.part), (Part::One, Part::Two));
+        assert_eq!(one.answer.unwrap().to_string(), "514579");
+        assert_eq!(two.answer.unwrap().to_string(), "241861950");
+        assert!(one.phases.parse > Duration::ZERO);
+        assert_eq!(two.phases.parse, Duration::ZERO);
+        assert_eq!(*asked.borrow(), [1]);
+
+        let [one, _] = results.next().unwrap().parts.unwrap();
+        assert!(matches!(one.answer, Err(AocError::Parse { line: 2, .. })));
+        let day = results.next().unwrap();
+        assert!(matches!(day.parts, Err(AocError::Missing { .. })));
+        assert_eq!(results.count(), 22);
+    }
+}
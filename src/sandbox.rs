@@ -0,0 +1,197 @@
+//! Sandboxed Execution
+//!
+//! Support for `--sandbox`, which solves every part in a child process
+//! with a CPU time and a memory limit, so a runaway solver, such as day 19
+//! with a bad grammar or day 25 with a key that never matches, is killed
+//! instead of taking the whole run down. The limits are set with the
+//! `ulimit` builtin of `sh` rather than by calling `setrlimit` directly,
+//! which keeps the crate free of a libc dependency, and makes `run` Unix
+//! only.
+
+use std::fmt;
+#[cfg(unix)]
+use std::io::{self, Write};
+#[cfg(unix)]
+use std::os::unix::process::ExitStatusExt;
+#[cfg(unix)]
+use std::path::Path;
+#[cfg(unix)]
+use std::process::{Command, ExitStatus, Stdio};
+use std::time::Duration;
+
+/// The resources a child process may use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// CPU time, rounded up to whole seconds.
+    pub cpu: Duration,
+    /// Address space in bytes.
+    pub memory: usize,
+}
+
+/// How a child process failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    Cpu(Duration),
+    Memory(usize),
+    /// Any other failure, with what the child wrote to stderr.
+    Crash(String),
+}
+
+impl Violation {
+    /// What the runner reports in place of the answer.
+    pub fn answer(&self) -> &'static str {
+        match self {
+            Violation::Cpu(_) => "CPU LIMIT",
+            Violation::Memory(_) => "MEMORY LIMIT",
+            Violation::Crash(_) => "CRASHED",
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Violation::Cpu(limit) => {
+                write!(f, "exceeded the CPU time limit of {limit:?}")
+            }
+            Violation::Memory(limit) => {
+                write!(f, "exceeded the memory limit of {limit} bytes")
+            }
+            Violation::Crash(stderr) => write!(f, "crashed: {stderr}"),
+        }
+    }
+}
+
+/// Whether `answer` stands for a `Violation` rather than a real answer.
+pub fn is_violation(answer: &str) -> bool {
+    ["CPU LIMIT", "MEMORY LIMIT", "CRASHED"].contains(&answer)
+}
+
+/// The stdout of `program` run with `args` and `input` on stdin within
+/// `limits`.
+#[cfg(unix)]
+pub fn run(
+    program: &Path,
+    args: &[String],
+    input: &str,
+    limits: Limits,
+) -> Result<String, Violation> {
+    let crash = |e: io::Error| Violation::Crash(e.to_string());
+    let seconds = limits.cpu.as_secs_f64().ceil().max(1.0);
+    // a hard limit past the soft one lets SIGXCPU, not SIGKILL, end a
+    // child that used up its CPU time
+    let script = r#"ulimit -St "$1" && ulimit -Ht "$2" && ulimit -v "$3" &&
+        shift 3 && exec "$@""#;
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(script)
+        .arg("aoc-sandbox")
+        .arg(seconds.to_string())
+        .arg((seconds + 1.0).to_string())
+        .arg((limits.memory / 1024).to_string())
+        .arg(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(crash)?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // a child that dies early closes its end of the pipe
+        let _ = stdin.write_all(input.as_bytes());
+    }
+    let output = child.wait_with_output().map_err(crash)?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    match violation(output.status, &stderr, limits) {
+        Some(violation) => Err(violation),
+        None => {
+            // such as the report of a panic the child recovered from
+            let _ = io::stderr().write_all(stderr.as_bytes());
+            String::from_utf8(output.stdout)
+                .map_err(|e| Violation::Crash(e.to_string()))
+        }
+    }
+}
+
+#[cfg(unix)]
+fn violation(
+    status: ExitStatus,
+    stderr: &str,
+    limits: Limits,
+) -> Option<Violation> {
+    const SIGKILL: i32 = 9;
+    const SIGXCPU: i32 = 24;
+
+    if status.success() {
+        return None;
+    }
+    Some(match status.signal() {
+        Some(SIGXCPU) => Violation::Cpu(limits.cpu),
+        // the kernel kills a child out of memory outright
+        Some(SIGKILL) if limits.memory > 0 => {
+            Violation::Memory(limits.memory)
+        }
+        // the allocator aborts when the address space is exhausted
+        _ if stderr.contains("memory allocation of") => {
+            Violation::Memory(limits.memory)
+        }
+        _ => Violation::Crash(stderr.trim().to_string()),
+    })
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    const LIMITS: Limits = Limits {
+        cpu: Duration::from_secs(1),
+        memory: 1 << 30,
+    };
+
+    #[test]
+    fn limits() {
+        let sh = Path::new("sh");
+        let echo = ["-c".to_string(), "cat".to_string()];
+        assert_eq!(run(sh, &echo, "42\n", LIMITS), Ok("42\n".to_string()));
+        let spin = ["-c".to_string(), "while :; do :; done".to_string()];
+        assert_eq!(
+            run(sh, &spin, "", LIMITS),
+            Err(Violation::Cpu(LIMITS.cpu))
+        );
+    }
+
+    #[test]
+    fn violations() {
+        let abort = ExitStatus::from_raw(6);
+        let stderr = "memory allocation of 4294967296 bytes failed\n";
+        assert_eq!(
+            violation(abort, stderr, LIMITS),
+            Some(Violation::Memory(1 << 30))
+        );
+        let panic = ExitStatus::from_raw(101 << 8);
+        assert_eq!(
+            violation(panic, " no day 26\n", LIMITS),
+            Some(Violation::Crash("no day 26".to_string()))
+        );
+        assert_eq!(violation(ExitStatus::from_raw(0), "", LIMITS), None);
+        let (xcpu, kill) =
+            (ExitStatus::from_raw(24), ExitStatus::from_raw(9));
+        assert_eq!(
+            violation(xcpu, "", LIMITS),
+            Some(Violation::Cpu(LIMITS.cpu))
+        );
+        assert_eq!(
+            violation(kill, "", LIMITS),
+            Some(Violation::Memory(LIMITS.memory))
+        );
+        let unlimited = Limits {
+            memory: 0,
+            ..LIMITS
+        };
+        assert_eq!(
+            violation(kill, "", unlimited),
+            Some(Violation::Crash(String::new()))
+        );
+        assert!(is_violation(Violation::Cpu(LIMITS.cpu).answer()));
+    }
+}
@@ -0,0 +1,580 @@
+//! Input Scrambler
+//!
+//! Rewrites a puzzle input into a shareable variant so it can be attached to
+//! a bug report without publishing the personal puzzle input.
+//!
+//! ## Strategies
+//!
+//! Every day keeps the structure its parser expects, and the result is
+//! always solvable. Where a symmetry of the puzzle allows it, the answers
+//! are preserved as well:
+//!
+//! - **Reordering**: shuffles lines, groups, fields or tiles whenever the
+//!   puzzle does not depend on their order (days 1, 4, 5, 6, 10, 16, 21)
+//! - **Renaming**: consistent letter permutations (days 2, 6), renamed bag
+//!   colors (day 7), renamed ingredients (day 21), swapped `a`/`b` (day 19)
+//!   and renumbered tiles (day 20)
+//! - **Symmetries**: rotated/flipped grids (days 11, 17, 20), mirrored
+//!   navigation (day 12, part one), rotated/mirrored hex directions (day 24)
+//! - **Re-seeding**: fresh values where any value is valid, e.g. the tree
+//!   map (day 3), accumulator operands (day 8), memory values (day 14),
+//!   digits (day 18), decks (day 22), cup labels (day 23) and public keys
+//!   (day 25)
+//!
+//! The same seed always produces the same output.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::rng::Rng;
+
+/// Scramble the input of the given day using `seed`.
+pub fn scramble(day: u8, input: &str, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let rng = &mut rng;
+    let input = input.trim();
+    let output = match day {
+        1 | 5 | 10 => shuffle_lines(input, rng),
+        2 => permute_letters(&shuffle_lines(input, rng), rng),
+        3 => day03(input, rng),
+        4 => day04(input, rng),
+        6 => day06(input, rng),
+        7 => day07(input, rng),
+        8 => day08(input, rng),
+        9 => day09(input, rng),
+        11 | 17 => grid_to_string(&dihedral(parse_grid(input), rng)),
+        12 => day12(input, rng),
+        13 => day13(input, rng),
+        14 => day14(input, rng),
+        15 => day15(input, rng),
+        16 => day16(input, rng),
+        18 => day18(input, rng),
+        19 => day19(input, rng),
+        20 => day20(input, rng),
+        21 => day21(input, rng),
+        22 => day22(input, rng),
+        23 => day23(input, rng),
+        24 => day24(input, rng),
+        25 => day25(input, rng),
+        _ => panic!("unknown day: {day}"),
+    };
+    output + "\n"
+}
+
+fn shuffle_lines(input: &str, rng: &mut Rng) -> String {
+    let mut lines: Vec<&str> = input.lines().collect();
+    rng.shuffle(&mut lines);
+    lines.join("\n")
+}
+
+/// Apply one random permutation of `a..=z` to every lowercase letter.
+fn permute_letters(input: &str, rng: &mut Rng) -> String {
+    let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+    rng.shuffle(&mut letters);
+    input
+        .bytes()
+        .map(|ch| match ch {
+            b'a'..=b'z' => letters[(ch - b'a') as usize] as char,
+            _ => ch as char,
+        })
+        .collect()
+}
+
+fn parse_grid(input: &str) -> Vec<Vec<u8>> {
+    input.lines().map(|s| s.trim().bytes().collect()).collect()
+}
+
+pub(crate) fn grid_to_string(grid: &[Vec<u8>]) -> String {
+    grid.iter()
+        .map(|row| String::from_utf8_lossy(row))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Random rotation/reflection of a rectangular grid.
+pub(crate) fn dihedral(
+    mut grid: Vec<Vec<u8>>,
+    rng: &mut Rng,
+) -> Vec<Vec<u8>> {
+    if rng.chance() {
+        grid = (0..grid[0].len())
+            .map(|x| grid.iter().map(|row| row[x]).collect())
+            .collect();
+    }
+    if rng.chance() {
+        grid.reverse();
+    }
+    if rng.chance() {
+        grid.iter_mut().for_each(|row| row.reverse());
+    }
+    grid
+}
+
+fn day03(input: &str, rng: &mut Rng) -> String {
+    let grid = parse_grid(input);
+    let total = grid.iter().map(|row| row.len()).sum::<usize>() as u64;
+    let trees = grid.iter().flatten().filter(|&&c| c == b'#').count() as u64;
+    let grid: Vec<Vec<u8>> = grid
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|_| if rng.below(total) < trees { b'#' } else { b'.' })
+                .collect()
+        })
+        .collect();
+    grid_to_string(&grid)
+}
+
+fn day04(input: &str, rng: &mut Rng) -> String {
+    let mut passports: Vec<Vec<String>> = input
+        .split("\n\n")
+        .map(|s| {
+            s.split_whitespace()
+                .map(|field| {
+                    let (key, value) = field.split_once(':').unwrap();
+                    let value: String = match key {
+                        "pid" => value
+                            .chars()
+                            .map(|ch| match ch {
+                                '0'..='9' => {
+                                    (b'0' + rng.below(10) as u8) as char
+                                }
+                                _ => ch,
+                            })
+                            .collect(),
+                        "hcl" if value.starts_with('#') => value
+                            .chars()
+                            .map(|ch| match ch {
+                                '0'..='9' | 'a'..='f' => {
+                                    let v = rng.below(16) as u32;
+                                    char::from_digit(v, 16).unwrap()
+                                }
+                                _ => ch,
+                            })
+                            .collect(),
+                        _ => value.to_string(),
+                    };
+                    format!("{key}:{value}")
+                })
+                .collect()
+        })
+        .collect();
+    rng.shuffle(&mut passports);
+    passports
+        .iter_mut()
+        .map(|fields| {
+            rng.shuffle(fields);
+            let mut s = String::new();
+            for (i, field) in fields.iter().enumerate() {
+                if i > 0 {
+                    s.push(if rng.chance() { ' ' } else { '\n' });
+                }
+                s.push_str(field);
+            }
+            s
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn day06(input: &str, rng: &mut Rng) -> String {
+    let mut groups: Vec<Vec<&str>> =
+        input.split("\n\n").map(|s| s.lines().collect()).collect();
+    rng.shuffle(&mut groups);
+    let groups: Vec<String> = groups
+        .iter_mut()
+        .map(|people| {
+            rng.shuffle(people);
+            people.join("\n")
+        })
+        .collect();
+    permute_letters(&groups.join("\n\n"), rng)
+}
+
+fn day07(input: &str, rng: &mut Rng) -> String {
+    const TARGET: (&str, &str) = ("shiny", "gold");
+    let mut names: HashMap<(String, String), (String, String)> =
+        HashMap::new();
+    let mut used: HashSet<(String, String)> = HashSet::new();
+    used.insert((TARGET.0.to_string(), TARGET.1.to_string()));
+
+    let mut lines: Vec<String> = input
+        .lines()
+        .map(|line| {
+            let mut tokens: Vec<String> =
+                line.split_whitespace().map(|s| s.to_string()).collect();
+            for i in 2..tokens.len() {
+                if !tokens[i].starts_with("bag") {
+                    continue;
+                }
+                let key = (tokens[i - 2].clone(), tokens[i - 1].clone());
+                if key == ("no".to_string(), "other".to_string())
+                    || key == (TARGET.0.to_string(), TARGET.1.to_string())
+                {
+                    continue;
+                }
+                let name = names
+                    .entry(key)
+                    .or_insert_with(|| loop {
+                        let (a, b) = (rng.range(4, 7), rng.range(4, 7));
+                        let name =
+                            (rng.word(a as usize), rng.word(b as usize));
+                        if used.insert(name.clone()) {
+                            break name;
+                        }
+                    })
+                    .clone();
+                tokens[i - 2] = name.0;
+                tokens[i - 1] = name.1;
+            }
+            tokens.join(" ")
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.join("\n")
+}
+
+fn day08(input: &str, rng: &mut Rng) -> String {
+    input
+        .lines()
+        .map(|line| {
+            if line.starts_with("acc") {
+                format!("acc {:+}", rng.range(-50, 50))
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day09(input: &str, rng: &mut Rng) -> String {
+    // scaling keeps every "sum of two" and contiguous-sum relation intact
+    let k = rng.range(2, 9) as u64;
+    input
+        .lines()
+        .map(|s| (s.trim().parse::<u64>().unwrap() * k).to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day12(input: &str, rng: &mut Rng) -> String {
+    // the ship starts facing east, so only a mirror across the east-west
+    // axis keeps part one's distance
+    let mirror = rng.chance();
+    input
+        .lines()
+        .map(|line| {
+            let (action, value) = line.trim().split_at(1);
+            let action = match action {
+                "N" if mirror => "S",
+                "S" if mirror => "N",
+                "L" if mirror => "R",
+                "R" if mirror => "L",
+                _ => action,
+            };
+            format!("{action}{value}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day13(input: &str, rng: &mut Rng) -> String {
+    let (earliest, buses) = input.split_once('\n').unwrap();
+    let earliest: i64 = earliest.trim().parse().unwrap();
+    let earliest = rng.range((earliest / 2).max(1), earliest * 3 / 2 + 1);
+    format!("{earliest}\n{}", buses.trim())
+}
+
+fn day14(input: &str, rng: &mut Rng) -> String {
+    input
+        .lines()
+        .map(|line| match line.split_once(" = ") {
+            Some((lhs, _)) if lhs.starts_with("mem[") => {
+                format!("{lhs} = {}", rng.below(1 << 36))
+            }
+            _ => line.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn day15(input: &str, rng: &mut Rng) -> String {
+    let mut numbers: Vec<&str> = input.split(',').collect();
+    rng.shuffle(&mut numbers);
+    numbers.join(",")
+}
+
+fn day16(input: &str, rng: &mut Rng) -> String {
+    let sections: Vec<&str> = input.split("\n\n").collect();
+    let mut rules: Vec<&str> = sections[0].lines().collect();
+    rng.shuffle(&mut rules);
+
+    let ticket = sections[1].lines().nth(1).unwrap();
+    let mut columns: Vec<usize> = (0..ticket.split(',').count()).collect();
+    rng.shuffle(&mut columns);
+    let permute = |s: &str| {
+        let values: Vec<&str> = s.split(',').collect();
+        columns
+            .iter()
+            .map(|&i| values[i])
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    let mut nearby: Vec<String> =
+        sections[2].lines().skip(1).map(permute).collect();
+    rng.shuffle(&mut nearby);
+
+    format!(
+        "{}\n\nyour ticket:\n{}\n\nnearby tickets:\n{}",
+        rules.join("\n"),
+        permute(ticket),
+        nearby.join("\n")
+    )
+}
+
+fn day18(input: &str, rng: &mut Rng) -> String {
+    let mut lines: Vec<String> = input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|ch| match ch {
+                    '0'..='9' => (b'1' + rng.below(9) as u8) as char,
+                    _ => ch,
+                })
+                .collect()
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.join("\n")
+}
+
+fn day19(input: &str, rng: &mut Rng) -> String {
+    let (rules, messages) = input.split_once("\n\n").unwrap();
+    let swap = rng.chance();
+    let swap = |s: String| -> String {
+        if !swap {
+            return s;
+        }
+        s.chars()
+            .map(|ch| match ch {
+                'a' => 'b',
+                'b' => 'a',
+                _ => ch,
+            })
+            .collect()
+    };
+    let rules = swap(shuffle_lines(rules, rng));
+    let messages = swap(shuffle_lines(messages, rng));
+    format!("{rules}\n\n{messages}")
+}
+
+fn day20(input: &str, rng: &mut Rng) -> String {
+    let mut used = HashSet::new();
+    let mut tiles: Vec<String> = input
+        .split("\n\n")
+        .map(|block| {
+            let id = loop {
+                let id = rng.range(1000, 9999);
+                if used.insert(id) {
+                    break id;
+                }
+            };
+            let grid = parse_grid(block.split_once('\n').unwrap().1);
+            format!("Tile {id}:\n{}", grid_to_string(&dihedral(grid, rng)))
+        })
+        .collect();
+    rng.shuffle(&mut tiles);
+    tiles.join("\n\n")
+}
+
+fn day21(input: &str, rng: &mut Rng) -> String {
+    let mut names: HashMap<&str, String> = HashMap::new();
+    let mut used: HashSet<String> = HashSet::new();
+    let mut foods: Vec<String> = input
+        .lines()
+        .map(|line| {
+            let (ingredients, allergens) = match line.split_once(" (") {
+                Some((a, b)) => (a, format!(" ({b}")),
+                None => (line, String::new()),
+            };
+            let mut ingredients: Vec<String> = ingredients
+                .split_whitespace()
+                .map(|s| {
+                    names
+                        .entry(s)
+                        .or_insert_with(|| loop {
+                            let len = rng.range(3, 8) as usize;
+                            let name = rng.word(len);
+                            if used.insert(name.clone()) {
+                                break name;
+                            }
+                        })
+                        .clone()
+                })
+                .collect();
+            rng.shuffle(&mut ingredients);
+            format!("{}{allergens}", ingredients.join(" "))
+        })
+        .collect();
+    rng.shuffle(&mut foods);
+    foods.join("\n")
+}
+
+fn day22(input: &str, rng: &mut Rng) -> String {
+    let (deck1, deck2) = input.split_once("\n\n").unwrap();
+    let n1 = deck1.lines().skip(1).count();
+    let n2 = deck2.lines().skip(1).count();
+    deal_combat(n1, n2, rng)
+}
+
+/// Deal cards `1..=n1 + n2` to two players. Regular Combat can loop forever
+/// on some deals, so re-deal until part one terminates.
+pub(crate) fn deal_combat(n1: usize, n2: usize, rng: &mut Rng) -> String {
+    let mut cards: Vec<usize> = (1..=n1 + n2).collect();
+    let terminates = |cards: &[usize]| {
+        let mut d1: VecDeque<usize> = cards[..n1].iter().copied().collect();
+        let mut d2: VecDeque<usize> = cards[n1..].iter().copied().collect();
+        for _ in 0..100_000 {
+            let (Some(c1), Some(c2)) = (d1.pop_front(), d2.pop_front())
+            else {
+                return true;
+            };
+            if c1 > c2 {
+                d1.extend([c1, c2]);
+            } else {
+                d2.extend([c2, c1]);
+            }
+        }
+        false
+    };
+    loop {
+        rng.shuffle(&mut cards);
+        if terminates(&cards) {
+            break;
+        }
+    }
+
+    let deck = |cards: &[usize]| {
+        cards
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    format!(
+        "Player 1:\n{}\n\nPlayer 2:\n{}",
+        deck(&cards[..n1]),
+        deck(&cards[n1..])
+    )
+}
+
+fn day23(input: &str, rng: &mut Rng) -> String {
+    let mut cups: Vec<char> = input.chars().collect();
+    rng.shuffle(&mut cups);
+    cups.into_iter().collect()
+}
+
+fn day24(input: &str, rng: &mut Rng) -> String {
+    // clockwise order, so rotating by 60° is an index shift and mirroring
+    // across the east-west axis is a negation
+    const DIRS: [&str; 6] = ["e", "se", "sw", "w", "nw", "ne"];
+    let rotation = rng.below(6) as usize;
+    let mirror = rng.chance();
+
+    let mut lines: Vec<String> = input
+        .lines()
+        .map(|line| {
+            let mut steps = Vec::new();
+            let mut rest = line.trim();
+            while !rest.is_empty() {
+                let len = if rest.starts_with(['n', 's']) { 2 } else { 1 };
+                let i = DIRS.iter().position(|&d| d == &rest[..len]).unwrap();
+                let i = if mirror { (6 - i) % 6 } else { i };
+                steps.push(DIRS[(i + rotation) % 6]);
+                rest = &rest[len..];
+            }
+            rng.shuffle(&mut steps);
+            steps.concat()
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.join("\n")
+}
+
+fn day25(input: &str, rng: &mut Rng) -> String {
+    const MODULUS: u64 = 20201227;
+    let public_key = |loop_size: i64| {
+        (0..loop_size).fold(1, |value, _| value * 7 % MODULUS)
+    };
+    input
+        .lines()
+        .map(|_| public_key(rng.range(1_000, 1_000_000)).to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::year2020::*;
+    use crate::*;
+
+    #[test]
+    fn deterministic() {
+        for day in [4, 7, 20, 24] {
+            let input = read_example(day).unwrap();
+            assert_eq!(scramble(day, &input, 42), scramble(day, &input, 42));
+        }
+        let input = read_example(7).unwrap();
+        assert_ne!(scramble(7, &input, 1), scramble(7, &input, 2));
+    }
+
+    #[test]
+    fn preserves_answers() {
+        for seed in 0..8 {
+            let input = scramble(1, &read_example(1).unwrap(), seed);
+            assert_eq!(day01::part_one(&input), 514579);
+            let input = scramble(2, &read_example(2).unwrap(), seed);
+            assert_eq!(day02::part_one(&input), 2);
+            assert_eq!(day02::part_two(&input), 1);
+            let input = scramble(4, &read_example(4).unwrap(), seed);
+            assert_eq!(day04::part_one(&input), 2);
+            let input = scramble(6, &read_example(6).unwrap(), seed);
+            assert_eq!(day06::part_one(&input), 11);
+            assert_eq!(day06::part_two(&input), 6);
+            let input = scramble(7, &read_example(7).unwrap(), seed);
+            assert_eq!(day07::part_one(&input), 4);
+            assert_eq!(day07::part_two(&input), 32);
+            let input = scramble(10, &read_example(10).unwrap(), seed);
+            assert_eq!(day10::part_two(&input), 19208);
+            let input = scramble(11, &read_example(11).unwrap(), seed);
+            assert_eq!(day11::part_one(&input), 37);
+            assert_eq!(day11::part_two(&input), 26);
+            let input = scramble(12, &read_example(12).unwrap(), seed);
+            assert_eq!(day12::part_one(&input), 25);
+            let input = scramble(16, &read_example(16).unwrap(), seed);
+            assert_eq!(day16::part_one(&input), 71);
+            let input = scramble(19, &read_example(19).unwrap(), seed);
+            assert_eq!(day19::part_one(&input), 2);
+            let input = scramble(21, &read_example(21).unwrap(), seed);
+            assert_eq!(day21::part_one(&input), 5);
+            let input = scramble(24, &read_example(24).unwrap(), seed);
+            assert_eq!(day24::part_one(&input), 10);
+        }
+    }
+
+    #[test]
+    fn still_solvable() {
+        day03::part_two(&scramble(3, &read_example(3).unwrap(), 7));
+        day08::part_two(&scramble(8, &read_example(8).unwrap(), 7));
+        day09::part_two(&scramble(9, &read_example(9).unwrap(), 7));
+        day13::part_two(&scramble(13, &read_example(13).unwrap(), 7));
+        day14::part_one(&scramble(14, &read_example(14).unwrap(), 7));
+        day18::part_two(&scramble(18, &read_example(18).unwrap(), 7));
+        day20::part_two(&scramble(20, &read_example(20).unwrap(), 7));
+        day22::part_two(&scramble(22, &read_example(22).unwrap(), 7));
+        day23::part_one(&scramble(23, &read_example(23).unwrap(), 7));
+        day25::part_one(&scramble(25, &read_example(25).unwrap(), 7));
+    }
+}
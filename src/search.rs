@@ -0,0 +1,216 @@
+//! Search Utilities
+//!
+//! Reusable scaffolding for the searches many puzzles need:
+//!
+//! - **Backtracking**: `Backtrack` describes a partial solution that is
+//!   extended one candidate at a time, and `backtrack` explores it depth
+//!   first, undoing candidates that lead nowhere (day 20's jigsaw)
+//! - **Graph traversal**: `bfs` for unweighted distances, `dfs` for
+//!   reachability and `dijkstra` for weighted distances, over any node type
+//!   with a neighbor function
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A problem solved by extending a partial solution step by step.
+pub trait Backtrack {
+    type Candidate;
+
+    /// Whether the partial solution is a full solution.
+    fn is_complete(&self) -> bool;
+
+    /// The candidates for the next step, in the order they are tried.
+    fn candidates(&self) -> Vec<Self::Candidate>;
+
+    /// Whether `candidate` is consistent with the partial solution.
+    fn accepts(&self, candidate: &Self::Candidate) -> bool;
+
+    /// Extend the partial solution with `candidate`.
+    fn apply(&mut self, candidate: Self::Candidate);
+
+    /// Revert the most recent `apply`.
+    fn undo(&mut self);
+}
+
+/// Search depth first for a full solution, leaving it in `problem`. Returns
+/// false, with `problem` back in its initial state, if there is none.
+pub fn backtrack<P: Backtrack>(problem: &mut P) -> bool {
+    if problem.is_complete() {
+        return true;
+    }
+    for candidate in problem.candidates() {
+        if !problem.accepts(&candidate) {
+            continue;
+        }
+        problem.apply(candidate);
+        if backtrack(problem) {
+            return true;
+        }
+        problem.undo();
+    }
+    false
+}
+
+/// Number of steps from `start` to every reachable node.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let d = distances[&node];
+        for next in neighbors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), d + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Every node reachable from `start`, in depth-first preorder.
+pub fn dfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+        let mut next: Vec<N> = neighbors(&node)
+            .into_iter()
+            .filter(|n| !visited.contains(n))
+            .collect();
+        // visit neighbors in the order they were given
+        next.reverse();
+        stack.extend(next);
+        order.push(node);
+    }
+    order
+}
+
+/// Lowest total cost from `start` to every reachable node, where
+/// `neighbors` yields each neighbor with the cost of the edge to it.
+pub fn dijkstra<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> HashMap<N, u64>
+where
+    N: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut heap = BinaryHeap::from([Reverse((0, start))]);
+    while let Some(Reverse((d, node))) = heap.pop() {
+        if distances.get(&node).is_some_and(|&best| best < d) {
+            continue;
+        }
+        for (next, cost) in neighbors(&node) {
+            let d = d + cost;
+            if distances.get(&next).is_none_or(|&best| d < best) {
+                distances.insert(next.clone(), d);
+                heap.push(Reverse((d, next)));
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Queens placed row by row, one column per row.
+    struct Queens {
+        n: usize,
+        columns: Vec<usize>,
+        undone: usize,
+    }
+
+    impl Backtrack for Queens {
+        type Candidate = usize;
+
+        fn is_complete(&self) -> bool {
+            self.columns.len() == self.n
+        }
+
+        fn candidates(&self) -> Vec<usize> {
+            (0..self.n).collect()
+        }
+
+        fn accepts(&self, &column: &usize) -> bool {
+            let row = self.columns.len();
+            self.columns
+                .iter()
+                .enumerate()
+                .all(|(r, &c)| c != column && row - r != c.abs_diff(column))
+        }
+
+        fn apply(&mut self, column: usize) {
+            self.columns.push(column);
+        }
+
+        fn undo(&mut self) {
+            self.columns.pop();
+            self.undone += 1;
+        }
+    }
+
+    #[test]
+    fn queens() {
+        let mut queens = Queens {
+            n: 8,
+            columns: vec![],
+            undone: 0,
+        };
+        assert!(backtrack(&mut queens));
+        assert_eq!(queens.columns, [0, 4, 7, 5, 2, 6, 1, 3]);
+        assert!(queens.undone > 0);
+
+        let mut queens = Queens {
+            n: 3,
+            columns: vec![],
+            undone: 0,
+        };
+        assert!(!backtrack(&mut queens));
+        assert!(queens.columns.is_empty());
+    }
+
+    #[test]
+    fn traversal() {
+        // 0 -> 1 -> 3, 0 -> 2 -> 3, 4 unreachable
+        let edges = |n: &u32| match n {
+            0 => vec![1, 2],
+            1 | 2 => vec![3],
+            _ => vec![],
+        };
+        let distances = bfs(0, edges);
+        assert_eq!(distances.len(), 4);
+        assert_eq!(distances[&3], 2);
+        assert_eq!(dfs(0, edges), [0, 1, 3, 2]);
+    }
+
+    #[test]
+    fn weighted() {
+        let edges = |n: &char| match n {
+            'a' => vec![('b', 7), ('c', 2)],
+            'c' => vec![('b', 3), ('d', 10)],
+            'b' => vec![('d', 1)],
+            _ => vec![],
+        };
+        let distances = dijkstra('a', edges);
+        assert_eq!(distances[&'b'], 5);
+        assert_eq!(distances[&'d'], 6);
+    }
+}
@@ -0,0 +1,151 @@
+//! Day Selection
+//!
+//! Parses the days given on the command line. Each argument is a comma
+//! separated list of days (`9`), inclusive ranges (`5-12`) and exclusions
+//! of either (`!23`, `!20-22`). Exclusions alone apply to all days, so
+//! `'!15' '!23'` runs everything but the two slowest days.
+//!
+//! `--filter` narrows the days down by title instead, with a small subset
+//! of regular expressions: alternatives separated by `|`, each matching a
+//! case-insensitive substring, optionally anchored with `^` and `$`.
+
+/// The day of the puzzle unlocked last at `timestamp` (seconds since the
+/// Unix epoch), in the UTC-5 timezone of Advent of Code, from December 1
+/// to 25 only.
+pub fn puzzle_day(timestamp: u64) -> Result<usize, String> {
+    let (y, m, d) =
+        crate::history::civil_date(timestamp.saturating_sub(5 * 3600));
+    match (m, d) {
+        (12, 1..=25) => Ok(d as usize),
+        _ => Err(format!(
+            "no puzzle today: {y:04}-{m:02}-{d:02} is outside December 1-25"
+        )),
+    }
+}
+
+/// Whether `title` matches the `--filter` `pattern`, e.g. `conway`,
+/// `^crab` or `seating|conway`.
+pub fn title_matches(pattern: &str, title: &str) -> bool {
+    let title = title.to_lowercase();
+    pattern.to_lowercase().split('|').any(|alternative| {
+        let (start, rest) = match alternative.strip_prefix('^') {
+            Some(rest) => (true, rest),
+            None => (false, alternative),
+        };
+        let (end, needle) = match rest.strip_suffix('$') {
+            Some(needle) => (true, needle),
+            None => (false, rest),
+        };
+        match (start, end) {
+            (true, true) => title == needle,
+            (true, false) => title.starts_with(needle),
+            (false, true) => title.ends_with(needle),
+            (false, false) => title.contains(needle),
+        }
+    })
+}
+
+/// Whether `arg` looks like a day selector rather than a subcommand.
+pub fn is_selector(arg: &str) -> bool {
+    arg.starts_with(|c: char| c.is_ascii_digit() || c == '!')
+}
+
+/// The selected days among `1..=count`, in the order first given and
+/// without duplicates; all days when no days are given.
+pub fn parse_days(args: &[&str], count: usize) -> Result<Vec<usize>, String> {
+    let mut included = Vec::new();
+    let mut excluded = Vec::new();
+    for item in args.iter().flat_map(|arg| arg.split(',')) {
+        let (list, spec) = match item.strip_prefix('!') {
+            Some(spec) => (&mut excluded, spec),
+            None => (&mut included, item),
+        };
+        let day = |s: &str| -> Result<usize, String> {
+            let day: usize = s
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid day selector: {item}"))?;
+            if !(1..=count).contains(&day) {
+                return Err(format!("day {day} is out of range 1-{count}"));
+            }
+            Ok(day)
+        };
+        match spec.split_once('-') {
+            Some((a, b)) => {
+                let (a, b) = (day(a)?, day(b)?);
+                if a > b {
+                    return Err(format!("empty day range: {item}"));
+                }
+                list.extend(a..=b);
+            }
+            None => list.push(day(spec)?),
+        }
+    }
+    if included.is_empty() {
+        included.extend(1..=count);
+    }
+    let mut days = Vec::new();
+    for day in included {
+        if !excluded.contains(&day) && !days.contains(&day) {
+            days.push(day);
+        }
+    }
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selectors() {
+        assert_eq!(parse_days(&[], 3), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_days(&["5-7", "2"], 25), Ok(vec![5, 6, 7, 2]));
+        assert_eq!(parse_days(&["1,3,9,3"], 25), Ok(vec![1, 3, 9]));
+        assert_eq!(parse_days(&["!2"], 4), Ok(vec![1, 3, 4]));
+        assert_eq!(parse_days(&["1-5", "!2-4"], 25), Ok(vec![1, 5]));
+        assert_eq!(
+            parse_days(&["26"], 25),
+            Err("day 26 is out of range 1-25".to_string())
+        );
+        assert_eq!(
+            parse_days(&["0-3"], 25),
+            Err("day 0 is out of range 1-25".to_string())
+        );
+        assert_eq!(
+            parse_days(&["3x"], 25),
+            Err("invalid day selector: 3x".to_string())
+        );
+        assert_eq!(
+            parse_days(&["9-5"], 25),
+            Err("empty day range: 9-5".to_string())
+        );
+        assert!(is_selector("!23") && is_selector("5-12"));
+        assert!(!is_selector("answers"));
+    }
+
+    #[test]
+    fn filters() {
+        assert!(title_matches("conway", "Conway Cubes"));
+        assert!(title_matches("^Crab", "Crab Cups"));
+        assert!(!title_matches("^cups", "Crab Cups"));
+        assert!(title_matches("cups$", "Crab Cups"));
+        assert!(title_matches("^crab cups$", "Crab Cups"));
+        assert!(title_matches("seating|conway", "Seating System"));
+        assert!(!title_matches("seating|conway", "Crab Combat"));
+    }
+
+    #[test]
+    fn today() {
+        // 2020-12-01 05:00 UTC is midnight in the timezone of the puzzles
+        let unlock = 1606798800;
+        assert_eq!(puzzle_day(unlock), Ok(1));
+        assert!(puzzle_day(unlock - 1).is_err());
+        assert_eq!(puzzle_day(unlock + 24 * 86400), Ok(25));
+        assert_eq!(
+            puzzle_day(unlock + 25 * 86400),
+            Err("no puzzle today: 2020-12-26 is outside December 1-25"
+                .to_string())
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! Solver Service
+//!
+//! The HTTP/1.1 of `aoc serve`, enough for `curl` and a Prometheus scraper:
+//! one request a connection, read with `read_request` and answered with
+//! `write_response`. `route` tells what a request asks for:
+//!
+//! - `POST /solve/<day>/<part>` with the input as the body, answered with
+//!   the answer as plain text
+//! - `GET /metrics`, the `Metrics` of the parts solved so far
+
+use std::io::{self, BufRead, Write};
+
+/// The largest input the service reads, far above any puzzle's.
+pub const MAX_BODY: usize = 16 << 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+}
+
+/// What a request asks the service for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Route {
+    Solve {
+        day: u8,
+        part: u8,
+    },
+    Metrics,
+    /// A path the service does not serve, or a part other than 1 or 2.
+    NotFound,
+    MethodNotAllowed,
+}
+
+/// Read a request with its `Content-Length` body, failing with
+/// `InvalidData` on anything else.
+pub fn read_request(reader: &mut impl BufRead) -> io::Result<Request> {
+    let invalid = |message: &str| {
+        io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+    };
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut words = line.split_whitespace();
+    let (Some(method), Some(path), Some(_version)) =
+        (words.next(), words.next(), words.next())
+    else {
+        return Err(invalid("expected a request line"));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut length = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(invalid("the headers end early"));
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| invalid("invalid Content-Length"))?;
+            }
+        }
+    }
+    if length > MAX_BODY {
+        return Err(invalid("the body is too large"));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    let body = String::from_utf8(body)
+        .map_err(|_| invalid("the body is not UTF-8"))?;
+    Ok(Request { method, path, body })
+}
+
+/// What `request` asks for.
+pub fn route(request: &Request) -> Route {
+    let segments: Vec<&str> =
+        request.path.trim_matches('/').split('/').collect();
+    let (route, method) = match segments[..] {
+        ["metrics"] => (Route::Metrics, "GET"),
+        ["solve", day, part] => match (day.parse(), part.parse()) {
+            (Ok(day), Ok(part @ (1 | 2))) => {
+                (Route::Solve { day, part }, "POST")
+            }
+            _ => return Route::NotFound,
+        },
+        _ => return Route::NotFound,
+    };
+    match request.method == method {
+        true => route,
+        false => Route::MethodNotAllowed,
+    }
+}
+
+/// Write a response with `status`, e.g. `200 OK`, and a plain text or
+/// other `content_type` body, closing the connection after it.
+pub fn write_response(
+    writer: &mut impl Write,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> io::Result<()> {
+    write!(
+        writer,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(raw: &str) -> io::Result<Request> {
+        read_request(&mut raw.as_bytes())
+    }
+
+    #[test]
+    fn requests() {
+        let solve = request(
+            "POST /solve/1/2 HTTP/1.1\r\nHost: localhost\r\n\
+             content-length: 9\r\n\r\n1721\n979\nignored",
+        )
+        .unwrap();
+        assert_eq!(solve.body, "1721\n979\n");
+        assert_eq!(route(&solve), Route::Solve { day: 1, part: 2 });
+
+        let metrics = request("GET /metrics HTTP/1.1\r\n\r\n").unwrap();
+        assert_eq!(
+            (metrics.method.as_str(), metrics.body.as_str()),
+            ("GET", "")
+        );
+        assert_eq!(route(&metrics), Route::Metrics);
+
+        assert!(request("GET /metrics\r\n\r\n").is_err());
+        assert!(request("GET /metrics HTTP/1.1\r\nHost: a\r\n").is_err());
+        let long = "POST /solve/1/1 HTTP/1.1\r\nContent-Length: 5\r\n\r\n12";
+        assert!(request(long).is_err());
+    }
+
+    #[test]
+    fn routes() {
+        let route_of = |method: &str, path: &str| {
+            route(&Request {
+                method: method.to_string(),
+                path: path.to_string(),
+                body: String::new(),
+            })
+        };
+        assert_eq!(route_of("GET", "/solve/1/1"), Route::MethodNotAllowed);
+        assert_eq!(route_of("POST", "/metrics"), Route::MethodNotAllowed);
+        assert_eq!(route_of("POST", "/solve/1/3"), Route::NotFound);
+        assert_eq!(route_of("POST", "/solve/x/1"), Route::NotFound);
+        assert_eq!(route_of("GET", "/"), Route::NotFound);
+    }
+
+    #[test]
+    fn responses() {
+        let mut out = Vec::new();
+        write_response(&mut out, "200 OK", "text/plain", "514579").unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\n\
+             Content-Length: 6\r\nConnection: close\r\n\r\n514579"
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! Solver Trait
+//!
+//! Every day module implements `Solver` on its `Solution`, splitting the
+//! puzzle into parsing the input and solving each part on the parsed
+//! form, so callers that want both answers parse the input only once. The
+//! `part_one` and `part_two` functions of a day module take the raw input
+//! and do both stages, panicking where the stages return an error.
+//! `prepare` keeps the parsed form behind `Prepared` for callers that do
+//! not know the day's types, such as the registry.
+
+use core::fmt;
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+
+use crate::error::AocError;
+#[cfg(feature = "std")]
+use crate::timing::{Phases, Stopwatch};
+use crate::Answer;
+
+pub trait Solver {
+    /// The parsed input, which may borrow from the input text.
+    type Parsed<'a>;
+    type One: Into<Answer>;
+    type Two: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError>;
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Self::One, AocError>;
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Self::Two, AocError>;
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// `1` or `2`.
+    pub const fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> u8 {
+        part.number()
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(part: u8) -> Result<Self, String> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("no part {part}")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// The answer of `part` of `S` for `input`.
+pub fn solve<S: Solver>(part: u8, input: &str) -> Result<Answer, AocError> {
+    let parsed = S::parse(input)?;
+    match part {
+        1 => S::part_one(&parsed).map(Into::into),
+        2 => S::part_two(&parsed).map(Into::into),
+        _ => Err(AocError::UnknownPart(part)),
+    }
+}
+
+/// Like `solve`, with how long parsing and solving took.
+#[cfg(feature = "std")]
+pub fn solve_timed<S: Solver>(
+    part: u8,
+    input: &str,
+) -> Result<(Answer, Phases), AocError> {
+    let mut stopwatch = Stopwatch::start();
+    let parsed = S::parse(input)?;
+    let parse = stopwatch.lap();
+    let answer = match part {
+        1 => S::part_one(&parsed).map(Into::into),
+        2 => S::part_two(&parsed).map(Into::into),
+        _ => Err(AocError::UnknownPart(part)),
+    }?;
+    let solve = stopwatch.lap();
+    Ok((answer, Phases { parse, solve }))
+}
+
+/// A parsed input, ready to solve either part as often as needed.
+pub trait Prepared {
+    /// The answer of `part` for the parsed input.
+    fn solve(&self, part: u8) -> Result<Answer, AocError>;
+}
+
+/// The parsed form of `S`.
+struct ParsedBy<'a, S: Solver>(S::Parsed<'a>);
+
+impl<S: Solver> Prepared for ParsedBy<'_, S> {
+    fn solve(&self, part: u8) -> Result<Answer, AocError> {
+        match part {
+            1 => S::part_one(&self.0).map(Into::into),
+            2 => S::part_two(&self.0).map(Into::into),
+            _ => Err(AocError::UnknownPart(part)),
+        }
+    }
+}
+
+/// `input` parsed by `S`, to solve both parts without parsing it again.
+pub fn prepare<S: Solver + 'static>(
+    input: &str,
+) -> Result<Box<dyn Prepared + '_>, AocError> {
+    Ok(Box::new(ParsedBy::<S>(S::parse(input)?)))
+}
+
+/// The answers of both parts of `S` for `input`, parsing it once.
+pub fn solve_both<S: Solver>(
+    input: &str,
+) -> Result<(Answer, Answer), AocError> {
+    let parsed = S::parse(input)?;
+    Ok((S::part_one(&parsed)?.into(), S::part_two(&parsed)?.into()))
+}
+
+/// Part one of `S` for `input`, panicking with the error if any.
+pub fn part_one<S: Solver>(input: &str) -> S::One {
+    S::parse(input)
+        .and_then(|parsed| S::part_one(&parsed))
+        .unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Part two of `S` for `input`, panicking with the error if any.
+pub fn part_two<S: Solver>(input: &str) -> S::Two {
+    S::parse(input)
+        .and_then(|parsed| S::part_two(&parsed))
+        .unwrap_or_else(|e| panic!("{e}"))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::read_example;
+    use crate::year2020::{day01, day16};
+    use std::time::Duration;
+
+    #[test]
+    fn parse_once() {
+        let input = read_example(16).unwrap();
+        let both = solve_both::<day16::Solution>(&input).unwrap();
+        assert_eq!(both.0, solve::<day16::Solution>(1, &input).unwrap());
+        assert_eq!(both.1, solve::<day16::Solution>(2, &input).unwrap());
+    }
+
+    #[test]
+    fn prepared() {
+        let input = read_example(16).unwrap();
+        let prepared = prepare::<day16::Solution>(&input).unwrap();
+        for part in [1, 2] {
+            let answer = solve::<day16::Solution>(part, &input).unwrap();
+            assert_eq!(prepared.solve(part).unwrap(), answer);
+        }
+        assert!(prepare::<day01::Solution>("x\n").is_err());
+    }
+
+    #[test]
+    fn phases() {
+        let input = read_example(16).unwrap();
+        let (answer, phases) =
+            solve_timed::<day16::Solution>(1, &input).unwrap();
+        assert_eq!(answer, solve::<day16::Solution>(1, &input).unwrap());
+        assert!(phases.parse > Duration::ZERO);
+        let error = solve_timed::<day01::Solution>(1, "x\n").unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: 1, .. }));
+    }
+
+    #[test]
+    fn parts() {
+        assert_eq!(Part::try_from(2), Ok(Part::Two));
+        assert_eq!(Part::try_from(3).unwrap_err(), "no part 3");
+        assert_eq!(Part::ALL.map(u8::from), [1, 2]);
+        assert_eq!(Part::One.to_string(), "1");
+    }
+
+    #[test]
+    fn errors() {
+        let error = solve::<day01::Solution>(1, "1721\n979\nx\n");
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "line 3: invalid number `x`"
+        );
+        let error = solve::<day01::Solution>(1, "1\n2\n").unwrap_err();
+        assert!(matches!(error, AocError::NoSolution(_)));
+        let error = solve::<day01::Solution>(3, "1721\n299\n").unwrap_err();
+        assert_eq!(error.to_string(), "no part 3");
+        let prepared = prepare::<day01::Solution>("1721\n299\n").unwrap();
+        assert!(matches!(prepared.solve(0), Err(AocError::UnknownPart(0))));
+    }
+}
@@ -0,0 +1,159 @@
+//! Stress Tests
+//!
+//! Differential testing of the optimized core of a day against a slow
+//! reference implementation, on random inputs that grow with every round so
+//! that size-dependent bugs show up before a real input trips over them:
+//!
+//! - **Day 9**: the two-pointer contiguous sum against trying every range,
+//!   on XMAS sequences with preambles of 2 to 25 numbers
+//! - **Day 15**: the vector memory game against the HashMap one, for
+//!   `2020 · round` turns
+//! - **Day 23**: the array linked list against the VecDeque crab cups, with
+//!   `9 + 10 · round` cups and `100 · round` moves
+//!
+//! The same day, round and seed always produce the same case.
+
+use crate::rng::Rng;
+use crate::year2020::{day09, day15, day23};
+
+/// The days with a reference implementation to stress against.
+pub const DAYS: [u8; 3] = [9, 15, 23];
+
+/// One random input with the answers of both implementations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case {
+    pub input: String,
+    /// The size of the case, e.g. `2020 turns`.
+    pub label: String,
+    pub fast: String,
+    pub reference: String,
+}
+
+/// The case of `round` (from 1) for the given day, if it has a reference
+/// implementation.
+pub fn case(day: u8, round: usize, seed: u64) -> Option<Case> {
+    let rng = &mut Rng::new(seed);
+    match day {
+        9 => Some(day09_case(rng, round)),
+        15 => Some(day15_case(rng, round)),
+        23 => Some(day23_case(rng, round)),
+        _ => None,
+    }
+}
+
+fn day09_case(rng: &mut Rng, round: usize) -> Case {
+    const LIMIT: u64 = 1 << 50;
+    let len = rng.range(2, 25) as usize;
+    let mut nums: Vec<u64> = Vec::new();
+    while nums.len() < len {
+        let v = rng.range(1, 50) as u64;
+        if !nums.contains(&v) {
+            nums.push(v);
+        }
+    }
+    // every number is the sum of two different ones of its preamble
+    while nums.len() < len + 10 * round {
+        let window = &nums[nums.len() - len..];
+        let a = window[rng.below(len as u64) as usize];
+        let b = window[rng.below(len as u64) as usize];
+        if a == b {
+            continue;
+        }
+        if a + b > LIMIT {
+            break;
+        }
+        nums.push(a + b);
+    }
+    // the sum of a short range is usually invalid, the sum of all numbers
+    // always is
+    let window = &nums[nums.len() - len..];
+    let valid = |s: u64| {
+        window
+            .iter()
+            .any(|&a| window.iter().any(|&b| a != b && a + b == s))
+    };
+    let invalid = (0..100)
+        .map(|_| {
+            let a = rng.below(nums.len() as u64 - 1) as usize;
+            let b = (a + rng.range(1, 3) as usize).min(nums.len() - 1);
+            nums[a..=b].iter().sum()
+        })
+        .find(|&s| !valid(s))
+        .unwrap_or_else(|| nums.iter().sum());
+    nums.push(invalid);
+
+    Case {
+        input: nums.iter().map(|n| format!("{n}\n")).collect(),
+        label: format!("preamble {len}, {} numbers", nums.len()),
+        fast: day09::find_invalid_sum(&nums, len).unwrap().to_string(),
+        reference: day09::find_invalid_sum_brute(&nums, len)
+            .unwrap()
+            .to_string(),
+    }
+}
+
+fn day15_case(rng: &mut Rng, round: usize) -> Case {
+    let mut numbers: Vec<usize> = (0..20).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(rng.range(2, 6) as usize);
+    let turns = 2020 * round;
+    let input: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+    Case {
+        input: input.join(",") + "\n",
+        label: format!("{turns} turns"),
+        // nothing cancels a stress run
+        fast: day15::target_number(numbers.clone(), turns)
+            .unwrap()
+            .to_string(),
+        reference: day15::target_number_hashmap(numbers, turns).to_string(),
+    }
+}
+
+fn day23_case(rng: &mut Rng, round: usize) -> Case {
+    let mut cups: Vec<u32> = (1..=9).collect();
+    rng.shuffle(&mut cups);
+    let (total, moves) = (9 + 10 * round, 100 * round);
+    let labels = |cups: &[u32]| -> String {
+        let labels: Vec<String> =
+            cups.iter().map(|c| c.to_string()).collect();
+        labels.join(",")
+    };
+
+    let fast =
+        day23::play_game_efficient(cups.clone(), total, moves).unwrap();
+    let all: Vec<u32> =
+        cups.iter().copied().chain(10..=total as u32).collect();
+    let mut circle = day23::play_game_simple(all, moves);
+    let one = circle.iter().position(|&c| c == 1).unwrap();
+    circle.rotate_left(one);
+    circle.pop_front();
+
+    Case {
+        input: labels(&cups).replace(',', "") + "\n",
+        label: format!("{total} cups, {moves} moves"),
+        fast: labels(&fast),
+        reference: labels(circle.make_contiguous()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn agree() {
+        for day in DAYS {
+            for round in 1..=3 {
+                let case = case(day, round, round as u64).unwrap();
+                assert_eq!(case.fast, case.reference, "day {day} {case:?}");
+            }
+        }
+        assert_eq!(case(1, 1, 0), None);
+    }
+
+    #[test]
+    fn deterministic() {
+        assert_eq!(case(9, 2, 7), case(9, 2, 7));
+        assert!(case(23, 1, 7).unwrap().label.starts_with("19 cups"));
+    }
+}
@@ -0,0 +1,163 @@
+//! Answer Submission
+//!
+//! Posts an answer to adventofcode.com and reads the verdict out of the
+//! returned page. The page is HTML meant for people, so the verdict is
+//! recognized by the phrases Advent of Code has used since 2015:
+//!
+//! - "That's the right answer!"
+//! - "That's not the right answer; your answer is too high."
+//! - "You gave an answer too recently; you have 41s left to wait."
+//! - "You don't seem to be solving the right level." (already solved)
+
+use std::fmt;
+use std::io;
+
+/// What adventofcode.com said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too soon after a wrong answer; how long to wait.
+    Wait(String),
+    AlreadySolved,
+    /// The text of a page that could not be understood.
+    Unknown(String),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right answer"),
+            Verdict::TooHigh => write!(f, "wrong answer, too high"),
+            Verdict::TooLow => write!(f, "wrong answer, too low"),
+            Verdict::Wrong => write!(f, "wrong answer"),
+            Verdict::Wait(left) => {
+                write!(f, "answered too recently, wait {left}")
+            }
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown(text) => {
+                write!(f, "unexpected response: {text}")
+            }
+        }
+    }
+}
+
+/// Submit `answer` for one part of a day of the `year` with the session
+/// `cookie`.
+pub fn submit(
+    day: u8,
+    part: u8,
+    answer: &str,
+    cookie: &str,
+) -> io::Result<Verdict> {
+    let url = format!(
+        "https://adventofcode.com/{}/day/{day}/answer",
+        crate::year()
+    );
+    let level = part.to_string();
+    let page = crate::http::post_form(
+        &url,
+        &[("Cookie", cookie)],
+        &[("level", &level), ("answer", answer)],
+    )?;
+    Ok(verdict(&page))
+}
+
+/// The verdict in the response page to a submission.
+pub fn verdict(page: &str) -> Verdict {
+    let text = article_text(page);
+    if text.contains("That's the right answer") {
+        Verdict::Right
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let left = text
+            .split_once("you have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map_or("a while", |(left, _)| left);
+        Verdict::Wait(left.to_string())
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown(text)
+    }
+}
+
+/// The text of the page's `<article>`, or the whole page, without tags.
+fn article_text(page: &str) -> String {
+    // inside the article, the rest of the opening tag comes first
+    let (article, mut in_tag) = match page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+    {
+        Some((article, _)) => (article, true),
+        None => (page, false),
+    };
+    let mut text = String::new();
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(
+            verdict(&page("That's the right answer! You are <em>one gold star</em> closer.")),
+            Verdict::Right
+        );
+        assert_eq!(
+            verdict(&page("That's not the right answer; your answer is too high. Please wait one minute.")),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            verdict(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            verdict(&page(
+                "That's not the right answer. If you're stuck, ..."
+            )),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            verdict(&page("You gave an answer too recently; you have 4m 27s left to wait.")),
+            Verdict::Wait("4m 27s".to_string())
+        );
+        assert_eq!(
+            verdict(&page("You don't seem to be solving the right level.  Did you already complete it?")),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(
+            verdict(&page("<b>Maintenance</b>")),
+            Verdict::Unknown("Maintenance".to_string())
+        );
+        assert_eq!(
+            verdict("Internal Server Error"),
+            Verdict::Unknown("Internal Server Error".to_string())
+        );
+    }
+}
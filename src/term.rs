@@ -0,0 +1,46 @@
+//! Terminal Colors
+//!
+//! ANSI styling for the runner's output. Colors are only used when stdout
+//! is a terminal and `NO_COLOR` is not set, so piped output stays plain.
+
+use std::env;
+use std::io::{self, IsTerminal};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Verified answers.
+    Green,
+    /// Wrong answers and failures.
+    Red,
+    /// Warnings.
+    Yellow,
+    /// Secondary details such as timings.
+    Dim,
+}
+
+/// Whether stdout should be colored.
+pub fn enabled() -> bool {
+    env::var_os("NO_COLOR").is_none() && io::stdout().is_terminal()
+}
+
+/// `text` wrapped in the escape codes of `style`.
+pub fn paint(text: &str, style: Style) -> String {
+    let code = match style {
+        Style::Green => "32",
+        Style::Red => "31",
+        Style::Yellow => "33",
+        Style::Dim => "2",
+    };
+    format!("\x1b[{code}m{text}\x1b[0m")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_codes() {
+        assert_eq!(paint("42", Style::Green), "\x1b[32m42\x1b[0m");
+        assert_eq!(paint("1.2ms", Style::Dim), "\x1b[2m1.2ms\x1b[0m");
+    }
+}
@@ -0,0 +1,100 @@
+//! Timeouts
+//!
+//! Support for `--timeout`, which abandons a runaway part instead of
+//! hanging the whole run. A part runs on a worker thread and is left behind
+//! when it does not finish in time, cancelling its `CancelToken`: the
+//! thread stops at the next `cancel::check` of its solver, or keeps running
+//! until the process exits if the solver never checks.
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::cancel::{self, CancelToken};
+
+/// Parse a duration such as `5s`, `500ms`, `2m` or `1.5` (seconds).
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value: f64 = value
+        .parse()
+        .map_err(|_| format!("invalid duration: {s}"))?;
+    let seconds = match unit {
+        "" | "s" => value,
+        "ms" => value / 1000.0,
+        "m" => value * 60.0,
+        _ => return Err(format!("invalid duration unit: {unit}")),
+    };
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|_| format!("duration out of range: {s}"))
+}
+
+/// The result of `f` with `token` current, or `None` if it takes longer
+/// than `timeout`, which cancels `token`.
+pub fn run_with_timeout<T, F>(
+    timeout: Duration,
+    token: &CancelToken,
+    f: F,
+) -> Option<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let current = token.clone();
+    thread::spawn(move || {
+        // the receiver is gone after a timeout
+        let _ = tx.send(cancel::with(&current, f));
+    });
+    let result = rx.recv_timeout(timeout).ok();
+    if result.is_none() {
+        token.cancel();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("5s"), Ok(Duration::from_secs(5)));
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert!(parse_duration("5h").is_err());
+        assert!(parse_duration("s").is_err());
+        assert!(parse_duration("1e30").is_err());
+        assert!(parse_duration("inf").is_err());
+        let huge = format!("1{}", "0".repeat(30));
+        assert_eq!(
+            parse_duration(&huge),
+            Err(format!("duration out of range: {huge}"))
+        );
+        assert!(parse_duration("400000000000000000m").is_err());
+    }
+
+    #[test]
+    fn timeouts() {
+        let token = CancelToken::new();
+        let quick = run_with_timeout(Duration::from_secs(10), &token, || 42);
+        assert_eq!(quick, Some(42));
+        assert!(!token.is_cancelled());
+        let (tx, rx) = mpsc::channel();
+        let slow =
+            run_with_timeout(Duration::from_millis(10), &token, move || {
+                while cancel::check().is_ok() {
+                    thread::sleep(Duration::from_millis(1));
+                }
+                tx.send("cancelled").unwrap();
+            });
+        assert_eq!(slow, None);
+        assert!(token.is_cancelled());
+        let stopped = rx.recv_timeout(Duration::from_secs(10));
+        assert_eq!(stopped, Ok("cancelled"));
+    }
+}
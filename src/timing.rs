@@ -0,0 +1,204 @@
+//! Timing
+//!
+//! Measuring how long things take and saying so: a `Stopwatch` on the
+//! monotonic clock, which unlike `SystemTime` never goes backwards, a
+//! `scoped` one that logs its scope, `format_duration` for output meant for
+//! people, and `Phases`, the split of a part into parsing the input and
+//! solving, which `registry::Puzzle::solve_timed` measures.
+
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+use std::time::{Duration, Instant};
+
+/// Measures the time since it was started, and between laps.
+#[derive(Debug, Clone, Copy)]
+pub struct Stopwatch {
+    start: Instant,
+    lap: Instant,
+}
+
+impl Stopwatch {
+    pub fn start() -> Self {
+        let now = Instant::now();
+        Stopwatch {
+            start: now,
+            lap: now,
+        }
+    }
+
+    /// The time since the stopwatch was started.
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// The time since the last lap, or since the start for the first.
+    pub fn lap(&mut self) -> Duration {
+        let now = Instant::now();
+        let lap = now - self.lap;
+        self.lap = now;
+        lap
+    }
+}
+
+/// Logs how long its scope took when dropped, at debug level.
+#[derive(Debug)]
+pub struct Scoped<'a> {
+    label: &'a str,
+    stopwatch: Stopwatch,
+}
+
+impl Drop for Scoped<'_> {
+    fn drop(&mut self) {
+        let elapsed = format_duration(self.stopwatch.elapsed());
+        log::debug!("{} took {elapsed}", self.label);
+    }
+}
+
+/// Time the rest of the enclosing scope, e.g.
+/// `let _timer = timing::scoped("reading inputs");`.
+pub fn scoped(label: &str) -> Scoped<'_> {
+    Scoped {
+        label,
+        stopwatch: Stopwatch::start(),
+    }
+}
+
+/// The result of `f` and how long it took.
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let stopwatch = Stopwatch::start();
+    let result = f();
+    (result, stopwatch.elapsed())
+}
+
+/// `duration` to three significant digits in the largest unit below it,
+/// e.g. `812ns`, `75.5µs`, `2.39s`, or in minutes and seconds from a
+/// minute on, e.g. `1m 05s`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1000 {
+        return format!("{nanos}ns");
+    }
+    // the limits are where rounding would reach the next unit
+    for (unit, scale, limit) in
+        [("µs", 1e3, 999.5), ("ms", 1e6, 999.5), ("s", 1e9, 59.95)]
+    {
+        let value = nanos as f64 / scale;
+        if value < limit {
+            let decimals = match value {
+                v if v < 9.995 => 2,
+                v if v < 99.95 => 1,
+                _ => 0,
+            };
+            return format!("{value:.decimals$}{unit}");
+        }
+    }
+    let seconds = duration.as_secs_f64().round() as u64;
+    format!("{}m {:02}s", seconds / 60, seconds % 60)
+}
+
+/// How long parsing the input and solving the parsed form took.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Phases {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Phases {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+
+    /// The share of parsing in the total, in percent.
+    pub fn parse_share(&self) -> f64 {
+        match self.total().is_zero() {
+            true => 0.0,
+            false => {
+                self.parse.as_secs_f64() / self.total().as_secs_f64() * 100.0
+            }
+        }
+    }
+}
+
+impl Add for Phases {
+    type Output = Phases;
+
+    fn add(self, other: Phases) -> Phases {
+        Phases {
+            parse: self.parse + other.parse,
+            solve: self.solve + other.solve,
+        }
+    }
+}
+
+impl Sum for Phases {
+    fn sum<I: Iterator<Item = Phases>>(iter: I) -> Phases {
+        iter.fold(Phases::default(), Add::add)
+    }
+}
+
+impl fmt::Display for Phases {
+    /// E.g. `parse 270µs + solve 47.5ms`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "parse {} + solve {}",
+            format_duration(self.parse),
+            format_duration(self.solve)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formatting() {
+        for (duration, text) in [
+            (Duration::from_nanos(812), "812ns"),
+            (Duration::from_nanos(1234), "1.23µs"),
+            (Duration::from_nanos(75_512), "75.5µs"),
+            (Duration::from_nanos(999_499), "999µs"),
+            (Duration::from_nanos(999_500), "1.00ms"),
+            (Duration::from_nanos(2_385_091_644), "2.39s"),
+            (Duration::from_millis(59_960), "1m 00s"),
+            (Duration::from_secs(65), "1m 05s"),
+            (Duration::from_secs(3725), "62m 05s"),
+        ] {
+            assert_eq!(format_duration(duration), text);
+        }
+    }
+
+    #[test]
+    fn phases() {
+        let ms = Duration::from_millis;
+        let phases = [
+            Phases {
+                parse: ms(1),
+                solve: ms(3),
+            },
+            Phases {
+                parse: ms(1),
+                solve: ms(5),
+            },
+        ];
+        let total: Phases = phases.into_iter().sum();
+        assert_eq!((total.parse, total.solve), (ms(2), ms(8)));
+        assert_eq!(total.total(), ms(10));
+        assert_eq!(total.parse_share(), 20.0);
+        assert_eq!(total.to_string(), "parse 2.00ms + solve 8.00ms");
+        assert_eq!(Phases::default().parse_share(), 0.0);
+    }
+
+    #[test]
+    fn stopwatch() {
+        let mut stopwatch = Stopwatch::start();
+        let ((), slept) =
+            time(|| std::thread::sleep(Duration::from_millis(2)));
+        assert!(slept >= Duration::from_millis(2));
+        let first = stopwatch.lap();
+        let second = stopwatch.lap();
+        assert!(first >= slept && stopwatch.elapsed() >= first + second);
+    }
+}
@@ -0,0 +1,48 @@
+//! Input Validation
+//!
+//! Parse-only checks for `aoc validate`: every day's parser runs on its
+//! input without solving, so a truncated download or a file saved with the
+//! wrong contents shows up in milliseconds instead of as a panic deep inside
+//! a solver. A failed parse is reported with the line at fault, or with
+//! the panic message of a parser that panics instead.
+
+use std::panic;
+
+use crate::registry;
+
+/// Run the parser of `day` of the `year` on `input`, returning why it
+/// failed to parse.
+pub fn validate(day: u8, input: &str) -> Result<(), String> {
+    if input.trim().is_empty() {
+        return Err("empty input".to_string());
+    }
+    let Some(puzzle) = registry::find(crate::year(), day) else {
+        return Err(format!("no day {day}"));
+    };
+    panic::catch_unwind(|| puzzle.parse(input))
+        .map_err(|payload| crate::panic_message(payload.as_ref()))?
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn examples() {
+        for day in 1..=25 {
+            assert_eq!(validate(day, &read_example(day).unwrap()), Ok(()), "{day}");
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            validate(1, "1721\nabc\n"),
+            Err("line 2: invalid number `abc`".to_string())
+        );
+        assert_eq!(validate(9, "\n"), Err("empty input".to_string()));
+        assert_eq!(validate(26, "1"), Err("no day 26".to_string()));
+    }
+}
@@ -0,0 +1,119 @@
+//! Algorithm Variants
+//!
+//! Some days carry more than one implementation of the same puzzle, e.g. a
+//! brute-force search next to a hash-based one. The runner selects one with
+//! `--algo NAME`, and `difftest` runs all of them against the same inputs to
+//! check they agree. The first variant of a day is its `part_one`/`part_two`.
+
+use crate::year2020::{day01, day15, day17};
+use crate::Answer;
+
+pub struct Variant {
+    pub name: &'static str,
+    pub part_one: fn(&str) -> Answer,
+    pub part_two: fn(&str) -> Answer,
+}
+
+impl Variant {
+    pub fn solve(&self, part: u8, input: &str) -> Answer {
+        match part {
+            1 => (self.part_one)(input),
+            _ => (self.part_two)(input),
+        }
+    }
+}
+
+static DAY01: [Variant; 2] = [
+    Variant {
+        name: "brute",
+        part_one: |input| day01::part_one(input).into(),
+        part_two: |input| day01::part_two(input).into(),
+    },
+    Variant {
+        name: "hashset",
+        part_one: |input| day01::part_one_hashset(input).into(),
+        part_two: |input| day01::part_two_hashset(input).into(),
+    },
+];
+
+static DAY15: [Variant; 2] = [
+    Variant {
+        name: "vec",
+        part_one: |input| day15::part_one(input).into(),
+        part_two: |input| day15::part_two(input).into(),
+    },
+    Variant {
+        name: "hashmap",
+        part_one: |input| day15::part_one_hashmap(input).into(),
+        part_two: |input| day15::part_two_hashmap(input).into(),
+    },
+];
+
+static DAY17: [Variant; 2] = [
+    Variant {
+        name: "sparse",
+        part_one: |input| day17::part_one(input).into(),
+        part_two: |input| day17::part_two(input).into(),
+    },
+    Variant {
+        name: "dense",
+        part_one: |input| day17::part_one_dense(input).into(),
+        part_two: |input| day17::part_two_dense(input).into(),
+    },
+];
+
+/// All registered variants of a day; empty for days with a single
+/// implementation.
+pub fn variants(day: u8) -> &'static [Variant] {
+    match day {
+        1 => &DAY01,
+        15 => &DAY15,
+        17 => &DAY17,
+        _ => &[],
+    }
+}
+
+/// The variant of a day called `name`, if any.
+pub fn find(day: u8, name: &str) -> Option<&'static Variant> {
+    variants(day).iter().find(|v| v.name == name)
+}
+
+/// Run every variant of a day on `input` and return the answers of the
+/// first part on which they disagree.
+pub fn divergence(
+    day: u8,
+    parts: &[u8],
+    input: &str,
+) -> Option<(u8, Vec<(&'static str, Answer)>)> {
+    for &part in parts {
+        let answers: Vec<(&str, Answer)> = variants(day)
+            .iter()
+            .map(|v| (v.name, v.solve(part, input)))
+            .collect();
+        if answers.windows(2).any(|w| w[0].1 != w[1].1) {
+            return Some((part, answers));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn registry() {
+        assert_eq!(variants(1)[0].name, "brute");
+        assert!(find(15, "hashmap").is_some());
+        assert!(find(15, "hashset").is_none());
+        assert!(variants(2).is_empty());
+    }
+
+    #[test]
+    fn agree() {
+        assert_eq!(divergence(1, &[1, 2], &read_example(1).unwrap()), None);
+        assert_eq!(divergence(15, &[1], &read_example(15).unwrap()), None);
+        assert_eq!(divergence(17, &[1, 2], &read_example(17).unwrap()), None);
+    }
+}
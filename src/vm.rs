@@ -0,0 +1,196 @@
+//! Handheld Console
+//!
+//! The boot code machine of day 8: a `Console` runs a program of
+//! `Instruction`s, one at a time with `step` or until it stops with `run`,
+//! which tells a program that terminates by running past its last
+//! instruction from one that loops forever, detected the first time an
+//! instruction is about to run again. The program counter and the
+//! accumulator are public, so a program can be patched and run again
+//! after `reset`.
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::error::{number, AocError};
+
+/// An instruction of the boot code with its argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    /// Add the argument to the accumulator.
+    Acc(i32),
+    /// Jump by the argument, relative to this instruction.
+    Jmp(i32),
+    /// Do nothing.
+    Nop(i32),
+}
+
+impl Instruction {
+    /// The instruction with `jmp` and `nop` swapped, none for `acc`.
+    pub fn swapped(self) -> Option<Instruction> {
+        match self {
+            Instruction::Acc(_) => None,
+            Instruction::Jmp(arg) => Some(Instruction::Nop(arg)),
+            Instruction::Nop(arg) => Some(Instruction::Jmp(arg)),
+        }
+    }
+}
+
+/// A line like `jmp -4`.
+impl FromStr for Instruction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, AocError> {
+        let (op, arg) = s.split_once(' ').unwrap_or((s, ""));
+        let op = match op {
+            "acc" => Instruction::Acc,
+            "jmp" => Instruction::Jmp,
+            "nop" => Instruction::Nop,
+            _ => {
+                return Err(AocError::at(
+                    s,
+                    s,
+                    format!("expected `acc`, `jmp` or `nop`, got `{s}`"),
+                ))
+            }
+        };
+        Ok(op(number(s, arg)?))
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Acc(arg) => write!(f, "acc {arg:+}"),
+            Instruction::Jmp(arg) => write!(f, "jmp {arg:+}"),
+            Instruction::Nop(arg) => write!(f, "nop {arg:+}"),
+        }
+    }
+}
+
+/// Why a console stopped running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+    /// It ran past the last instruction.
+    Terminated,
+    /// It was about to run an instruction a second time.
+    Looped,
+    /// It jumped outside the program other than just past its end.
+    OutOfBounds,
+}
+
+/// A handheld game console running boot code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Console {
+    pub program: Vec<Instruction>,
+    /// The index of the next instruction.
+    pub pc: isize,
+    pub acc: i32,
+}
+
+impl Console {
+    pub fn new(program: Vec<Instruction>) -> Self {
+        Console {
+            program,
+            pc: 0,
+            acc: 0,
+        }
+    }
+
+    /// A console running the program of `input`, one instruction a line.
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        let program = input
+            .trim()
+            .lines()
+            .map(|s| s.parse().map_err(|e: AocError| e.within(input, s)))
+            .collect::<Result<_, _>>()?;
+        Ok(Console::new(program))
+    }
+
+    /// Back to the first instruction with an accumulator of 0.
+    pub fn reset(&mut self) {
+        self.pc = 0;
+        self.acc = 0;
+    }
+
+    /// The index of the next instruction, none outside the program.
+    fn index(&self) -> Option<usize> {
+        usize::try_from(self.pc)
+            .ok()
+            .filter(|&pc| pc < self.program.len())
+    }
+
+    /// Run the next instruction and return it, none outside the program.
+    pub fn step(&mut self) -> Option<Instruction> {
+        let instruction = self.program[self.index()?];
+        match instruction {
+            Instruction::Acc(arg) => {
+                self.acc += arg;
+                self.pc += 1;
+            }
+            Instruction::Jmp(arg) => self.pc += arg as isize,
+            Instruction::Nop(_) => self.pc += 1,
+        }
+        Some(instruction)
+    }
+
+    /// Run until the program leaves its instructions or an instruction is
+    /// about to run again, with the accumulator as it is then.
+    pub fn run(&mut self) -> Exit {
+        let mut visited = vec![false; self.program.len()];
+        loop {
+            let Some(pc) = self.index() else {
+                return if self.pc == self.program.len() as isize {
+                    Exit::Terminated
+                } else {
+                    Exit::OutOfBounds
+                };
+            };
+            if visited[pc] {
+                return Exit::Looped;
+            }
+            visited[pc] = true;
+            self.step();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn instructions() {
+        let jmp: Instruction = "jmp -4".parse().unwrap();
+        assert_eq!(jmp, Instruction::Jmp(-4));
+        assert_eq!(jmp.to_string(), "jmp -4");
+        assert_eq!(Instruction::Acc(3).to_string(), "acc +3");
+        assert_eq!(jmp.swapped(), Some(Instruction::Nop(-4)));
+        assert_eq!(Instruction::Acc(3).swapped(), None);
+        assert!("acc one".parse::<Instruction>().is_err());
+    }
+
+    #[test]
+    fn exits() {
+        let input = "acc +2\njmp +2\nacc +5\nacc -1\n";
+        let mut console = Console::parse(input).unwrap();
+        assert_eq!(console.step(), Some(Instruction::Acc(2)));
+        assert_eq!((console.pc, console.acc), (1, 2));
+        assert_eq!(console.run(), Exit::Terminated);
+        assert_eq!((console.pc, console.acc), (4, 1));
+        assert_eq!(console.step(), None);
+
+        console.reset();
+        console.program[1] = Instruction::Jmp(-1);
+        assert_eq!(console.run(), Exit::Looped);
+        assert_eq!(console.acc, 2);
+
+        console.reset();
+        console.program[1] = Instruction::Jmp(-2);
+        assert_eq!(console.run(), Exit::OutOfBounds);
+        assert_eq!(console.pc, -1);
+    }
+}
@@ -0,0 +1,120 @@
+//! WebAssembly Bindings
+//!
+//! With the `wasm` feature the library becomes a module for the browser:
+//! paste an input, get the answer. Build it as a `cdylib`, which the
+//! manifest leaves out so the `no_std` core still builds as a plain rlib,
+//! and generate the JavaScript glue with `wasm-bindgen --target web`.
+//! Solving goes through `registry::Puzzle` on the pasted text alone, so
+//! nothing on this path touches the file system. The days are those of the
+//! default year.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{registry, Answer, AocError, Part};
+
+/// The answer of `part` of `day` for `input`: a `bigint` for a number, a
+/// `string` for text such as day 21's ingredient list. Throws a
+/// `SolveError` otherwise.
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<JsValue, SolveError> {
+    Ok(match answer(day, part, input)? {
+        Answer::Signed(v) => v.into(),
+        Answer::Unsigned(v) => v.into(),
+        Answer::Wide(v) => v.into(),
+        Answer::Text(v) => v.into(),
+    })
+}
+
+/// The title of `day`, e.g. `Report Repair`.
+#[wasm_bindgen]
+pub fn title(day: u8) -> Option<String> {
+    registry::find(registry::DEFAULT_YEAR, day)
+        .map(|puzzle| puzzle.title.to_string())
+}
+
+fn answer(day: u8, part: u8, input: &str) -> Result<Answer, SolveError> {
+    let part = Part::try_from(part).map_err(|message| SolveError {
+        kind: "part",
+        line: None,
+        message,
+    })?;
+    Ok(crate::solve(day, part, input)?)
+}
+
+/// Why `solve` failed.
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    kind: &'static str,
+    line: Option<usize>,
+    message: String,
+}
+
+#[wasm_bindgen]
+impl SolveError {
+    /// `day` or `part` for one out of range, `parse` for a malformed input,
+    /// `no-solution` for an input without an answer.
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> String {
+        self.kind.to_string()
+    }
+
+    /// The line of a malformed input, counted from 1.
+    #[wasm_bindgen(getter)]
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    /// The message as the runner prints it, e.g. `line 3: invalid number`.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        match self.line {
+            Some(line) => format!("line {line}: {}", self.message),
+            None => self.message.clone(),
+        }
+    }
+}
+
+impl From<AocError> for SolveError {
+    fn from(error: AocError) -> Self {
+        let (kind, line, message) = match error {
+            AocError::Parse { line, message } => {
+                ("parse", Some(line), message)
+            }
+            AocError::NoSolution(message) => ("no-solution", None, message),
+            AocError::UnknownPuzzle { .. } => {
+                ("day", None, error.to_string())
+            }
+            error => ("input", None, error.to_string()),
+        };
+        SolveError {
+            kind,
+            line,
+            message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn answers() {
+        let input = read_example(1).unwrap().replace('\n', "\r\n");
+        assert_eq!(answer(1, 1, &input).unwrap().to_string(), "514579");
+        let error = answer(1, 1, "1721\nabc\n").unwrap_err();
+        assert_eq!((error.kind(), error.line()), ("parse".into(), Some(2)));
+        assert_eq!(error.to_js_string(), "line 2: invalid number `abc`");
+        assert_eq!(answer(1, 1, "1\n").unwrap_err().kind(), "no-solution");
+        assert_eq!(answer(26, 1, "").unwrap_err().kind(), "day");
+        assert_eq!(answer(1, 3, "").unwrap_err().kind(), "part");
+        assert_eq!(title(1).as_deref(), Some("Report Repair"));
+    }
+}
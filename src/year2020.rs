@@ -0,0 +1,48 @@
+//! Advent of Code 2020
+//!
+//! The day modules of the 2020 event. Another year gets a module like this
+//! one, `yearNNNN.rs` with its days under `yearNNNN/`, and an entry in
+//! `aoc::YEARS`; the registry, the runner and the input files of the data
+//! directory's `NNNN/` subdirectory then serve it like this one.
+
+use crate::registry;
+
+/// The year of the puzzles registered below.
+pub const YEAR: u16 = 2020;
+
+days! {
+    day01,
+    day02,
+    day03,
+    #[cfg(feature = "std")]
+    day04,
+    day05,
+    day06,
+    #[cfg(feature = "std")]
+    day07,
+    day08,
+    day09,
+    day10,
+    day11,
+    day12,
+    day13,
+    #[cfg(feature = "std")]
+    day14,
+    #[cfg(feature = "std")]
+    day15,
+    day16,
+    #[cfg(feature = "std")]
+    day17,
+    day18,
+    day19,
+    #[cfg(feature = "std")]
+    day20,
+    #[cfg(feature = "std")]
+    day21,
+    #[cfg(feature = "std")]
+    day22,
+    day23,
+    #[cfg(feature = "std")]
+    day24,
+    day25,
+}
@@ -0,0 +1,134 @@
+//! Day 1: Report Repair
+//!
+//! ## Problem Description
+//!
+//! Part 1: Find two numbers in the expense report that sum to 2020 and return their product.
+//! Part 2: Find three numbers in the expense report that sum to 2020 and return their product.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Converts the multiline string input into a vector of integers.
+//!
+//! **Part 1 Strategy**: Uses a brute-force nested loop approach:
+//! - Iterates through all pairs of numbers using two nested loops
+//! - Outer loop: takes each number `a` from the start to second-to-last
+//! - Inner loop: takes each number `b` from current `a` position to end
+//! - Checks if `a + b == 2020`
+//! - Returns `a * b` immediately when found
+//!
+//! **Part 2 Strategy**: Extends the same approach to three numbers:
+//! - Uses three nested loops with similar indexing pattern
+//! - Outer loop: number `a` from start to third-to-last
+//! - Middle loop: number `b` from current `a` position to second-to-last
+//! - Inner loop: number `c` from current `b` position to end
+//! - Checks if `a + b + c == 2020`
+//! - Returns `a * b * c` immediately when found
+//!
+//! **Complexity**: O(n²) for part 1, O(n³) for part 2 where n is the number of entries.
+//! **Hash Set Variant**: `part_one_hashset` and `part_two_hashset` look up the
+//! missing complement in a hash set instead, for O(n) part 1 and O(n²) part 2.
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::collections::FastSet;
+use crate::error::{number, AocError};
+use crate::Solver;
+
+/// Parse the expense report into its entries
+pub fn parse(input: &str) -> Result<Vec<i32>, AocError> {
+    scope!("parse");
+    input.trim().lines().map(|s| number(input, s)).collect()
+}
+
+pub struct Solution;
+
+register!(1, "Report Repair");
+
+impl Solver for Solution {
+    type Parsed<'a> = Vec<i32>;
+    type One = i32;
+    type Two = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(numbers: &Vec<i32>) -> Result<i32, AocError> {
+        let n = numbers.len();
+        for (i, a) in numbers.iter().take(n.saturating_sub(1)).enumerate() {
+            for b in numbers.iter().skip(i) {
+                if a + b == 2020 {
+                    return Ok(a * b);
+                }
+            }
+        }
+        Err(AocError::no_solution("no two entries sum to 2020"))
+    }
+
+    fn part_two(numbers: &Vec<i32>) -> Result<i32, AocError> {
+        let n = numbers.len();
+        for (i, a) in numbers.iter().enumerate().take(n.saturating_sub(2)) {
+            for (j, b) in
+                numbers.iter().enumerate().take(n.saturating_sub(1)).skip(i)
+            {
+                for c in numbers.iter().skip(j) {
+                    if a + b + c == 2020 {
+                        return Ok(a * b * c);
+                    }
+                }
+            }
+        }
+        Err(AocError::no_solution("no three entries sum to 2020"))
+    }
+}
+
+pub fn part_one(input: &str) -> i32 {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(feature = "std")]
+pub fn part_one_hashset(input: &str) -> i32 {
+    let numbers = parse(input).unwrap();
+    let mut seen = FastSet::default();
+    for &a in &numbers {
+        if seen.contains(&(2020 - a)) {
+            return a * (2020 - a);
+        }
+        seen.insert(a);
+    }
+    panic!()
+}
+
+#[cfg(feature = "std")]
+pub fn part_two_hashset(input: &str) -> i32 {
+    let numbers = parse(input).unwrap();
+    for (i, &a) in numbers.iter().enumerate() {
+        let mut seen = FastSet::default();
+        for &b in &numbers[i + 1..] {
+            let c = 2020 - a - b;
+            if seen.contains(&c) {
+                return a * b * c;
+            }
+            seen.insert(b);
+        }
+    }
+    panic!()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn hashset() {
+        let input = read_example(1).unwrap();
+        assert_eq!(part_one_hashset(&input), 514579);
+        assert_eq!(part_two_hashset(&input), 241861950);
+    }
+}
@@ -0,0 +1,131 @@
+//! Day 2: Password Philosophy
+//!
+//! ## Problem Description
+//!
+//! Part 1: Validate passwords based on character count policy - each line contains
+//! a range (min-max), a character, and a password. Count how many passwords
+//! have the character appear between min and max times (inclusive).
+//!
+//! Part 2: Validate passwords based on position policy - each line contains
+//! two positions (1-indexed), a character, and a password. Count how many
+//! passwords have the character appear in exactly one of the two positions.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Parses each line in format "min-max char: password" into
+//! an `Entry` of the policy's `lo`, `hi` and `letter` and the password.
+//!
+//! **Part 1 Strategy**: Character frequency counting
+//! - For each password, count occurrences of the specified character
+//! - Check if count falls within the min-max range
+//! - Count valid passwords using iterator filters
+//!
+//! **Part 2 Strategy**: XOR position checking
+//! - Check if character appears at first position (min-1 for 0-indexing)
+//! - Check if character appears at second position (max-1 for 0-indexing)
+//! - Valid when exactly one position contains the character (XOR logic)
+//! - Count valid passwords using iterator filters
+//!
+//! **Parsing Notes**: Uses split on ['-', ' ', ':'] delimiters and careful indexing
+//! to extract policy components and password from each line.
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::error::{number, AocError};
+use crate::Solver;
+
+/// A line of the database: a password and the policy it was set under
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry<'a> {
+    /// The fewest times `letter` appears, or its first position from 1
+    pub lo: usize,
+    /// The most times `letter` appears, or its second position from 1
+    pub hi: usize,
+    pub letter: char,
+    pub password: &'a str,
+}
+
+/// Parse each line into its policy and password
+pub fn parse(input: &str) -> Result<Vec<Entry<'_>>, AocError> {
+    scope!("parse");
+    crate::trim(input)
+        .lines()
+        .map(|s| {
+            //
+            let parts: Vec<&str> =
+                s.split(['-', ' ', ':']).map(|s| s.trim()).collect();
+            let ch = parts.get(2).and_then(|s| s.chars().next());
+            let (Some(letter), Some(password)) = (ch, parts.get(4)) else {
+                return Err(AocError::at(
+                    input,
+                    s,
+                    format!("expected `lo-hi letter: password`, got `{s}`"),
+                ));
+            };
+            Ok(Entry {
+                lo: number(input, parts[0])?,
+                hi: number(input, parts[1])?,
+                letter,
+                password,
+            })
+        })
+        .collect()
+}
+
+pub struct Solution;
+
+register!(2, "Password Philosophy");
+
+impl Solver for Solution {
+    type Parsed<'a> = Vec<Entry<'a>>;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<Vec<Entry<'_>>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(entries: &Vec<Entry>) -> Result<usize, AocError> {
+        Ok(entries
+            .iter()
+            .filter(|e| {
+                let count = e.password.chars().filter(|&c| c == e.letter);
+                (e.lo..=e.hi).contains(&count.count())
+            })
+            .count())
+    }
+
+    fn part_two(entries: &Vec<Entry>) -> Result<usize, AocError> {
+        Ok(entries
+            .iter()
+            .filter(|e| {
+                let at =
+                    |position: usize| e.password.chars().nth(position - 1);
+                (at(e.lo) == Some(e.letter)) != (at(e.hi) == Some(e.letter))
+            })
+            .count())
+    }
+}
+
+pub fn part_one(input: &str) -> usize {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> usize {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn crlf() {
+        let input = read_example(2).unwrap();
+        let crlf = format!("\u{feff}{}", input.replace('\n', "\r\n"));
+        assert_eq!(part_one(&crlf), part_one(&input));
+        assert_eq!(part_two(&crlf), part_two(&input));
+    }
+}
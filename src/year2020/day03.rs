@@ -10,8 +10,7 @@
 //!
 //! ## Solution Approach
 //!
-//! **Input Parsing**: Converts each line of the terrain map into a vector of characters,
-//! creating a 2D grid representation.
+//! **Input Parsing**: Reads the terrain map into a `Grid<bool>` of trees.
 //!
 //! **Part 1 Strategy**: Single slope traversal
 //! - Start at top-left position (0,0)
@@ -29,17 +28,21 @@
 //! - Uses modulo on x-coordinate to handle infinite horizontal repetition
 //! - Returns tree count for the specified slope pattern
 
-fn parse_input(input: &str) -> Vec<Vec<char>> {
-    input.trim().lines().map(|s| s.chars().collect()).collect()
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::Solver;
+
+/// Parse the map of `.` (open) and `#` (tree)
+pub fn parse(input: &str) -> Result<Grid<bool>, AocError> {
+    scope!("parse");
+    input.parse()
 }
 
-fn slope(grid: &[Vec<char>], dx: usize, dy: usize) -> usize {
-    let h = grid.len();
-    let w = grid[0].len();
+fn slope(grid: &Grid<bool>, dx: usize, dy: usize) -> usize {
     let (mut x, mut y) = (0, 0);
     let mut trees = 0;
-    while y < h {
-        if grid[y][x % w] == '#' {
+    while y < grid.height() {
+        if grid[(x % grid.width(), y)] {
             trees += 1;
         }
         x += dx;
@@ -48,28 +51,35 @@ fn slope(grid: &[Vec<char>], dx: usize, dy: usize) -> usize {
     trees
 }
 
-pub fn part_one(input: &str) -> usize {
-    let grid = parse_input(input);
-    slope(&grid, 3, 1)
-}
+pub struct Solution;
 
-pub fn part_two(input: &str) -> usize {
-    let grid = parse_input(input);
-    [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
-        .into_iter()
-        .map(|(dx, dy)| slope(&grid, dx, dy))
-        .product()
-}
+register!(3, "Toboggan Trajectory");
+
+impl Solver for Solution {
+    type Parsed<'a> = Grid<bool>;
+    type One = usize;
+    type Two = usize;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::read_example;
+    fn parse(input: &str) -> Result<Grid<bool>, AocError> {
+        parse(input)
+    }
 
-    #[test]
-    fn example() {
-        let input = read_example(3);
-        assert_eq!(part_one(&input), 7);
-        assert_eq!(part_two(&input), 336);
+    fn part_one(grid: &Grid<bool>) -> Result<usize, AocError> {
+        Ok(slope(grid, 3, 1))
     }
+
+    fn part_two(grid: &Grid<bool>) -> Result<usize, AocError> {
+        Ok([(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+            .into_iter()
+            .map(|(dx, dy)| slope(grid, dx, dy))
+            .product())
+    }
+}
+
+pub fn part_one(input: &str) -> usize {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> usize {
+    crate::solver::part_two::<Solution>(input)
 }
@@ -29,30 +29,68 @@
 //!
 //! **Validation Logic**: Uses pattern matching for clean validation of each field type.
 
-use std::collections::HashMap;
-
-fn parse_input(input: &str) -> Vec<HashMap<&str, &str>> {
-    input
-        .trim()
-        .split("\n\n")
-        .map(|s| {
-            s.split(['\n', ' '])
-                .map(|s| s.trim())
-                .map(|s| (&s[..3], &s[4..]))
-                .collect()
-        })
-        .collect()
-}
+use crate::collections::FastMap;
+use crate::error::AocError;
+use crate::Solver;
 
 const FIELDS: [&str; 8] =
     ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
 
-fn is_valid_fields(pp: &HashMap<&str, &str>) -> bool {
-    FIELDS.iter().rev().skip(1).all(|k| pp.contains_key(k))
+/// A passport's `key:value` fields
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Passport<'a> {
+    pub fields: FastMap<&'a str, &'a str>,
 }
 
-fn is_valid_values(pp: &HashMap<&str, &str>) -> bool {
-    pp.iter().all(|(k, v)| match *k {
+/// A paragraph of `key:value` fields, separated by spaces or newlines.
+impl<'a> TryFrom<&'a str> for Passport<'a> {
+    type Error = AocError;
+
+    fn try_from(s: &'a str) -> Result<Self, AocError> {
+        let fields = s
+            .split(['\n', ' '])
+            .map(|field| field.trim())
+            .map(|field| {
+                field.split_once(':').ok_or_else(|| {
+                    AocError::at(
+                        s,
+                        field,
+                        format!("expected `key:value`, got `{field}`"),
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Passport { fields })
+    }
+}
+
+impl Passport<'_> {
+    /// Whether every field but `cid` is present.
+    pub fn has_required_fields(&self) -> bool {
+        FIELDS
+            .iter()
+            .rev()
+            .skip(1)
+            .all(|k| self.fields.contains_key(k))
+    }
+
+    /// Whether every field has a valid value.
+    pub fn has_valid_values(&self) -> bool {
+        self.fields.iter().all(|(k, v)| is_valid_value(k, v))
+    }
+}
+
+/// Parse each passport into its `key:value` fields
+pub fn parse(input: &str) -> Result<Vec<Passport<'_>>, AocError> {
+    scope!("parse");
+    crate::paragraphs(input)
+        .into_iter()
+        .map(|s| Passport::try_from(s).map_err(|e| e.within(input, s)))
+        .collect()
+}
+
+fn is_valid_value(k: &str, v: &str) -> bool {
+    match k {
         "byr" => match v.parse::<usize>() {
             Ok(v) => (1920..=2002).contains(&v),
             _ => false,
@@ -83,25 +121,46 @@ fn is_valid_values(pp: &HashMap<&str, &str>) -> bool {
                 && v.chars().skip(1).all(|ch| ch.is_ascii_hexdigit())
         }
         "ecl" => {
-            ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(v)
+            ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&v)
         }
         "pid" => v.len() == 9 && v.chars().all(|ch| ch.is_ascii_digit()),
         "cid" => true,
         _ => false,
-    })
+    }
+}
+
+pub struct Solution;
+
+register!(4, "Passport Processing");
+
+impl Solver for Solution {
+    type Parsed<'a> = Vec<Passport<'a>>;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<Vec<Passport<'_>>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(pps: &Vec<Passport>) -> Result<usize, AocError> {
+        Ok(pps.iter().filter(|pp| pp.has_required_fields()).count())
+    }
+
+    fn part_two(pps: &Vec<Passport>) -> Result<usize, AocError> {
+        Ok(pps
+            .iter()
+            .filter(|pp| pp.has_required_fields())
+            .filter(|pp| pp.has_valid_values())
+            .count())
+    }
 }
 
 pub fn part_one(input: &str) -> usize {
-    let pps = parse_input(input);
-    pps.iter().filter(|pp| is_valid_fields(pp)).count()
+    crate::solver::part_one::<Solution>(input)
 }
 
 pub fn part_two(input: &str) -> usize {
-    let pps = parse_input(input);
-    pps.iter()
-        .filter(|pp| is_valid_fields(pp))
-        .filter(|pp| is_valid_values(pp))
-        .count()
+    crate::solver::part_two::<Solution>(input)
 }
 
 #[cfg(test)]
@@ -110,10 +169,7 @@ mod tests {
     use crate::read_example;
 
     #[test]
-    fn example() {
-        let input = read_example(4);
-        assert_eq!(part_one(&input), 2);
-
+    fn example_part_two() {
         const INPUT: &str = concat!(
             "eyr:1972 cid:100\n",
             "hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926\n",
@@ -144,4 +200,30 @@ mod tests {
         );
         assert_eq!(part_two(INPUT), 4);
     }
+
+    #[test]
+    fn passports() {
+        let passport = Passport::try_from(
+            "hcl:#ae17e1 iyr:2013\neyr:2024\necl:brn pid:760753108 \
+             byr:1931\nhgt:179cm",
+        )
+        .unwrap();
+        assert_eq!(passport.fields["hgt"], "179cm");
+        assert!(
+            passport.has_required_fields() && passport.has_valid_values()
+        );
+        let error = parse("byr:1931\n\niyr:2013\neyr\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4: expected `key:value`, got `eyr`"
+        );
+    }
+
+    #[test]
+    fn crlf() {
+        let input = read_example(4).unwrap();
+        let crlf = format!("\u{feff}{}", input.replace('\n', "\r\n"));
+        assert_eq!(part_one(&crlf), part_one(&input));
+        assert_eq!(part_two(&crlf), part_two(&input));
+    }
 }
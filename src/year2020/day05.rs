@@ -0,0 +1,162 @@
+//! Day 5: Binary Boarding
+//!
+//! ## Problem Description
+//!
+//! Part 1: Find the highest seat ID on a boarding pass using binary space partitioning.
+//! Part 2: Find your seat ID which is missing from the list but has adjacent seats.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Reads each line as a boarding pass string (e.g., "FBFBBFFRLR").
+//!
+//! **Binary Space Partitioning**: The `decode` function uses binary search:
+//! - 'F'/'L' = take lower half (reduce upper bound)
+//! - 'B'/'R' = take upper half (increase lower bound)
+//! - First 7 chars: row (0-127), Last 3 chars: column (0-7)
+//! - Seat ID = row * 8 + column
+//!
+//! **Part 1 Strategy**: Find maximum seat ID
+//! - Decode all boarding passes to seat IDs
+//! - Return the maximum value
+//!
+//! **Part 2 Strategy**: Find missing seat ID
+//! - Decode all boarding passes and sort seat IDs
+//! - Find gap where seat ID+1 doesn't equal next seat ID
+//! - Return the missing seat ID (your seat)
+//!
+//! **Binary Search Logic**: Uses half-interval search to efficiently determine
+//! row/column from boarding pass characters.
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::error::AocError;
+use crate::Solver;
+
+/// Parse the boarding passes, checking each is 7 of F/B then 3 of L/R
+pub fn parse(input: &str) -> Result<Vec<&str>, AocError> {
+    scope!("parse");
+    input
+        .trim()
+        .lines()
+        .map(|s| {
+            let (row, column) = s.split_at_checked(7).unwrap_or((s, ""));
+            if row.bytes().all(|b| b"FB".contains(&b))
+                && column.len() == 3
+                && column.bytes().all(|b| b"LR".contains(&b))
+            {
+                Ok(s)
+            } else {
+                Err(AocError::at(
+                    input,
+                    s,
+                    format!("expected 7 of F/B then 3 of L/R, got `{s}`"),
+                ))
+            }
+        })
+        .collect()
+}
+
+fn decode(s: &str) -> u16 {
+    let (mut a, mut b) = (0, (1 << s.len()) - 1);
+    for ch in s.trim().chars() {
+        #[allow(clippy::manual_div_ceil)]
+        let delta = (b - a + 1) / 2;
+        match ch {
+            'F' | 'L' => b -= delta,
+            'B' | 'R' => a += delta,
+            _ => panic!("unknown char: {ch}"),
+        }
+    }
+    assert_eq!(a, b);
+    a
+}
+
+pub struct Solution;
+
+register!(5, "Binary Boarding");
+
+impl Solver for Solution {
+    type Parsed<'a> = Vec<&'a str>;
+    type One = u16;
+    type Two = u16;
+
+    fn parse(input: &str) -> Result<Vec<&str>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(passes: &Vec<&str>) -> Result<u16, AocError> {
+        passes
+            .iter()
+            .map(|s| (decode(&s[..7]), decode(&s[s.len() - 3..])))
+            .map(|(a, b)| a * 8 + b)
+            .max()
+            .ok_or_else(|| AocError::no_solution("no boarding passes"))
+    }
+
+    fn part_two(passes: &Vec<&str>) -> Result<u16, AocError> {
+        let mut seats = passes
+            .iter()
+            .map(|s| (decode(&s[..7]), decode(&s[s.len() - 3..])))
+            .map(|(a, b)| a * 8 + b)
+            .collect::<Vec<_>>();
+        seats.sort_unstable();
+        seats
+            .windows(2)
+            .find(|it| it[0] + 1 != it[1])
+            .map(|it| it[0] + 1)
+            .ok_or_else(|| AocError::no_solution("no seat is free"))
+    }
+}
+
+pub fn part_one(input: &str) -> u16 {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> u16 {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+    use proptest::prelude::*;
+
+    /// The boarding pass of seat `id`, the inverse of decoding.
+    fn encode(id: u16) -> String {
+        let bit =
+            |i: u16, one, zero| if id >> i & 1 == 1 { one } else { zero };
+        let row = (3..10).rev().map(|i| bit(i, 'B', 'F'));
+        let column = (0..3).rev().map(|i| bit(i, 'R', 'L'));
+        row.chain(column).collect()
+    }
+
+    /// A run of seats in any order with one free seat inside, and that seat.
+    fn occupied() -> impl Strategy<Value = (Vec<u16>, u16)> {
+        (0..1000u16, 2..24u16)
+            .prop_flat_map(|(first, n)| (Just(first), Just(n), 1..n))
+            .prop_flat_map(|(first, n, gap)| {
+                let free = first + gap;
+                let ids: Vec<u16> =
+                    (first..=first + n).filter(|&id| id != free).collect();
+                (Just(ids).prop_shuffle(), Just(free))
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(id in 0..1024u16) {
+            let pass = encode(id);
+            prop_assert_eq!(decode(&pass[..7]), id / 8);
+            prop_assert_eq!(decode(&pass[7..]), id % 8);
+            prop_assert_eq!(part_one(&pass), id);
+        }
+
+        #[test]
+        fn free_seat((ids, free) in occupied()) {
+            let passes: Vec<String> = ids.into_iter().map(encode).collect();
+            prop_assert_eq!(part_two(&passes.join("\n")), free);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Day 6: Custom Customs
+//!
+//! ## Problem Description
+//!
+//! Part 1: Count the total number of unique questions answered "yes" across all groups.
+//! Part 2: Count the total number of questions answered "yes" by everyone in each group.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Splits input by double newlines to separate groups,
+//! then converts each person's answers into byte slices for efficient processing.
+//!
+//! **Part 1 Strategy**: Union of answers per group
+//! - For each group, track which letters (a-z) appear in any person's answers
+//! - Uses boolean vector as a simple set representation
+//! - Counts unique questions per group and sums across all groups
+//!
+//! **Part 2 Strategy**: Intersection of answers per group
+//! - For each group, count how many people answered each question
+//! - Questions are valid only if count equals group size
+//! - Uses integer vector to track frequency of each letter
+//! - Counts questions answered by everyone per group and sums across all groups
+//!
+//! **Efficiency**: Uses byte arithmetic (ch - b'a') for O(1) character indexing,
+//! avoiding string allocations and leveraging contiguous memory access.
+
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use crate::error::AocError;
+use crate::Solver;
+
+/// Parse each group into the answers of its people
+pub fn parse(input: &str) -> Result<Vec<Vec<&[u8]>>, AocError> {
+    scope!("parse");
+    crate::paragraphs(input)
+        .into_iter()
+        .map(|section| {
+            section
+                .trim()
+                .lines()
+                .map(|s| match s.bytes().all(|b| b.is_ascii_lowercase()) {
+                    true => Ok(s.as_bytes()),
+                    false => Err(AocError::at(
+                        input,
+                        s,
+                        format!("expected questions a to z, got `{s}`"),
+                    )),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub struct Solution;
+
+register!(6, "Custom Customs");
+
+impl Solver for Solution {
+    type Parsed<'a> = Vec<Vec<&'a [u8]>>;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vec<&[u8]>>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(groups: &Vec<Vec<&[u8]>>) -> Result<usize, AocError> {
+        Ok(groups
+            .iter()
+            .map(|grid| {
+                let mut m: Vec<bool> = vec![false; 26];
+                grid.iter().for_each(|row| {
+                    row.iter().for_each(|ch| m[(ch - b'a') as usize] = true);
+                });
+                m.iter().filter(|it| **it).count()
+            })
+            .sum())
+    }
+
+    fn part_two(groups: &Vec<Vec<&[u8]>>) -> Result<usize, AocError> {
+        Ok(groups
+            .iter()
+            .map(|grid| {
+                let n = grid.len();
+                let mut m = vec![0; 26];
+                grid.iter().for_each(|row| {
+                    row.iter().for_each(|ch| {
+                        let i = (ch - b'a') as usize;
+                        m[i] += 1;
+                    });
+                });
+                m.into_iter().filter(|it| *it == n).count()
+            })
+            .sum())
+    }
+}
+
+pub fn part_one(input: &str) -> usize {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> usize {
+    crate::solver::part_two::<Solution>(input)
+}
@@ -0,0 +1,154 @@
+//! Day 7: Handy Haversacks
+//!
+//! ## Problem Description
+//!
+//! Part 1: Count how many bag colors can eventually contain at least one "shiny gold" bag.
+//! Part 2: Count how many individual bags are required inside a single "shiny gold" bag.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Creates a directed graph where:
+//! - Nodes are bag colors (e.g., "shiny gold", "dark red")
+//! - Edges represent containment relationships with weights (bag counts)
+//! - Uses nested HashMap: outer map keys are container bags, inner maps store contained bags and their counts
+//!
+//! **Part 1 Strategy**: Reverse traversal (containment check)
+//! - Uses recursive depth-first search to check if any bag can eventually contain "shiny gold"
+//! - For each bag, checks direct containment or recursive containment through any contained bag
+//! - Counts all bags that can reach "shiny gold" (excluding "shiny gold" itself)
+//!
+//! **Part 2 Strategy**: Forward traversal (bag counting)
+//! - Uses recursive depth-first search to count total bags inside "shiny gold"
+//! - For each contained bag: count = quantity * (recursive count of bags inside it + 1)
+//! - Sums all individual bag counts required
+//!
+//! **Algorithm**: Recursive DFS with memoization implicit in function calls handles the tree-like structure efficiently.
+
+use crate::collections::FastMap;
+use crate::error::{number, AocError};
+use crate::Solver;
+
+/// Parse the rules into the bags each color directly contains, by count
+pub fn parse(
+    input: &str,
+) -> Result<FastMap<String, FastMap<String, usize>>, AocError> {
+    scope!("parse");
+    input
+        .trim()
+        .lines()
+        .map(|s| {
+            let Some((name, contents)) =
+                s.split_once("contain").and_then(|(name, contents)| {
+                    Some((name.trim().strip_suffix("bags")?, contents))
+                })
+            else {
+                return Err(AocError::at(
+                    input,
+                    s,
+                    format!("expected `... bags contain ...`, got `{s}`"),
+                ));
+            };
+            let contents = contents
+                .trim()
+                .split([','])
+                .filter_map(|s| {
+                    let v: Vec<&str> = s.split_whitespace().collect();
+                    if v.len() < 4 {
+                        None
+                    } else {
+                        let n = number(input, v[0]);
+                        let name = v[1..3].join(" ");
+                        Some(n.map(|n| (name, n)))
+                    }
+                })
+                .collect::<Result<_, _>>()?;
+            Ok((name.trim().to_string(), contents))
+        })
+        .collect()
+}
+
+fn is_contain_shiny_gold(
+    name: &str,
+    input: &FastMap<String, FastMap<String, usize>>,
+) -> bool {
+    if let Some(bag) = input.get(name) {
+        bag.contains_key("shiny gold")
+            || bag.keys().any(|it| is_contain_shiny_gold(it, input))
+    } else {
+        false
+    }
+}
+
+fn contain_bags(
+    name: &str,
+    input: &FastMap<String, FastMap<String, usize>>,
+) -> Result<usize, AocError> {
+    let contents = input.get(name).ok_or_else(|| {
+        AocError::no_solution(format!("no rule for {name} bags"))
+    })?;
+    contents.iter().try_fold(0, |total, (name, n)| {
+        Ok(total + n * contain_bags(name, input)? + n)
+    })
+}
+
+pub struct Solution;
+
+register!(7, "Handy Haversacks");
+
+impl Solver for Solution {
+    type Parsed<'a> = FastMap<String, FastMap<String, usize>>;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(
+        input: &str,
+    ) -> Result<FastMap<String, FastMap<String, usize>>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(
+        rules: &FastMap<String, FastMap<String, usize>>,
+    ) -> Result<usize, AocError> {
+        Ok(rules
+            .keys()
+            .filter(|name| name != &"shiny gold")
+            .filter(|name| is_contain_shiny_gold(name, rules))
+            .count())
+    }
+
+    fn part_two(
+        rules: &FastMap<String, FastMap<String, usize>>,
+    ) -> Result<usize, AocError> {
+        contain_bags("shiny gold", rules)
+    }
+}
+
+pub fn part_one(input: &str) -> usize {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> usize {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::solve;
+
+    #[test]
+    fn missing_rules() {
+        let input = "light red bags contain 2 shiny gold bags.\n";
+        let error = solve::<Solution>(2, input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no solution: no rule for shiny gold bags"
+        );
+        let input = "shiny gold bags contain 1 dark red bag.\n";
+        let error = solve::<Solution>(2, input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no solution: no rule for dark red bags"
+        );
+    }
+}
@@ -0,0 +1,125 @@
+//! Day 8: Handheld Halting
+//!
+//! ## Problem Description
+//!
+//! Part 1: Find the accumulator value before the program loops infinitely.
+//! Part 2: Fix the program by changing exactly one "jmp" to "nop" or "nop" to "jmp"
+//! so the program terminates normally, then return the accumulator value.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Converts each line into an `Instruction` of the
+//! program of an `aoc::vm::Console`:
+//! - Operations: "acc" (accumulate), "jmp" (jump), "nop" (no operation)
+//! - Values: signed integers for jump offsets or accumulator changes
+//!
+//! **Part 1 Strategy**: Detect infinite loop
+//! - Execute instructions sequentially while tracking visited positions
+//! - Stop when hitting a previously visited instruction
+//! - Return accumulator value at loop detection point
+//!
+//! **Part 2 Strategy**: Brute-force repair
+//! - Identify all "jmp" and "nop" instructions as candidates for modification
+//! - Try changing each candidate one at a time
+//! - Test if modified program terminates successfully
+//! - Return accumulator value when program reaches end
+//!
+//! **Execution Model**: `Console::run` stops with `Exit::Terminated` past
+//! the last instruction or `Exit::Looped` before an instruction runs
+//! twice, leaving the accumulator in the console.
+
+use crate::error::AocError;
+use crate::vm::{Console, Exit};
+use crate::Solver;
+
+/// Parse the boot code into its instructions
+pub fn parse(input: &str) -> Result<Console, AocError> {
+    scope!("parse");
+    Console::parse(input)
+}
+
+/// The console with the one `jmp` or `nop` swapped that makes its program
+/// terminate, reset
+pub fn repair(console: &Console) -> Option<Console> {
+    let mut console = console.clone();
+    for i in 0..console.program.len() {
+        let instruction = console.program[i];
+        let Some(swapped) = instruction.swapped() else {
+            continue;
+        };
+        console.program[i] = swapped;
+        console.reset();
+        if console.run() == Exit::Terminated {
+            console.reset();
+            return Some(console);
+        }
+        console.program[i] = instruction;
+    }
+    None
+}
+
+pub struct Solution;
+
+register!(8, "Handheld Halting");
+
+impl Solver for Solution {
+    type Parsed<'a> = Console;
+    type One = i32;
+    type Two = i32;
+
+    fn parse(input: &str) -> Result<Console, AocError> {
+        parse(input)
+    }
+
+    fn part_one(console: &Console) -> Result<i32, AocError> {
+        let mut console = console.clone();
+        match console.run() {
+            Exit::Looped => Ok(console.acc),
+            _ => Err(AocError::no_solution("the program terminates")),
+        }
+    }
+
+    fn part_two(console: &Console) -> Result<i32, AocError> {
+        let mut console = repair(console).ok_or_else(|| {
+            AocError::no_solution("no single fix terminates the program")
+        })?;
+        console.run();
+        Ok(console.acc)
+    }
+}
+
+pub fn part_one(input: &str) -> i32 {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use crate::vm::Instruction;
+
+    #[test]
+    fn example_repair() {
+        let input = "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\n\
+                     acc +1\njmp -4\nacc +6\n";
+        let console = parse(input).unwrap();
+        assert_eq!(Solution::part_one(&console).unwrap(), 5);
+        let mut fixed = repair(&console).unwrap();
+        assert_eq!(fixed.program[7], Instruction::Nop(-4));
+        assert_eq!(fixed.run(), Exit::Terminated);
+        assert_eq!(fixed.acc, 8);
+    }
+
+    #[test]
+    fn instructions() {
+        let error = parse("nop +0\nacc +1\nhlt +0\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3: expected `acc`, `jmp` or `nop`, got `hlt +0`"
+        );
+    }
+}
@@ -0,0 +1,278 @@
+//! Day 9: Encoding Error
+//!
+//! ## Problem Description
+//!
+//! Part 1: Find the first number that is NOT the sum of any two of the previous N numbers.
+//! Part 2: Find a contiguous range that sums to the invalid number, then return
+//! the sum of the smallest and largest numbers in that range.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Converts input lines into a vector of unsigned 64-bit integers.
+//!
+//! **Part 1 Strategy**: XMAS cipher validation
+//! - Uses sliding window of previous N numbers, chosen by a `PreambleStrategy`:
+//!   5 for the example, 25 for real inputs, any explicit N, or (by default)
+//!   the smallest N for which exactly one number is invalid
+//! - For each number, checks if it can be expressed as sum of any two distinct numbers
+//! - Returns the first number that fails this validation
+//!
+//! **Part 2 Strategy**: Contiguous sum search
+//! - Uses sliding window approach to find contiguous range summing to invalid number
+//! - Expands window when sum is too small, shrinks when sum is too large
+//! - Once found, returns sum of min and max values in the contiguous range
+//!
+//! **Window Algorithm**: Efficient O(n) sliding window technique to find contiguous sum.
+//! `find_invalid_sum_brute` tries every range instead, as a reference for
+//! `aoc stress 9`.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use crate::error::{number, AocError};
+use crate::Solver;
+
+/// How many preceding numbers each number is checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PreambleStrategy {
+    /// A preamble of exactly this many numbers.
+    Fixed(usize),
+    /// The 5-number preamble of the example.
+    Example,
+    /// The 25-number preamble of real inputs.
+    Real,
+    /// The smallest preamble that leaves exactly one invalid number.
+    #[default]
+    Auto,
+}
+
+impl PreambleStrategy {
+    /// The preamble length to use for `nums`.
+    pub fn len(self, nums: &[u64]) -> Result<usize, AocError> {
+        match self {
+            PreambleStrategy::Fixed(len) => Ok(len),
+            PreambleStrategy::Example => Ok(5),
+            PreambleStrategy::Real => Ok(25),
+            PreambleStrategy::Auto => (1..nums.len())
+                .find(|&len| invalid_numbers(nums, len).take(2).count() == 1)
+                .ok_or_else(|| {
+                    AocError::no_solution(
+                        "no preamble length leaves exactly one invalid number",
+                    )
+                }),
+        }
+    }
+}
+
+/// `example`, `real`, `auto` or an explicit length, as given to
+/// `--preamble`.
+impl FromStr for PreambleStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "example" => Ok(PreambleStrategy::Example),
+            "real" => Ok(PreambleStrategy::Real),
+            "auto" => Ok(PreambleStrategy::Auto),
+            _ => match s.parse() {
+                Ok(len) if len > 0 => Ok(PreambleStrategy::Fixed(len)),
+                _ => Err(format!("invalid preamble: {s}")),
+            },
+        }
+    }
+}
+
+/// Parse the XMAS data into its numbers
+pub fn parse(input: &str) -> Result<Vec<u64>, AocError> {
+    scope!("parse");
+    input.trim().lines().map(|s| number(input, s)).collect()
+}
+
+fn is_valid(nums: &[u64], num: u64) -> bool {
+    for a in nums {
+        for b in nums {
+            if a != b && a + b == num {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn invalid_numbers(
+    nums: &[u64],
+    len: usize,
+) -> impl Iterator<Item = u64> + '_ {
+    (len..nums.len())
+        .filter(move |&i| !is_valid(&nums[i - len..i], nums[i]))
+        .map(|i| nums[i])
+}
+
+pub fn find_invalid(nums: &[u64], len: usize) -> Result<u64, AocError> {
+    invalid_numbers(nums, len).next().ok_or_else(|| {
+        AocError::no_solution(format!(
+            "every number is the sum of two of the {len} before it"
+        ))
+    })
+}
+
+fn no_range(invalid: u64) -> AocError {
+    AocError::no_solution(format!("no contiguous range sums to {invalid}"))
+}
+
+pub fn find_invalid_sum(nums: &[u64], len: usize) -> Result<u64, AocError> {
+    let invalid = find_invalid(nums, len)?;
+    // with a preamble of 0 even a lone number is invalid
+    if nums.len() < 2 {
+        return Err(no_range(invalid));
+    }
+    let mut a = 0;
+    let mut b = 1;
+    let mut sum = nums[a] + nums[b];
+    loop {
+        // a range is at least two numbers, so grow it rather than shrink
+        // it below that even when the sum is already too large
+        if sum < invalid || (sum > invalid && b == a + 1) {
+            b += 1;
+            if b == nums.len() {
+                return Err(no_range(invalid));
+            }
+            sum += nums[b];
+            continue;
+        }
+        if sum > invalid {
+            sum -= nums[a];
+            a += 1;
+            continue;
+        }
+        break;
+    }
+    let slice = &nums[a..b + 1];
+    Ok(slice.iter().min().unwrap() + slice.iter().max().unwrap())
+}
+
+/// Like `find_invalid_sum`, but trying every contiguous range of at least
+/// two numbers, leftmost first.
+pub fn find_invalid_sum_brute(
+    nums: &[u64],
+    len: usize,
+) -> Result<u64, AocError> {
+    let invalid = find_invalid(nums, len)?;
+    for a in 0..nums.len() {
+        let mut sum = nums[a];
+        for b in a + 1..nums.len() {
+            sum += nums[b];
+            if sum == invalid {
+                let slice = &nums[a..b + 1];
+                return Ok(
+                    slice.iter().min().unwrap() + slice.iter().max().unwrap()
+                );
+            }
+            if sum > invalid {
+                break;
+            }
+        }
+    }
+    Err(no_range(invalid))
+}
+
+pub struct Solution;
+
+register!(9, "Encoding Error");
+
+impl Solver for Solution {
+    type Parsed<'a> = Vec<u64>;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<Vec<u64>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(nums: &Vec<u64>) -> Result<u64, AocError> {
+        find_invalid(nums, PreambleStrategy::default().len(nums)?)
+    }
+
+    fn part_two(nums: &Vec<u64>) -> Result<u64, AocError> {
+        find_invalid_sum(nums, PreambleStrategy::default().len(nums)?)
+    }
+}
+
+pub fn part_one(input: &str) -> u64 {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> u64 {
+    crate::solver::part_two::<Solution>(input)
+}
+
+pub fn part_one_with(
+    input: &str,
+    preamble: PreambleStrategy,
+) -> Result<u64, AocError> {
+    let nums = parse(input)?;
+    find_invalid(&nums, preamble.len(&nums)?)
+}
+
+pub fn part_two_with(
+    input: &str,
+    preamble: PreambleStrategy,
+) -> Result<u64, AocError> {
+    let nums = parse(input)?;
+    find_invalid_sum(&nums, preamble.len(&nums)?)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::read_example;
+    use crate::solver::solve;
+
+    #[test]
+    fn example() {
+        let input = read_example(9).unwrap();
+        let numbers = parse(&input).unwrap();
+        assert_eq!(find_invalid(&numbers, 5).unwrap(), 127);
+        assert_eq!(find_invalid_sum(&numbers, 5).unwrap(), 62);
+        assert_eq!(find_invalid_sum_brute(&numbers, 5).unwrap(), 62);
+    }
+
+    #[test]
+    fn large_first_pair() {
+        let numbers = [40, 30, 1, 31, 32, 33, 62];
+        assert_eq!(find_invalid(&numbers, 3).unwrap(), 62);
+        assert_eq!(find_invalid_sum(&numbers, 3).unwrap(), 32);
+        assert_eq!(find_invalid_sum_brute(&numbers, 3).unwrap(), 32);
+    }
+
+    #[test]
+    fn no_solution() {
+        let error = solve::<Solution>(2, "1\n2\n3\n4").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no solution: no contiguous range sums to 4"
+        );
+        let numbers = [1, 2, 3, 4];
+        assert!(find_invalid_sum_brute(&numbers, 2).is_err());
+        assert!(find_invalid(&numbers, 3).is_err());
+        assert!(find_invalid_sum(&[7], 0).is_err());
+        let error = PreambleStrategy::Auto.len(&[1, 2, 3]).unwrap_err();
+        assert!(matches!(error, AocError::NoSolution(_)));
+    }
+
+    #[test]
+    fn preamble() {
+        let numbers = parse(&read_example(9).unwrap()).unwrap();
+        assert_eq!(PreambleStrategy::Auto.len(&numbers).unwrap(), 5);
+        assert_eq!(PreambleStrategy::Real.len(&numbers).unwrap(), 25);
+        assert_eq!("7".parse(), Ok(PreambleStrategy::Fixed(7)));
+        assert_eq!("example".parse(), Ok(PreambleStrategy::Example));
+        assert!("0".parse::<PreambleStrategy>().is_err());
+
+        let input = read_example(9).unwrap();
+        let answer = part_one_with(&input, PreambleStrategy::Fixed(7));
+        assert_eq!(answer.unwrap(), 127);
+    }
+}
@@ -0,0 +1,126 @@
+//! Day 10: Adapter Array
+//!
+//! ## Problem Description
+//!
+//! Part 1: Find the product of 1-jolt differences and 3-jolt differences in the adapter chain.
+//! Part 2: Count the total number of distinct ways to arrange the adapters to connect device.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Converts input lines into a vector of adapter joltage ratings.
+//!
+//! **Part 1 Strategy**: Joltage difference analysis
+//! - Add charging outlet (0 jolts) and built-in adapter (max + 3 jolts)
+//! - Sort all adapters and calculate differences between consecutive adapters
+//! - Count 1-jolt and 3-jolt differences, return their product
+//!
+//! **Part 2 Strategy**: Dynamic programming for arrangement counting
+//! - Uses DP array where dp[i] = number of ways to reach adapter i
+//! - For each adapter, sum ways from previous adapters within 3-jolt range
+//! - Optimized by working backwards and breaking when range exceeds 3 jolts
+//! - Returns total arrangements to reach the final adapter
+//! - Counts grow multiplicatively, so they are `Arrangements` (u128) summed
+//!   with checked arithmetic, failing with the adapter that overflowed
+//!
+//! **Algorithm**: Dynamic programming with sliding window optimization for efficient counting.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use crate::error::{number, AocError};
+use crate::Solver;
+
+/// Parse the joltage ratings of the adapters
+pub fn parse(input: &str) -> Result<Vec<i32>, AocError> {
+    scope!("parse");
+    input.trim().lines().map(|s| number(input, s)).collect()
+}
+
+/// Number of distinct adapter arrangements; grows multiplicatively with the
+/// number of adapters.
+pub type Arrangements = u128;
+
+/// Count the arrangements of `adapters` from the outlet to the device,
+/// failing when the count overflows `Arrangements`.
+pub fn count_arrangements(adapters: &[i32]) -> Result<Arrangements, String> {
+    let mut input = adapters.to_vec();
+    input.push(0);
+    input.sort_unstable();
+    input.push(input.last().unwrap() + 3);
+
+    let n = input.len();
+    let mut dp: Vec<Arrangements> = vec![0; n];
+    dp[0] = 1;
+    for i in 1..n {
+        for j in (0..i).rev() {
+            if input[i] - input[j] <= 3 {
+                dp[i] = dp[i].checked_add(dp[j]).ok_or_else(|| {
+                    format!(
+                        "arrangement count overflows u128 at the {} jolt \
+                         adapter ({} of {})",
+                        input[i],
+                        i,
+                        n - 1
+                    )
+                })?;
+            } else {
+                break;
+            }
+        }
+    }
+    Ok(dp[n - 1])
+}
+
+pub struct Solution;
+
+register!(10, "Adapter Array");
+
+impl Solver for Solution {
+    type Parsed<'a> = Vec<i32>;
+    type One = usize;
+    type Two = Arrangements;
+
+    fn parse(input: &str) -> Result<Vec<i32>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(adapters: &Vec<i32>) -> Result<usize, AocError> {
+        let mut input = adapters.clone();
+        input.push(0);
+        input.sort_unstable();
+        input.push(input.last().unwrap() + 3);
+        let diffs: Vec<i32> = input.windows(2).map(|v| v[1] - v[0]).collect();
+        let c1 = diffs.iter().filter(|&v| *v == 1).count();
+        let c3 = diffs.iter().filter(|&v| *v == 3).count();
+        Ok(c1 * c3)
+    }
+
+    fn part_two(adapters: &Vec<i32>) -> Result<Arrangements, AocError> {
+        count_arrangements(adapters).map_err(AocError::NoSolution)
+    }
+}
+
+pub fn part_one(input: &str) -> usize {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> Arrangements {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn large_counts() {
+        let adapters: Vec<i32> = (1..=100).collect();
+        let count = count_arrangements(&adapters).unwrap();
+        assert!(count > u64::MAX as Arrangements);
+
+        let adapters: Vec<i32> = (1..=200).collect();
+        let error = count_arrangements(&adapters).unwrap_err();
+        assert!(error.starts_with("arrangement count overflows u128"));
+    }
+}
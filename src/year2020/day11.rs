@@ -0,0 +1,190 @@
+//! Day 11: Seating System
+//!
+//! ## Problem Description
+//!
+//! Part 1: Apply seating rules based on adjacent seats until stable, count occupied seats.
+//! Part 2: Apply seating rules based on visible seats in all directions until stable.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Reads the seating layout into a `Grid<char>`
+//! where 'L'=empty seat, '#'=occupied seat, '.'=floor space.
+//!
+//! **Part 1 Strategy**: Adjacent seat rules
+//! - Empty seat ('L') becomes occupied ('#') if no adjacent occupied seats
+//! - Occupied seat ('#') becomes empty ('L') if 4+ adjacent seats occupied
+//! - Adjacent = 8 immediate neighbors (including diagonals)
+//!
+//! **Part 2 Strategy**: Visible seat rules
+//! - Empty seat ('L') becomes occupied ('#') if no visible occupied seats
+//! - Occupied seat ('#') becomes empty ('L') if 5+ visible seats occupied
+//! - Visible = first seat in each of 8 directions (including diagonals)
+//!
+//! **Simulation**: The seats are a `Dense` automaton of `aoc::automaton`:
+//! - The seats in sight of each seat are found once, up front
+//! - Apply rules to all seats simultaneously until no changes occur
+//! - Count total occupied seats in final configuration
+//!
+//! **Direction Handling**: 8-directional checking with ray casting for Part 2.
+
+use alloc::vec::Vec;
+
+use crate::automaton::{Automaton, Dense, Rule};
+use crate::error::AocError;
+use crate::grid::{Grid, NEIGHBORS8};
+use crate::Solver;
+
+/// Parse the seat layout of `.`, `L` and `#`
+pub fn parse(input: &str) -> Result<Grid<char>, AocError> {
+    scope!("parse");
+    Grid::parse(input, |c| matches!(c, '.' | 'L' | '#').then_some(c))
+}
+
+/// The seating rules of a part as an automaton of the seats, live when
+/// occupied.
+struct Seating<'a> {
+    seats: &'a Grid<char>,
+    /// How far a seat sees along each direction: to the next cell in part
+    /// one, to the first seat in part two.
+    reach: usize,
+    /// How many occupied seats in sight empty a seat.
+    threshold: usize,
+}
+
+impl<'a> Seating<'a> {
+    fn new(seats: &'a Grid<char>, part: u8) -> Self {
+        match part {
+            1 => Seating {
+                seats,
+                reach: 1,
+                threshold: 4,
+            },
+            _ => Seating {
+                seats,
+                reach: usize::MAX,
+                threshold: 5,
+            },
+        }
+    }
+
+    /// The seats with whether each is occupied, run by the rules.
+    fn automaton(self) -> Dense<Self> {
+        let seats = self.seats;
+        let cells = seats
+            .iter()
+            .filter(|(_, &seat)| seat != '.')
+            .map(|(position, &seat)| (position, seat == '#'));
+        Dense::new(self, cells)
+    }
+}
+
+impl Rule for Seating<'_> {
+    type Cell = (usize, usize);
+
+    /// The first seat within reach in each of the eight directions.
+    fn neighbors(
+        &self,
+        (x, y): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> {
+        NEIGHBORS8.iter().filter_map(move |(dx, dy)| {
+            let (mut x, mut y) = (x as isize, y as isize);
+            for _ in 0..self.reach {
+                x += dx;
+                y += dy;
+                if *self.seats.get(x, y)? != '.' {
+                    return Some((x as usize, y as usize));
+                }
+            }
+            None
+        })
+    }
+
+    fn next(&self, occupied: bool, occupied_in_sight: usize) -> bool {
+        if occupied {
+            occupied_in_sight < self.threshold
+        } else {
+            occupied_in_sight == 0
+        }
+    }
+}
+
+/// The occupied seats once the rules of `part` settle.
+fn occupied_seats(seats: &Grid<char>, part: u8) -> Result<usize, AocError> {
+    let mut seating = Seating::new(seats, part).automaton();
+    seating.run_until_stable()?;
+    Ok(seating.population())
+}
+
+/// The `(x, y)` of the seats that flip forever under the rules of `part`,
+/// none for a layout that settles.
+pub fn unsettled(seats: &Grid<char>, part: u8) -> Vec<(usize, usize)> {
+    let mut seating = Seating::new(seats, part).automaton();
+    if seating.run_until_stable().is_ok() {
+        return Vec::new();
+    }
+    let before: Vec<bool> = seating.states().map(|(_, live)| live).collect();
+    seating.step();
+    seating
+        .states()
+        .zip(before)
+        .filter(|&((_, now), before)| now != before)
+        .map(|((seat, _), _)| seat)
+        .collect()
+}
+
+pub struct Solution;
+
+register!(11, "Seating System");
+
+impl Solver for Solution {
+    type Parsed<'a> = Grid<char>;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<Grid<char>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(seats: &Grid<char>) -> Result<usize, AocError> {
+        occupied_seats(seats, 1)
+    }
+
+    fn part_two(seats: &Grid<char>) -> Result<usize, AocError> {
+        occupied_seats(seats, 2)
+    }
+}
+
+pub fn part_one(input: &str) -> usize {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> usize {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn wide_rows() {
+        // wider than the coordinates of an `i8` reach
+        let input = "L".repeat(200) + "\n" + &".".repeat(200) + "\n";
+        assert_eq!(part_one(&input), 200);
+        assert_eq!(part_two(&input), 200);
+    }
+
+    #[test]
+    fn never_settles() {
+        // every seat is taken and left again on alternate rounds
+        let seats = parse(".LL.\nLLLL\nLLLL\n.LL.\n").unwrap();
+        assert!(matches!(
+            Solution::part_one(&seats),
+            Err(AocError::NoSolution(_))
+        ));
+        assert_eq!(unsettled(&seats, 1).len(), 12);
+        let seats = parse(&read_example(11).unwrap()).unwrap();
+        assert!(unsettled(&seats, 1).is_empty());
+    }
+}
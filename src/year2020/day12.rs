@@ -0,0 +1,207 @@
+//! Day 12: Rain Risk
+//!
+//! ## Problem Description
+//!
+//! Part 1: Navigate a ship using directional instructions and calculate Manhattan distance from start.
+//! Part 2: Navigate using waypoint-based movement and calculate Manhattan distance from start.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Converts each line into an `Instruction` where:
+//! - Actions: N, S, E, W (move), L, R (turn), F (forward)
+//! - Values: distances or angles (90, 180, 270 degrees for turns)
+//!
+//! **Part 1 Strategy**: Direct navigation
+//! - Ship moves in cardinal directions or forward in current facing direction
+//! - Uses 4-direction array: [East, South, West, North] indexed by direction counter
+//! - Tracks position (x, y) and facing direction (0-3 representing ESWN)
+//!
+//! **Part 2 Strategy**: Waypoint navigation
+//! - Ship moves toward waypoint, waypoint moves relative to ship
+//! - Waypoint starts 10 units East, 1 unit North of ship
+//! - Rotation: uses coordinate transformation for 90-degree turns
+//! - Forward movement: moves ship toward waypoint multiple times
+//!
+//! **Coordinate System**: Uses standard grid with East=+x, North=-y for simplicity.
+
+use alloc::format;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use crate::error::{number, AocError};
+use crate::Solver;
+
+/// What a navigation instruction does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    North,
+    South,
+    East,
+    West,
+    Left,
+    Right,
+    Forward,
+}
+
+/// One of `NSEWLRF`.
+impl FromStr for Action {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, AocError> {
+        Ok(match s {
+            "N" => Action::North,
+            "S" => Action::South,
+            "E" => Action::East,
+            "W" => Action::West,
+            "L" => Action::Left,
+            "R" => Action::Right,
+            "F" => Action::Forward,
+            _ => {
+                return Err(AocError::at(
+                    s,
+                    s,
+                    format!("expected an action of NSEWLRF, got `{s}`"),
+                ))
+            }
+        })
+    }
+}
+
+/// A navigation instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub action: Action,
+    /// The distance, or the degrees to turn for `L` and `R`
+    pub value: i32,
+}
+
+/// A line like `F10`.
+impl FromStr for Instruction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, AocError> {
+        let (action, value) = s.split_at_checked(1).unwrap_or(("", s));
+        let action = action.parse().map_err(|_| {
+            AocError::at(
+                s,
+                s,
+                format!("expected an action of NSEWLRF, got `{s}`"),
+            )
+        })?;
+        Ok(Instruction {
+            action,
+            value: number(s, value)?,
+        })
+    }
+}
+
+/// Parse the navigation instructions into actions and values
+pub fn parse(input: &str) -> Result<Vec<Instruction>, AocError> {
+    scope!("parse");
+    input
+        .trim()
+        .lines()
+        .map(|s| s.parse().map_err(|e: AocError| e.within(input, s)))
+        .collect()
+}
+
+pub struct Solution;
+
+register!(12, "Rain Risk");
+
+impl Solver for Solution {
+    type Parsed<'a> = Vec<Instruction>;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(instructions: &Vec<Instruction>) -> Result<usize, AocError> {
+        const DIRS: [(i32, i32); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)]; // ESWN
+        let mut x = 0;
+        let mut y = 0;
+        let mut d = 0;
+        for &Instruction { action, value } in instructions {
+            match (action, value) {
+                (Action::Right, v) => d = (d + (v / 90) as usize) % 4,
+                (Action::Left, v) => d = (d + 4 - (v / 90) as usize) % 4,
+                (Action::Forward, v) => {
+                    x += DIRS[d].0 * v;
+                    y += DIRS[d].1 * v;
+                }
+                (Action::East, v) => x += v,
+                (Action::South, v) => y += v,
+                (Action::West, v) => x -= v,
+                (Action::North, v) => y -= v,
+            }
+        }
+        Ok((x.abs() + y.abs()) as usize)
+    }
+
+    fn part_two(instructions: &Vec<Instruction>) -> Result<usize, AocError> {
+        let mut x = 0;
+        let mut y = 0;
+        let mut wpx = 10;
+        let mut wpy = -1;
+        for &Instruction { action, value } in instructions {
+            match (action, value) {
+                (Action::Right, v) => {
+                    for _ in 0..(v / 90 % 4) {
+                        let (dx, dy) = (x - wpx, y - wpy);
+                        wpx = x + dy;
+                        wpy = y - dx;
+                    }
+                }
+                (Action::Left, v) => {
+                    for _ in 0..(v / 90 % 4) {
+                        let (dx, dy) = (x - wpx, y - wpy);
+                        wpx = x - dy;
+                        wpy = y + dx;
+                    }
+                }
+                (Action::Forward, v) => {
+                    let (dx, dy) = (wpx - x, wpy - y);
+                    x += dx * v;
+                    y += dy * v;
+                    wpx = x + dx;
+                    wpy = y + dy;
+                }
+                (Action::East, v) => wpx += v,
+                (Action::South, v) => wpy += v,
+                (Action::West, v) => wpx -= v,
+                (Action::North, v) => wpy -= v,
+            }
+        }
+        Ok((x.abs() + y.abs()) as usize)
+    }
+}
+
+pub fn part_one(input: &str) -> usize {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> usize {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn instructions() {
+        let instruction: Instruction = "F10".parse().unwrap();
+        assert_eq!(instruction.action, Action::Forward);
+        assert_eq!(instruction.value, 10);
+        assert_eq!("R".parse::<Action>().unwrap(), Action::Right);
+        let error = parse("F10\nX3\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected an action of NSEWLRF, got `X3`"
+        );
+        assert!("N".parse::<Instruction>().is_err());
+    }
+}
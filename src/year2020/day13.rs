@@ -0,0 +1,236 @@
+//! Day 13: Shuttle Search
+//!
+//! ## Problem Description
+//!
+//! Part 1: Find the earliest bus you can take to the airport and calculate the product
+//! of its ID and the minutes you need to wait.
+//!
+//! Part 2: Find the earliest timestamp where each bus departs at a specific offset
+//! from the timestamp (bus at index i departs at timestamp + i).
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Splits input into:
+//! - Earliest departure time (first line)
+//! - List of bus IDs with 'x' values replaced by 0 (second line, comma-separated)
+//!
+//! **Part 1 Strategy**: Modular arithmetic
+//! - For each bus ID, calculate wait time: (ID - (earliest % ID)) % ID
+//! - Find bus with minimum wait time
+//! - Return bus ID × wait time
+//!
+//! **Part 2 Strategy**: Chinese Remainder Theorem from `aoc::math`
+//! - Each bus adds the congruence t ≡ -i (mod id) to the system so far
+//! - `Congruence::and` merges two with a modular inverse, so ids that are
+//!   not coprime work too, and contradictory ones fail instead of looping
+//! - The residue of the whole system is the earliest timestamp
+//!
+//! **Mathematical Insight**: Solves t ≡ -i (mod id) for each bus at position i.
+//!
+//! Both answers are `u128`: the product of a few dozen large bus ids exceeds
+//! `u64`, and part two fails rather than wraps past `u128`.
+
+use alloc::vec::Vec;
+
+use crate::error::{number, AocError};
+use crate::math::{Congruence, CrtError};
+use crate::Solver;
+
+/// The notes on the shuttles
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notes {
+    /// The earliest timestamp to depart at
+    pub earliest_departure: u64,
+    /// The bus ids in service, with 0 for an `x`
+    pub bus_ids: Vec<u64>,
+}
+
+/// Parse the earliest departure and the bus ids, with 0 for an `x`
+pub fn parse(input: &str) -> Result<Notes, AocError> {
+    scope!("parse");
+    let lines: Vec<&str> = input.trim().lines().collect();
+    let [timestamp, buses] = lines[..] else {
+        return Err(AocError::at(
+            input,
+            input.trim(),
+            "expected a timestamp and a line of bus ids",
+        ));
+    };
+    Ok(Notes {
+        earliest_departure: number(input, timestamp)?,
+        bus_ids: buses.split(',').map(|s| s.parse().unwrap_or(0)).collect(),
+    })
+}
+
+/// The id of the earliest bus to depart at or after `earliest_departure`
+/// times the minutes to wait for it
+pub const fn earliest_bus(earliest_departure: u64, bus_ids: &[u64]) -> u128 {
+    let mut min_wait = u64::MAX;
+    let mut min_id = 0;
+    let mut i = 0;
+    while i < bus_ids.len() {
+        let id = bus_ids[i];
+        i += 1;
+        if id == 0 {
+            continue;
+        }
+        let wait = id - earliest_departure % id;
+        if wait < min_wait {
+            min_wait = wait;
+            min_id = id;
+        }
+    }
+    min_id as u128 * min_wait as u128
+}
+
+/// The earliest timestamp at which each bus departs as many minutes later
+/// as its position in `bus_ids`, by the Chinese remainder theorem
+pub const fn earliest_timestamp(bus_ids: &[u64]) -> Result<u128, CrtError> {
+    let mut system = Congruence::ANY;
+    let mut i = 0;
+    while i < bus_ids.len() {
+        let id = bus_ids[i] as u128;
+        if id != 0 {
+            // t + i ≡ 0 (mod id)
+            let bus = Congruence {
+                residue: (id - i as u128 % id) % id,
+                modulus: Some(id),
+            };
+            system = match system.and(bus) {
+                Ok(system) => system,
+                Err(e) => return Err(e),
+            };
+        }
+        i += 1;
+    }
+    Ok(system.residue)
+}
+
+pub struct Solution;
+
+register!(13, "Shuttle Search");
+
+impl Solver for Solution {
+    type Parsed<'a> = Notes;
+    type One = u128;
+    type Two = u128;
+
+    fn parse(input: &str) -> Result<Notes, AocError> {
+        parse(input)
+    }
+
+    fn part_one(notes: &Notes) -> Result<u128, AocError> {
+        Ok(earliest_bus(notes.earliest_departure, &notes.bus_ids))
+    }
+
+    fn part_two(notes: &Notes) -> Result<u128, AocError> {
+        earliest_timestamp(&notes.bus_ids).map_err(|e| {
+            AocError::no_solution(match e {
+                CrtError::Inconsistent => "no timestamp fits every bus",
+                CrtError::Overflow => "the earliest timestamp is past u128",
+            })
+        })
+    }
+}
+
+pub fn part_one(input: &str) -> u128 {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> u128 {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use alloc::string::{String, ToString};
+    use proptest::prelude::*;
+
+    /// Bus ids as puzzle inputs have them: distinct primes, so pairwise
+    /// coprime, in any order with `x`s, here 0s, between them.
+    fn schedule() -> impl Strategy<Value = Vec<u64>> {
+        const PRIMES: [u64; 15] =
+            [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+        prop::sample::subsequence(PRIMES.to_vec(), 1..8)
+            .prop_flat_map(|ids| {
+                let gaps = prop::collection::vec(0..4usize, ids.len());
+                (Just(ids).prop_shuffle(), gaps)
+            })
+            .prop_map(|(ids, gaps)| {
+                ids.into_iter()
+                    .zip(gaps)
+                    .flat_map(|(id, gap)| {
+                        core::iter::repeat_n(0, gap).chain([id])
+                    })
+                    .collect()
+            })
+    }
+
+    /// The bus ids of the primes below `limit`, largest first.
+    fn primes(limit: u64) -> String {
+        let ids: Vec<String> = (2..limit)
+            .rev()
+            .filter(|&n| (2..n).all(|d| n % d != 0))
+            .map(|n| n.to_string())
+            .collect();
+        format!("939\n{}", ids.join(","))
+    }
+
+    #[test]
+    fn past_u64() {
+        // their product is about 2^83
+        let input = primes(70);
+        let timestamp = part_two(&input);
+        assert!(timestamp > u64::MAX as u128);
+        let ids = input.lines().nth(1).unwrap().split(',');
+        for (i, id) in ids.enumerate() {
+            let id: u128 = id.parse().unwrap();
+            assert_eq!((timestamp + i as u128) % id, 0);
+        }
+        // and this one about 2^183
+        let error = crate::solver::solve::<Solution>(2, &primes(140));
+        assert!(matches!(error, Err(AocError::NoSolution(_))));
+    }
+
+    #[test]
+    fn shared_factors() {
+        // t ≡ 0 (mod 4) and t + 2 ≡ 0 (mod 6)
+        assert_eq!(part_two("939\n4,x,6"), 4);
+        let notes = parse("939\n2,4").unwrap();
+        let error = Solution::part_two(&notes).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no solution: no timestamp fits every bus"
+        );
+    }
+
+    #[test]
+    fn example_others() {
+        assert_eq!(part_two("939\n17,x,13,19"), 3417);
+        assert_eq!(part_two("939\n67,7,59,61"), 754018);
+        assert_eq!(part_two("939\n67,x,7,59,61"), 779210);
+        assert_eq!(part_two("939\n67,7,x,59,61"), 1261476);
+        assert_eq!(part_two("939\n1789,37,47,1889"), 1202161486);
+    }
+
+    // the example, evaluated by the compiler
+    const EXAMPLE: [u64; 8] = [7, 13, 0, 0, 59, 0, 31, 19];
+    const _: () = assert!(earliest_bus(939, &EXAMPLE) == 295);
+    const _: () =
+        assert!(matches!(earliest_timestamp(&EXAMPLE), Ok(1068781)));
+
+    proptest! {
+        #[test]
+        fn satisfies_every_congruence(ids in schedule()) {
+            let timestamp = earliest_timestamp(&ids).unwrap();
+            let buses = || ids.iter().enumerate().filter(|(_, &id)| id != 0);
+            for (i, &id) in buses() {
+                prop_assert_eq!((timestamp + i as u128) % id as u128, 0);
+            }
+            // the earliest: the solutions repeat every product of the ids
+            prop_assert!(timestamp < buses().map(|(_, &id)| id as u128).product());
+        }
+    }
+}
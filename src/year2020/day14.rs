@@ -0,0 +1,191 @@
+//! Day 14: Docking Data
+//!
+//! ## Problem Description
+//!
+//! Part 1: Apply bitmask operations to memory values before storing them.
+//! Part 2: Apply bitmask operations to memory addresses, with floating bits creating
+//! multiple address variants.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Splits input into sections by "mask = " delimiter:
+//! - Each section starts with a bitmask (36 characters of 0, 1, X)
+//! - Followed by memory assignments in format "mem[address] = value"
+//!
+//! **Part 1 Strategy**: Value masking
+//! - Parse bitmask into two bitmasks: bm0 (for 0s) and bm1 (for 1s)
+//! - Apply masks: (value | bm1) & !bm0
+//! - bm1 forces 1s, bm0 forces 0s, X bits remain unchanged
+//!
+//! **Part 2 Strategy**: Address masking with floating bits
+//! - Parse bitmask to identify: fixed 1s (bm1), fixed 0s (bm0), and floating bits (X)
+//! - For each memory assignment, generate all possible addresses by setting floating bits
+//! - Uses recursive generation to handle all 2^n combinations for n floating bits
+//! - Stores value in all generated addresses
+//!
+//! **Bit Manipulation**: Uses bitwise operations for efficient mask application and
+//! recursive address generation for floating bits.
+
+use crate::collections::FastMap;
+use crate::error::{number, AocError};
+use crate::Solver;
+
+/// A bitmask: the bits it forces to 0 and those it forces to 1, the
+/// others being `X`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mask {
+    pub zeros: u64,
+    pub ones: u64,
+}
+
+/// A `mem[address] = value` write
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Write {
+    pub address: u64,
+    pub value: u64,
+}
+
+/// A mask and the writes made under it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    pub mask: Mask,
+    pub writes: Vec<Write>,
+}
+
+/// The initialization program of the docking computer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DockingProgram {
+    pub sections: Vec<Section>,
+}
+
+fn parse_mask(input: &str, s: &str) -> Result<Mask, AocError> {
+    let mut mask = Mask { zeros: 0, ones: 0 };
+    for c in s.chars() {
+        mask.zeros <<= 1;
+        mask.ones <<= 1;
+        match c {
+            '0' => mask.zeros |= 1,
+            '1' => mask.ones |= 1,
+            'X' => {}
+            _ => {
+                return Err(AocError::at(
+                    input,
+                    s,
+                    format!("invalid mask `{s}`"),
+                ))
+            }
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_write(input: &str, s: &str) -> Result<Write, AocError> {
+    let expected = || {
+        AocError::at(input, s, format!("expected `mem[a] = v`, got `{s}`"))
+    };
+    let (address, value) = s
+        .strip_prefix("mem[")
+        .and_then(|s| s.split_once("] = "))
+        .ok_or_else(expected)?;
+    Ok(Write {
+        address: number(input, address)?,
+        value: number(input, value)?,
+    })
+}
+
+/// Parse each mask section into its mask followed by its writes
+pub fn parse(input: &str) -> Result<DockingProgram, AocError> {
+    scope!("parse");
+    let sections = input
+        .split("mask = ")
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|section| {
+            let mut lines = section.lines();
+            let mask = parse_mask(input, lines.next().unwrap_or_default())?;
+            let writes = lines
+                .map(|s| parse_write(input, s))
+                .collect::<Result<_, _>>()?;
+            Ok(Section { mask, writes })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(DockingProgram { sections })
+}
+
+pub struct Solution;
+
+register!(14, "Docking Data");
+
+impl Solver for Solution {
+    type Parsed<'a> = DockingProgram;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<DockingProgram, AocError> {
+        parse(input)
+    }
+
+    fn part_one(program: &DockingProgram) -> Result<u64, AocError> {
+        let mut memory = FastMap::<u64, u64>::default();
+        for section in &program.sections {
+            let Mask { zeros, ones } = section.mask;
+            for &Write { address, value } in &section.writes {
+                memory.insert(address, (value | ones) & !zeros);
+            }
+        }
+        Ok(memory.values().sum())
+    }
+
+    fn part_two(program: &DockingProgram) -> Result<u64, AocError> {
+        let mut memory = FastMap::<u64, u64>::default();
+        for section in &program.sections {
+            let Mask { zeros, ones } = section.mask;
+            let bmx = !zeros & !ones & 0b111111111111111111111111111111111111;
+            let bits: Vec<u8> =
+                (0..36).filter(|i| bmx & (1 << i) != 0).collect();
+            fn setbmx(
+                memory: &mut FastMap<u64, u64>,
+                address: u64,
+                value: u64,
+                bits: &[u8],
+            ) {
+                if let Some(shift) = bits.first() {
+                    let mask = 1 << shift;
+                    for addr in [address & !mask, address | mask] {
+                        memory.insert(addr, value);
+                        setbmx(memory, addr, value, &bits[1..]);
+                    }
+                }
+            }
+            for &Write { address, value } in &section.writes {
+                setbmx(&mut memory, address | ones & !bmx, value, &bits);
+            }
+        }
+        Ok(memory.values().sum())
+    }
+}
+
+pub fn part_one(input: &str) -> u64 {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> u64 {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disjoint_writes() {
+        // the second example with no address written twice
+        let input = concat!(
+            "mask = 000000000000000000000000000000X1001X\n",
+            "mem[42] = 100\n",
+            "mask = 00000000000000000000000000000000X0XX\n",
+            "mem[30] = 1\n"
+        );
+        assert_eq!(part_two(input), 8 + 100 * 4);
+    }
+}
@@ -26,16 +26,27 @@
 //!
 //! **Performance**: Uses pre-allocated vector for near O(1) lookups, avoiding
 //! HashMap overhead for better cache locality and performance.
+//!
+//! **HashMap Variant**: `part_one_hashmap` and `part_two_hashmap` keep only
+//! the last turn of each spoken number in a HashMap, trading speed for
+//! memory proportional to the distinct numbers spoken.
+
+use crate::cancel::{self, CHECK_EVERY};
+use crate::collections::FastMap;
+use crate::error::{number, AocError};
+use crate::Solver;
 
-fn parse_input(input: &str) -> Vec<usize> {
-    input
-        .trim()
-        .split(',')
-        .map(|s| s.parse().unwrap())
-        .collect()
+/// Parse the starting numbers
+pub fn parse(input: &str) -> Result<Vec<usize>, AocError> {
+    scope!("parse");
+    input.trim().split(',').map(|s| number(input, s)).collect()
 }
 
-fn target_number(numbers: Vec<usize>, target: usize) -> usize {
+/// The number spoken on turn `target`.
+pub fn target_number(
+    numbers: Vec<usize>,
+    target: usize,
+) -> Result<usize, AocError> {
     let n = numbers.len();
     // Use a Vec instead of HashMap for better performance
     // Since we're dealing with the last spoken number -> (turn last spoken, current turn)
@@ -48,6 +59,9 @@ fn target_number(numbers: Vec<usize>, target: usize) -> usize {
 
     let mut last = numbers[n - 1];
     for i in numbers.len()..target {
+        if i % CHECK_EVERY == 0 {
+            cancel::check()?;
+        }
         if let Some(prev) = visited.get_mut(last).and_then(|v| v.as_mut()) {
             let j = prev.1;
             *prev = (j, i);
@@ -57,30 +71,72 @@ fn target_number(numbers: Vec<usize>, target: usize) -> usize {
             last = 0;
         }
     }
-    last
+    Ok(last)
+}
+
+pub struct Solution;
+
+register!(15, "Rambunctious Recitation");
+
+impl Solver for Solution {
+    type Parsed<'a> = Vec<usize>;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(numbers: &Vec<usize>) -> Result<usize, AocError> {
+        target_number(numbers.clone(), 2020)
+    }
+
+    fn part_two(numbers: &Vec<usize>) -> Result<usize, AocError> {
+        target_number(numbers.clone(), 30000000)
+    }
 }
 
 pub fn part_one(input: &str) -> usize {
-    let numbers = parse_input(input);
-    target_number(numbers, 2020)
+    crate::solver::part_one::<Solution>(input)
 }
 
 pub fn part_two(input: &str) -> usize {
-    let numbers = parse_input(input);
-    target_number(numbers, 30000000)
+    crate::solver::part_two::<Solution>(input)
+}
+
+/// Like `target_number`, keeping the last turns in a HashMap.
+pub fn target_number_hashmap(numbers: Vec<usize>, target: usize) -> usize {
+    let n = numbers.len();
+    let mut last_turn: FastMap<usize, usize> = numbers[..n - 1]
+        .iter()
+        .enumerate()
+        .map(|(i, &num)| (num, i + 1))
+        .collect();
+
+    let mut last = numbers[n - 1];
+    for i in n..target {
+        let next = match last_turn.insert(last, i) {
+            Some(j) => i - j,
+            None => 0,
+        };
+        last = next;
+    }
+    last
+}
+
+pub fn part_one_hashmap(input: &str) -> usize {
+    let numbers = parse(input).unwrap();
+    target_number_hashmap(numbers, 2020)
+}
+
+pub fn part_two_hashmap(input: &str) -> usize {
+    let numbers = parse(input).unwrap();
+    target_number_hashmap(numbers, 30000000)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::read_example;
-
-    #[test]
-    fn example() {
-        let input = read_example(15);
-        assert_eq!(part_one(&input), 436);
-        assert_eq!(part_two(&input), 175594);
-    }
 
     #[test]
     fn more_examples() {
@@ -93,6 +149,7 @@ mod tests {
             ("3,1,2", 1836),
         ] {
             assert_eq!(part_one(input), expected);
+            assert_eq!(part_one_hashmap(input), expected);
         }
     }
 
@@ -0,0 +1,234 @@
+//! Day 16: Ticket Translation
+//!
+//! ## Problem Description
+//!
+//! Part 1: Find the sum of all invalid values in nearby tickets based on field rules.
+//! Part 2: Determine which fields are which on your ticket and multiply the departure values.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Parses three sections:
+//! - Field rules: name + valid ranges (e.g., "class: 1-3 or 5-7")
+//! - Your ticket: comma-separated values
+//! - Nearby tickets: list of comma-separated value tickets
+//!
+//! **Part 1 Strategy**: Invalid value identification
+//! - For each value in nearby tickets, check if it matches any field's valid ranges
+//! - Sum all values that don't match any field's constraints
+//!
+//! **Part 2 Strategy**: Field mapping via constraint satisfaction
+//! - Filter out invalid tickets using Part 1 criteria
+//! - For each ticket position, determine which fields could validly map to it
+//! - Assign each position its own field with `aoc::csp::assign_unique`
+//! - Extract departure-related fields from your ticket and multiply their values
+//!
+//! **Algorithm**: Constraint satisfaction by elimination, which settles the
+//! puzzle, with a backtracking fallback on the smallest domain first.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::csp;
+use crate::error::{number, AocError};
+use crate::Solver;
+
+/// A field and the ranges its values are in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule<'a> {
+    pub name: &'a str,
+    /// Inclusive `(lo, hi)` ranges
+    pub ranges: Vec<(u64, u64)>,
+}
+
+impl Rule<'_> {
+    /// Whether `value` is in one of the ranges of the field
+    pub fn allows(&self, value: u64) -> bool {
+        self.ranges
+            .iter()
+            .any(|&(lo, hi)| (lo..=hi).contains(&value))
+    }
+}
+
+/// The values of a ticket, in the order of its fields
+pub type Ticket = Vec<u64>;
+
+/// The notes on the tickets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notes<'a> {
+    pub rules: Vec<Rule<'a>>,
+    /// Your ticket
+    pub ticket: Ticket,
+    pub nearby_tickets: Vec<Ticket>,
+}
+
+/// Parse the notes into the field rules, your ticket and nearby tickets
+pub fn parse(input: &str) -> Result<Notes<'_>, AocError> {
+    scope!("parse");
+    let sections: Vec<&str> = crate::paragraphs(input);
+    let [rules, ticket, nearby_tickets] = sections[..] else {
+        return Err(AocError::at(
+            input,
+            input.trim(),
+            "expected rules, your ticket and nearby tickets",
+        ));
+    };
+    let invalid = |s: &str, expected: &str| {
+        AocError::at(input, s, format!("expected {expected}, got `{s}`"))
+    };
+    let values = |s: &str| -> Result<Vec<u64>, AocError> {
+        s.split(',').map(|s| number(input, s)).collect()
+    };
+
+    let rules: Vec<Rule> = rules
+        .trim()
+        .lines()
+        .map(|s| {
+            let (name, ranges) = s
+                .split_once(": ")
+                .ok_or_else(|| invalid(s, "`name: a-b or c-d`"))?;
+            let ranges = ranges
+                .trim()
+                .split(" or ")
+                .map(|range| {
+                    let (lo, hi) = range
+                        .split_once('-')
+                        .ok_or_else(|| invalid(range, "a range `a-b`"))?;
+                    Ok((number(input, lo)?, number(input, hi)?))
+                })
+                .collect::<Result<_, AocError>>()?;
+            Ok(Rule {
+                name: name.trim(),
+                ranges,
+            })
+        })
+        .collect::<Result<_, AocError>>()?;
+
+    let ticket = values(
+        ticket
+            .lines()
+            .nth(1)
+            .ok_or_else(|| invalid(ticket, "your ticket"))?,
+    )?;
+
+    let nearby_tickets = nearby_tickets
+        .lines()
+        .skip(1)
+        .map(values)
+        .collect::<Result<_, _>>()?;
+
+    Ok(Notes {
+        rules,
+        ticket,
+        nearby_tickets,
+    })
+}
+
+/// The field at each position of a ticket, deduced from the valid nearby
+/// tickets
+pub fn field_positions<'a>(
+    notes: &Notes<'a>,
+) -> Result<Vec<&'a str>, AocError> {
+    let Notes {
+        rules,
+        nearby_tickets,
+        ..
+    } = notes;
+    let nearby_count = nearby_tickets.len();
+    let tickets: Vec<&Ticket> = nearby_tickets
+        .iter()
+        .filter(|ticket| {
+            ticket
+                .iter()
+                .all(|&value| rules.iter().any(|rule| rule.allows(value)))
+        })
+        .collect();
+    log::debug!(
+        "{} of {} nearby tickets are valid",
+        tickets.len(),
+        nearby_count
+    );
+
+    // the fields that allow the value at a position of every ticket
+    let candidates: BTreeMap<usize, BTreeSet<usize>> =
+        (0..notes.ticket.len())
+            .map(|x| {
+                let fields = (0..rules.len())
+                    .filter(|&i| {
+                        tickets.iter().all(|ticket| {
+                            ticket.get(x).is_some_and(|&v| rules[i].allows(v))
+                        })
+                    })
+                    .collect();
+                (x, fields)
+            })
+            .collect();
+    let sizes: Vec<usize> = candidates.values().map(BTreeSet::len).collect();
+    log::debug!("candidate fields per position: {sizes:?}");
+
+    let fields = csp::assign_unique(candidates)?;
+    Ok(fields.into_values().map(|i| rules[i].name).collect())
+}
+
+pub struct Solution;
+
+register!(16, "Ticket Translation");
+
+impl Solver for Solution {
+    type Parsed<'a> = Notes<'a>;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<Notes<'_>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(notes: &Notes) -> Result<u64, AocError> {
+        let is_invalid =
+            |value: u64| !notes.rules.iter().any(|rule| rule.allows(value));
+        Ok(notes
+            .nearby_tickets
+            .iter()
+            .flat_map(|ticket| {
+                ticket.iter().filter(|&value| is_invalid(*value))
+            })
+            .sum())
+    }
+
+    fn part_two(notes: &Notes) -> Result<u64, AocError> {
+        Ok(field_positions(notes)?
+            .iter()
+            .zip(&notes.ticket)
+            .filter(|(s, _)| s.starts_with("departure"))
+            .map(|(_, v)| *v)
+            .product())
+    }
+}
+
+pub fn part_one(input: &str) -> u64 {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> u64 {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[test]
+fn example_part_two() {
+    let input = concat!(
+        "class: 0-1 or 4-19\n",
+        "row: 0-5 or 8-19\n",
+        "seat: 0-13 or 16-19\n",
+        "\n",
+        "your ticket:\n",
+        "11,12,13\n",
+        "\n",
+        "nearby tickets:\n",
+        "3,9,18\n",
+        "15,1,5\n",
+        "5,14,9"
+    );
+
+    let fields = field_positions(&parse(input).unwrap()).unwrap();
+    assert_eq!(fields, ["row", "class", "seat"]);
+}
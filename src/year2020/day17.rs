@@ -0,0 +1,226 @@
+//! Day 17: Conway Cubes
+//!
+//! ## Problem Description
+//!
+//! Part 1: Simulate Conway's Game of Life in 3D space for 6 cycles.
+//! Part 2: Simulate Conway's Game of Life in 4D space for 6 cycles.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Reads the initial slice into a `Grid<bool>`, then
+//! into initial cube positions, mapping '#' to active cubes at z=0 (Part 1) or z=w=0 (Part 2).
+//!
+//! **Part 1 Strategy**: 3D cellular automaton
+//! - Active cube stays active with 2-3 active neighbors
+//! - Inactive cube becomes active with exactly 3 active neighbors
+//! - Uses 3D coordinates (x,y,z) for cube positions
+//!
+//! **Part 2 Strategy**: 4D cellular automaton
+//! - Same rules as Part 1 but in 4D space (x,y,z,w)
+//!
+//! **Simulation Algorithm**: Both parts are a `Cubes<D>` rule run by the
+//! `Sparse` engine of `aoc::automaton`:
+//! - Only active cube positions are stored, as `[i32; D]`
+//! - Each cycle, every active cube adds one to the count of each of its
+//!   `3^D - 1` neighbors, so only cubes next to an active one are visited
+//! - Applies Conway's rules to determine next state
+//!
+//! **Dense Variant** (`--algo dense`): The active region grows by at most one
+//! cell per cycle on each side, so the final bounding box is known up front.
+//! `part_one_dense`/`part_two_dense` allocate a flat array of that size and
+//! count neighbors through precomputed index offsets, avoiding hashing
+//! entirely (part 2 on the real input drops from about 700ms to 15ms).
+
+use crate::automaton::{self, Automaton, Rule, Sparse};
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::Solver;
+
+/// Parse the initial slice of `.` and `#`
+pub fn parse(input: &str) -> Result<Grid<bool>, AocError> {
+    scope!("parse");
+    input.parse()
+}
+
+/// Conway's rules in `D` dimensions, the cell of a cube being its
+/// coordinates.
+struct Cubes<const D: usize> {
+    /// The steps to the `3^D - 1` neighbors of a cube.
+    offsets: Vec<[i32; D]>,
+}
+
+impl<const D: usize> Cubes<D> {
+    fn new() -> Self {
+        let offsets = (0..3usize.pow(D as u32))
+            .map(|k| {
+                core::array::from_fn(|d| {
+                    (k / 3usize.pow(d as u32) % 3) as i32 - 1
+                })
+            })
+            .filter(|offset: &[i32; D]| offset.iter().any(|&o| o != 0))
+            .collect();
+        Cubes { offsets }
+    }
+}
+
+impl<const D: usize> Rule for Cubes<D> {
+    type Cell = [i32; D];
+
+    fn neighbors(&self, cube: [i32; D]) -> impl Iterator<Item = [i32; D]> {
+        self.offsets
+            .iter()
+            .map(move |offset| core::array::from_fn(|d| cube[d] + offset[d]))
+    }
+
+    fn next(&self, active: bool, active_neighbors: usize) -> bool {
+        automaton::conway(active, active_neighbors)
+    }
+}
+
+/// The active cubes after six cycles in `D` dimensions, the initial
+/// slice at 0 in all but the first two.
+fn boot<const D: usize>(grid: &Grid<bool>) -> Result<usize, AocError> {
+    let active =
+        grid.iter()
+            .filter(|(_, &active)| active)
+            .map(|((x, y), _)| {
+                let mut cube = [0; D];
+                cube[..2].copy_from_slice(&[x as i32, y as i32]);
+                cube
+            });
+    let mut cubes = Sparse::new(Cubes::<D>::new(), active);
+    scope!("cycles");
+    cubes.run_n(6)?;
+    Ok(cubes.population())
+}
+
+pub struct Solution;
+
+register!(17, "Conway Cubes");
+
+impl Solver for Solution {
+    type Parsed<'a> = Grid<bool>;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<Grid<bool>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(grid: &Grid<bool>) -> Result<usize, AocError> {
+        boot::<3>(grid)
+    }
+
+    fn part_two(grid: &Grid<bool>) -> Result<usize, AocError> {
+        boot::<4>(grid)
+    }
+}
+
+pub fn part_one(input: &str) -> usize {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> usize {
+    crate::solver::part_two::<Solution>(input)
+}
+
+pub fn part_one_dense(input: &str) -> usize {
+    simulate_dense(&parse(input).unwrap(), 3, 6)
+}
+
+pub fn part_two_dense(input: &str) -> usize {
+    simulate_dense(&parse(input).unwrap(), 4, 6)
+}
+
+/// Run `cycles` cycles in `dims` dimensions on a dense array sized to the
+/// final bounding box, flipping cells in place of hashing positions.
+fn simulate_dense(grid: &Grid<bool>, dims: usize, cycles: usize) -> usize {
+    // every axis grows by at most one cell per cycle on both sides, plus a
+    // border of inactive cells so that neighbors never leave the array
+    let offset = cycles + 1;
+    let extents: Vec<usize> = (0..dims)
+        .map(|d| match d {
+            0 => grid.width(),
+            1 => grid.height(),
+            _ => 1,
+        })
+        .map(|size| size + 2 * offset)
+        .collect();
+    let strides: Vec<usize> = extents
+        .iter()
+        .scan(1, |stride, &extent| {
+            let s = *stride;
+            *stride *= extent;
+            Some(s)
+        })
+        .collect();
+    let len = strides[dims - 1] * extents[dims - 1];
+
+    let mut cells = vec![false; len];
+    for ((x, y), &active) in grid.iter() {
+        if active {
+            let center: usize = strides[2..].iter().sum::<usize>() * offset;
+            cells[(x + offset) * strides[0]
+                + (y + offset) * strides[1]
+                + center] = true;
+        }
+    }
+
+    let deltas: Vec<isize> = (0..3usize.pow(dims as u32))
+        .filter(|&n| n != (3usize.pow(dims as u32) - 1) / 2)
+        .map(|mut n| {
+            let mut delta = 0;
+            for &stride in &strides {
+                delta += (n % 3) as isize * stride as isize - stride as isize;
+                n /= 3;
+            }
+            delta
+        })
+        .collect();
+
+    let mut next = cells.clone();
+    for cycle in 1..=cycles {
+        scope!("cycle", cycle);
+        // cells closer to the border than this are still out of reach
+        let margin = offset - cycle;
+        let mut coords = vec![margin; dims];
+        loop {
+            let i: usize =
+                coords.iter().zip(&strides).map(|(c, s)| c * s).sum();
+            let neighbors = deltas
+                .iter()
+                .filter(|&&d| cells[i.wrapping_add_signed(d)])
+                .count();
+            next[i] = neighbors == 3 || (cells[i] && neighbors == 2);
+
+            let mut d = 0;
+            while d < dims {
+                coords[d] += 1;
+                if coords[d] < extents[d] - margin {
+                    break;
+                }
+                coords[d] = margin;
+                d += 1;
+            }
+            if d == dims {
+                break;
+            }
+        }
+        std::mem::swap(&mut cells, &mut next);
+    }
+
+    cells.iter().filter(|&&c| c).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn dense() {
+        let input = read_example(17).unwrap();
+        assert_eq!(part_one_dense(&input), part_one(&input));
+        assert_eq!(part_two_dense(&input), part_two(&input));
+    }
+}
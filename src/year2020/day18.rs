@@ -0,0 +1,200 @@
+//! Day 18: Operation Order
+//!
+//! ## Problem Description
+//!
+//! Part 1: Evaluate mathematical expressions with different operator precedence:
+//! - Addition and multiplication have equal precedence
+//! - Evaluate left-to-right regardless of operator type
+//!
+//! Part 2: Evaluate with modified precedence rules:
+//! - Addition has higher precedence than multiplication
+//! - Still evaluate left-to-right within same precedence level
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Reads each line as an `aoc::expr::Expr` of numbers,
+//! '+', '*', '(' and ')', failing at the line of a malformed one.
+//!
+//! **Part 1 Strategy**: Equal precedence evaluation
+//! - A precedence table with '+' and '*' at the same level
+//!
+//! **Part 2 Strategy**: Modified precedence evaluation
+//! - A precedence table with addition '+' above multiplication '*'
+//!
+//! **Algorithm**: `Expr::eval` converts to Reverse Polish Notation (RPN) by
+//! the shunting-yard algorithm under the table, then evaluates the RPN on a
+//! stack, for both parts.
+//!
+//! Values are `u128`, and an expression past it fails rather than wraps: long
+//! lines of products are past `u64` after twenty-odd factors of 9.
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::error::AocError;
+use crate::expr::{Expr, Operator, ADD, MUL};
+use crate::Solver;
+
+/// Part one: `+` and `*` bind equally, so left to right
+const LEFT_TO_RIGHT: [Operator; 2] = [ADD.binding(1), MUL.binding(1)];
+
+/// Part two: `+` before `*`
+const ADDITION_FIRST: [Operator; 2] = [ADD.binding(2), MUL.binding(1)];
+
+/// Parse the homework into its expressions
+pub fn parse(input: &str) -> Result<Vec<Expr<'_>>, AocError> {
+    scope!("parse");
+    input
+        .trim()
+        .lines()
+        .map(|s| Expr::try_from(s.trim()).map_err(|e| e.within(input, s)))
+        .collect()
+}
+
+/// The sum of the values of `expressions` under `table`.
+fn sum(expressions: &[Expr], table: &[Operator]) -> Result<u128, AocError> {
+    expressions.iter().try_fold(0u128, |total, expr| {
+        total.checked_add(expr.eval(table)?).ok_or_else(|| {
+            AocError::no_solution(format!(
+                "`{}` adds up past u128",
+                expr.source
+            ))
+        })
+    })
+}
+
+pub struct Solution;
+
+register!(18, "Operation Order");
+
+impl Solver for Solution {
+    type Parsed<'a> = Vec<Expr<'a>>;
+    type One = u128;
+    type Two = u128;
+
+    fn parse(input: &str) -> Result<Vec<Expr<'_>>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(expressions: &Vec<Expr>) -> Result<u128, AocError> {
+        sum(expressions, &LEFT_TO_RIGHT)
+    }
+
+    fn part_two(expressions: &Vec<Expr>) -> Result<u128, AocError> {
+        sum(expressions, &ADDITION_FIRST)
+    }
+}
+
+pub fn part_one(input: &str) -> u128 {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> u128 {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::solve;
+    use alloc::string::{String, ToString};
+    use proptest::prelude::*;
+
+    /// `expr` by precedence climbing, with the binding power of `+` and `*`
+    /// given by `precedence`: a reference independent of the RPN evaluator.
+    fn reference(expr: &str, precedence: fn(char) -> u8) -> u128 {
+        fn climb(
+            tokens: &[char],
+            pos: &mut usize,
+            min: u8,
+            precedence: fn(char) -> u8,
+        ) -> u128 {
+            let mut lhs = match tokens[*pos] {
+                '(' => {
+                    *pos += 1;
+                    let value = climb(tokens, pos, 0, precedence);
+                    *pos += 1;
+                    value
+                }
+                digit => {
+                    *pos += 1;
+                    digit.to_digit(10).unwrap() as u128
+                }
+            };
+            while let Some(&op @ ('+' | '*')) = tokens.get(*pos) {
+                if precedence(op) < min {
+                    break;
+                }
+                *pos += 1;
+                let rhs = climb(tokens, pos, precedence(op) + 1, precedence);
+                lhs = if op == '+' { lhs + rhs } else { lhs * rhs };
+            }
+            lhs
+        }
+        let tokens: Vec<char> =
+            expr.chars().filter(|c| !c.is_whitespace()).collect();
+        climb(&tokens, &mut 0, 0, precedence)
+    }
+
+    /// Terms joined by `+` and `*`, e.g. `2 * 3 + (4 * 5)`.
+    fn sequence(term: BoxedStrategy<String>) -> BoxedStrategy<String> {
+        let op = prop_oneof![Just('+'), Just('*')];
+        (term.clone(), prop::collection::vec((op, term), 0..4))
+            .prop_map(|(first, rest)| {
+                rest.into_iter()
+                    .fold(first, |s, (op, term)| format!("{s} {op} {term}"))
+            })
+            .boxed()
+    }
+
+    /// A line of homework: digits and parenthesized sequences, nested up to
+    /// three deep.
+    fn expression() -> impl Strategy<Value = String> {
+        let digit = (1..10u32).prop_map(|d| d.to_string()).boxed();
+        sequence(
+            digit
+                .prop_recursive(3, 32, 4, |term| {
+                    sequence(term).prop_map(|s| format!("({s})")).boxed()
+                })
+                .boxed(),
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_precedence_climbing(expr in expression()) {
+            prop_assert_eq!(part_one(&expr), reference(&expr, |_| 1));
+            let additive_first = |op| if op == '+' { 2 } else { 1 };
+            prop_assert_eq!(part_two(&expr), reference(&expr, additive_first));
+        }
+    }
+
+    #[test]
+    fn past_u64() {
+        let nines = ["9"; 30].join(" * ");
+        assert_eq!(part_one(&nines), 9u128.pow(30));
+        assert_eq!(part_two(&format!("{nines} + 1")), 9u128.pow(29) * 10);
+        let error = solve::<Solution>(1, &["9"; 41].join(" * ")).unwrap_err();
+        assert!(matches!(error, AocError::NoSolution(_)));
+    }
+
+    #[test]
+    fn malformed() {
+        let error = parse("1 + 2\n(3 * 4\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: incomplete expression `(3 * 4`"
+        );
+    }
+
+    #[test]
+    fn reference_examples() {
+        let expr = "1 + (2 * 3) + (4 * (5 + 6))";
+        assert_eq!(reference(expr, |_| 1), 51);
+        let expr = "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2";
+        assert_eq!(
+            reference(expr, |op| if op == '+' { 2 } else { 1 }),
+            23340
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! Day 19: Monster Messages
+//!
+//! ## Problem Description
+//!
+//! Part 1: Count how many messages completely match rule 0 using the given grammar rules.
+//! Part 2: Modify rules 8 and 11 to handle loops, then count valid messages.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Splits input into:
+//! - Rules: Grammar rules in format "id: rule" where rules can be literals or sequences
+//! - Messages: Lines of text to validate against the grammar
+//!
+//! **Part 1 Strategy**: Recursive pattern matching
+//! - Parses the rules once into an `aoc::grammar::Rules` table of literals
+//!   and sequences
+//! - Uses its recursive descent parsing to match messages against rule 0
+//! - Returns all possible end positions after matching a rule prefix
+//! - Message is valid if any match ends at the end of the message
+//! - Messages are independent, so with the `parallel` feature they are
+//!   matched on all cores with rayon
+//!
+//! **Part 2 Strategy**: Grammar modification with loops
+//! - Substitutes the rules in the table:
+//! - Rule 8: Replaced with "42 | 42 8" (one or more 42s)
+//! - Rule 11: Replaced with "42 31 | 42 11 31" (n 42s followed by n 31s)
+//! - Same recursive matching algorithm handles the modified grammar
+//!
+//! **Algorithm**: Recursive backtracking parser, memoized per rule and
+//! position within a message. The lists of end positions are slices in an
+//! `Arena`, reset between messages, so the memo hands them out without
+//! cloning and the lists under construction share one scratch stack.
+//!
+//! ## Grammar Format
+//! - Literals: "a" or "b" (`Rule::Literal`)
+//! - Sequences: "1 2 3" or "1 2 | 3 4" (`Rule::Alternatives`)
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::error::AocError;
+use crate::grammar::{Rule, Rules};
+use crate::Solver;
+
+/// Replace rules 8 and 11 with their looping versions from part two.
+pub fn with_loops(mut rules: Rules) -> Rules {
+    rules.substitute(8, Rule::Alternatives(vec![vec![42], vec![42, 8]]));
+    rules.substitute(
+        11,
+        Rule::Alternatives(vec![vec![42, 31], vec![42, 11, 31]]),
+    );
+    rules
+}
+
+/// The rules and the messages received from the satellite
+#[derive(Debug, Clone)]
+pub struct Transmission<'a> {
+    pub rules: Rules,
+    pub messages: Vec<&'a str>,
+}
+
+/// Parse the rules into `Rules` and the received messages
+pub fn parse(input: &str) -> Result<Transmission<'_>, AocError> {
+    scope!("parse");
+    let sections = crate::paragraphs(input);
+    let [p1, p2] = sections[..] else {
+        return Err(AocError::at(
+            input,
+            input.trim(),
+            "expected rules and messages",
+        ));
+    };
+    let messages = p2.lines().collect();
+    Ok(Transmission {
+        rules: Rules::parse(p1)?,
+        messages,
+    })
+}
+
+pub struct Solution;
+
+register!(19, "Monster Messages");
+
+impl Solver for Solution {
+    type Parsed<'a> = Transmission<'a>;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<Transmission<'_>, AocError> {
+        parse(input)
+    }
+
+    fn part_one(transmission: &Transmission) -> Result<usize, AocError> {
+        let Transmission { rules, messages } = transmission;
+        Ok(rules.count_matches(messages))
+    }
+
+    fn part_two(transmission: &Transmission) -> Result<usize, AocError> {
+        let Transmission { rules, messages } = transmission;
+        Ok(with_loops(rules.clone()).count_matches(messages))
+    }
+}
+
+pub fn part_one(input: &str) -> usize {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> usize {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    // both parts of the second example; `expected.rs` has part two
+    aoc_example_tests!(day = 19, part_one = 3 in "example-2");
+
+    #[test]
+    fn loops() {
+        let input = read_example(19).unwrap();
+        let Transmission { rules, .. } = parse(&input).unwrap();
+        let rules = with_loops(rules);
+        assert_eq!(rules.get(8).unwrap().to_string(), "42 | 42 8");
+        assert_eq!(rules.get(11).unwrap().to_string(), "42 31 | 42 11 31");
+    }
+
+    #[test]
+    fn crlf() {
+        let input = read_example(19).unwrap();
+        let crlf = format!("\u{feff}{}", input.replace('\n', "\r\n"));
+        assert_eq!(part_one(&crlf), part_one(&input));
+        assert_eq!(part_two(&crlf), part_two(&input));
+    }
+}
@@ -0,0 +1,461 @@
+//! Day 20: Jurassic Jigsaw
+//!
+//! ## Problem Description
+//!
+//! Part 1: Assemble a jigsaw puzzle from square tiles and find the product of corner tile IDs.
+//! Part 2: Find sea monsters in the assembled image and count '#' characters not part of any monster.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Parse input into tiles with:
+//! - Tile ID (from "Tile ####:")
+//! - 10x10 `Bitmap` of '#' (active) and '.' (inactive) pixels
+//!
+//! **Part 1 Strategy**: Edge matching algorithm
+//! - Extract all 4 edges (top, right, bottom, left) from each tile
+//! - Consider both original and flipped versions of edges
+//! - Find tiles with exactly 2 matching neighbors (corners)
+//! - Return product of corner tile IDs
+//!
+//! **Part 2 Strategy**: Image assembly and pattern matching
+//! - Assemble tiles into complete image by matching edges, backtracking
+//!   row by row from a corner with `search::backtrack`
+//! - Remove borders from each tile (leaving 8x8 pixels per 10x10 tile)
+//! - Malformed tile sets (not a square number of tiles, tiles of differing
+//!   sizes, tiles without matching neighbors) fail with an error naming the
+//!   problem instead of assembling garbage
+//! - Search for sea monster pattern in all orientations (8 total: 4 rotations × 2 flips)
+//! - Count total '#' characters minus those part of sea monsters
+//!
+//! **Tile Operations**:
+//! - Rotations and flips with `aoc::image`: `Bitmap::apply` lays a tile or
+//!   the image down in an `Orientation`
+//! - All orientations: 8 possible (4 rotations × 2 flips), their edges
+//!   allocated once in an `Arena` and compared as byte slices while
+//!   backtracking
+//!
+//! **Sea Monster Pattern**:
+//! - 3-line pattern with specific '#' positions
+//! - Search across entire image in all orientations
+
+use std::str::FromStr;
+
+use crate::arena::Arena;
+use crate::collections::{FastMap, FastSet};
+use crate::error::{number, AocError};
+use crate::image::{Bitmap, Orientation};
+use crate::search;
+use crate::Solver;
+
+/// Represents a square tile in the jigsaw puzzle
+#[derive(Debug, Clone)]
+pub struct Tile {
+    pub id: usize,
+    pub pixels: Bitmap,
+}
+
+/// A `Tile n:` line and the rows of pixels below it.
+impl FromStr for Tile {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, AocError> {
+        let lines: Vec<&str> = s.lines().collect();
+        let (id_line, rows) = s.split_once('\n').unwrap_or((s, ""));
+        let id: usize = id_line
+            .strip_prefix("Tile ")
+            .and_then(|s| s.strip_suffix(":"))
+            .ok_or_else(|| {
+                AocError::at(
+                    s,
+                    id_line,
+                    format!("expected `Tile n:`, got `{id_line}`"),
+                )
+            })
+            .and_then(|id| number(s, id))?;
+
+        let size = lines.len() - 1;
+        if let Some(row) = lines[1..].iter().find(|row| row.len() != size) {
+            return Err(AocError::at(
+                s,
+                row,
+                format!("expected a row of {size} pixels, got `{row}`"),
+            ));
+        }
+        let pixels = rows.parse().map_err(|e: AocError| e.within(s, rows))?;
+        Ok(Tile { id, pixels })
+    }
+}
+
+/// One of the eight orientations of a tile, with its edges in an arena.
+#[derive(Debug, Clone)]
+struct Oriented<'a> {
+    pixels: Bitmap,
+    /// Clockwise from the top, each read left to right or top to bottom.
+    edges: [&'a [bool]; 4],
+}
+
+impl<'a> Oriented<'a> {
+    /// `tile` in every `Orientation`.
+    fn all(tile: &Tile, arena: &'a Arena<bool>) -> Vec<Oriented<'a>> {
+        tile.pixels
+            .orientations()
+            .map(|(_, pixels)| Oriented {
+                edges: pixels.edges().map(|edge| arena.alloc_slice(&edge)),
+                pixels,
+            })
+            .collect()
+    }
+}
+
+/// Parse the input string into a vector of tiles
+pub fn parse(input: &str) -> Result<Vec<Tile>, AocError> {
+    scope!("parse");
+    crate::paragraphs(input)
+        .into_iter()
+        .map(|s| s.parse().map_err(|e: AocError| e.within(input, s)))
+        .collect()
+}
+
+/// Find which tiles can connect to each other based on matching edges
+fn find_edge_matches(tiles: &[Tile]) -> FastMap<usize, FastSet<usize>> {
+    let mut matches: FastMap<usize, FastSet<usize>> = FastMap::default();
+
+    // Get all edges for each tile (including flipped versions)
+    let mut all_edges: FastMap<Vec<bool>, Vec<usize>> = FastMap::default();
+
+    for tile in tiles {
+        for mut edge in tile.pixels.edges() {
+            all_edges.entry(edge.clone()).or_default().push(tile.id);
+            // Also add the reversed edge
+            edge.reverse();
+            all_edges.entry(edge).or_default().push(tile.id);
+        }
+    }
+
+    // Find which tiles can connect to each other
+    for (_edge, tile_ids) in all_edges {
+        if tile_ids.len() >= 2 {
+            for &id1 in &tile_ids {
+                for &id2 in &tile_ids {
+                    if id1 != id2 {
+                        matches.entry(id1).or_default().insert(id2);
+                    }
+                }
+            }
+        }
+    }
+
+    matches
+}
+
+/// The jigsaw as a backtracking problem: tiles are placed row by row, each
+/// in one of its orientations, starting from a corner in the top-left.
+struct Jigsaw<'a> {
+    /// All orientations of every tile.
+    orientations: Vec<Vec<Oriented<'a>>>,
+    corner: usize,
+    size: usize,
+    /// (tile, orientation) of every placed position.
+    placed: Vec<(usize, usize)>,
+    used: Vec<bool>,
+    /// How many tiles were placed, including those taken back.
+    attempts: usize,
+}
+
+impl<'a> Jigsaw<'a> {
+    fn tile(&self, position: usize) -> &Oriented<'a> {
+        let (tile, orientation) = self.placed[position];
+        &self.orientations[tile][orientation]
+    }
+}
+
+impl search::Backtrack for Jigsaw<'_> {
+    type Candidate = (usize, usize);
+
+    fn is_complete(&self) -> bool {
+        self.placed.len() == self.size * self.size
+    }
+
+    fn candidates(&self) -> Vec<(usize, usize)> {
+        let tiles: Vec<usize> = if self.placed.is_empty() {
+            vec![self.corner]
+        } else {
+            (0..self.orientations.len())
+                .filter(|&t| !self.used[t])
+                .collect()
+        };
+        tiles
+            .into_iter()
+            .flat_map(|t| {
+                (0..self.orientations[t].len()).map(move |o| (t, o))
+            })
+            .collect()
+    }
+
+    fn accepts(&self, &(tile, orientation): &(usize, usize)) -> bool {
+        let edges = self.orientations[tile][orientation].edges;
+        let position = self.placed.len();
+        let (row, col) = (position / self.size, position % self.size);
+
+        // top edge must match bottom edge of top tile
+        if row > 0 && edges[0] != self.tile(position - self.size).edges[2] {
+            return false;
+        }
+        // left edge must match right edge of left tile
+        if col > 0 && edges[3] != self.tile(position - 1).edges[1] {
+            return false;
+        }
+        true
+    }
+
+    fn apply(&mut self, (tile, orientation): (usize, usize)) {
+        self.attempts += 1;
+        self.used[tile] = true;
+        self.placed.push((tile, orientation));
+    }
+
+    fn undo(&mut self) {
+        let (tile, _) = self.placed.pop().unwrap();
+        self.used[tile] = false;
+    }
+}
+
+/// Assemble the jigsaw puzzle into a complete image without the tile
+/// borders, or explain why the tiles cannot form one
+pub fn assemble_image(tiles: &[Tile]) -> Result<Bitmap, String> {
+    scope!("assemble");
+    let size = (tiles.len() as f64).sqrt() as usize;
+    if size == 0 || size * size != tiles.len() {
+        return Err(format!(
+            "tile count {} is not a perfect square",
+            tiles.len()
+        ));
+    }
+    let tile_size = tiles[0].pixels.height();
+    if tile_size < 3 {
+        return Err(format!("tile {} is too small", tiles[0].id));
+    }
+    for tile in tiles {
+        if tile.pixels.height() != tile_size
+            || tile.pixels.width() != tile_size
+        {
+            return Err(format!(
+                "tiles of differing sizes: tile {} is not {tile_size}x{tile_size} \
+                 like tile {}",
+                tile.id, tiles[0].id
+            ));
+        }
+    }
+
+    let matches = find_edge_matches(tiles);
+    let neighbors = |t: &Tile| matches.get(&t.id).map_or(0, FastSet::len);
+    // a single bad tile also costs its neighbors a match, so blame the
+    // tile with the fewest
+    let tile = tiles.iter().min_by_key(|t| neighbors(t)).unwrap();
+    if neighbors(tile) < 2 {
+        return Err(format!("tile {} has unmatched edge", tile.id));
+    }
+
+    // Corner tiles have exactly 2 matching neighbors
+    let corner = tiles
+        .iter()
+        .position(|t| neighbors(t) == 2)
+        .ok_or("no corner tile with exactly two matching neighbors")?;
+    log::debug!(
+        "{} corner candidates, starting from tile {}",
+        tiles.iter().filter(|t| neighbors(t) == 2).count(),
+        tiles[corner].id
+    );
+
+    let arena = Arena::new();
+    let mut jigsaw = Jigsaw {
+        orientations: tiles
+            .iter()
+            .map(|t| Oriented::all(t, &arena))
+            .collect(),
+        corner,
+        size,
+        placed: Vec::new(),
+        used: vec![false; tiles.len()],
+        attempts: 0,
+    };
+    let fits = {
+        scope!("backtrack");
+        search::backtrack(&mut jigsaw)
+    };
+    log::debug!("placed {} tiles while backtracking", jigsaw.attempts);
+    if !fits {
+        return Err("tiles do not fit together".to_string());
+    }
+    log::debug!(
+        "assembled a {size}x{size} image of {tile_size}x{tile_size} tiles"
+    );
+
+    // Combine tiles into final image (removing borders)
+    let inner = tile_size - 2;
+    let mut image = Bitmap::new(size * inner, size * inner);
+    for position in 0..size * size {
+        let (row, col) = (position / size, position % size);
+        let pixels = &jigsaw.tile(position).pixels;
+        image.draw(
+            col * inner,
+            row * inner,
+            &pixels.crop(1, 1, inner, inner),
+        );
+    }
+    Ok(image)
+}
+
+/// Find sea monsters in the assembled image and return count of '#' not part of monsters
+fn find_sea_monsters(image: &Bitmap) -> usize {
+    scope!("sea monsters");
+    let sea_monster = [
+        "                  # ",
+        "#    ##    ##    ###",
+        " #  #  #  #  #  #   ",
+    ];
+
+    let monster_positions: Vec<(usize, usize)> = sea_monster
+        .iter()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|(_, c)| *c == '#')
+                .map(move |(col, _)| (row, col))
+        })
+        .collect();
+
+    // Try all orientations of the image
+    for orientation in Orientation::ALL {
+        let mut oriented = image.apply(orientation);
+        let monsters_found = mark_monsters(&mut oriented, &monster_positions);
+        if monsters_found > 0 {
+            log::debug!("{monsters_found} sea monsters in {orientation:?}");
+            // Count remaining # pixels
+            return oriented.count_ones();
+        }
+    }
+    image.count_ones()
+}
+
+/// Clear the pixels of every sea monster in `image`, returning how many
+/// there are.
+fn mark_monsters(
+    image: &mut Bitmap,
+    monster_positions: &[(usize, usize)],
+) -> usize {
+    let mut monsters_found = 0;
+    let rows = image.height();
+    let cols = image.width();
+
+    for start_row in 0..rows.saturating_sub(2) {
+        for start_col in 0..cols.saturating_sub(19) {
+            let is_monster =
+                monster_positions.iter().all(|&(row_offset, col_offset)| {
+                    image.get(start_col + col_offset, start_row + row_offset)
+                });
+
+            if is_monster {
+                monsters_found += 1;
+                for &(row_offset, col_offset) in monster_positions {
+                    image.set(
+                        start_col + col_offset,
+                        start_row + row_offset,
+                        false,
+                    );
+                }
+            }
+        }
+    }
+
+    monsters_found
+}
+
+pub struct Solution;
+
+register!(20, "Jurassic Jigsaw");
+
+impl Solver for Solution {
+    type Parsed<'a> = Vec<Tile>;
+    type One = u64;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<Vec<Tile>, AocError> {
+        parse(input)
+    }
+
+    /// Part 1: Find the product of corner tile IDs
+    fn part_one(tiles: &Vec<Tile>) -> Result<u64, AocError> {
+        let matches = find_edge_matches(tiles);
+
+        // Corner tiles have exactly 2 matching neighbors
+        let corner_tiles: Vec<usize> = matches
+            .iter()
+            .filter(|(_, neighbors)| neighbors.len() == 2)
+            .map(|(id, _)| *id)
+            .collect();
+        log::debug!("corner candidates: {corner_tiles:?}");
+
+        // a u64 rather than usize, which overflows on 32-bit targets
+        Ok(corner_tiles.iter().map(|&id| id as u64).product())
+    }
+
+    /// Part 2: Count '#' characters that are not part of sea monsters
+    fn part_two(tiles: &Vec<Tile>) -> Result<usize, AocError> {
+        let image = assemble_image(tiles).map_err(AocError::NoSolution)?;
+        Ok(find_sea_monsters(&image))
+    }
+}
+
+pub fn part_one(input: &str) -> u64 {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> usize {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn tiles() {
+        let tile: Tile = "Tile 7:\n#.\n.#".parse().unwrap();
+        assert_eq!(tile.id, 7);
+        assert_eq!(tile.pixels, "#.\n.#".parse().unwrap());
+        let input = "Tile 1:\n#.\n.#\n\nTile 2:\n#.\n.\n";
+        let error = parse(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 7: expected a row of 2 pixels, got `.`"
+        );
+        assert!("Tile x:\n#".parse::<Tile>().is_err());
+    }
+
+    #[test]
+    fn malformed() {
+        let tiles = parse(&read_example(20).unwrap()).unwrap();
+        assert_eq!(assemble_image(&tiles).unwrap().height(), 24);
+        assert_eq!(
+            assemble_image(&tiles[1..]).unwrap_err(),
+            "tile count 8 is not a perfect square"
+        );
+
+        let mut shrunk = tiles.clone();
+        shrunk[4].pixels = shrunk[4].pixels.crop(0, 0, 10, 9);
+        let error = assemble_image(&shrunk).unwrap_err();
+        assert!(error.starts_with("tiles of differing sizes: tile "));
+
+        let mut garbled = tiles.clone();
+        garbled[4].pixels =
+            "#.##..#.#.\n.#..##.#.#\n".repeat(5).parse().unwrap();
+        let id = garbled[4].id;
+        assert_eq!(
+            assemble_image(&garbled).unwrap_err(),
+            format!("tile {id} has unmatched edge")
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! Day 21: Allergen Assessment
+//!
+//! ## Problem Description
+//!
+//! Part 1: Count how many times ingredients that cannot contain allergens appear across all foods.
+//! Part 2: Determine which ingredient contains which allergen and return a canonical list sorted by allergen name.
+//!
+//! ## Solution Approach
+//!
+//! **Input Parsing**: Parses each food line into:
+//! - Ingredients: Set of ingredient names (space-separated before "(contains")
+//! - Allergens: Set of allergen names (comma-separated after "contains")
+//!
+//! **Part 1 Strategy**: Constraint elimination
+//! - For each allergen, find ingredients that could potentially contain it by intersecting all ingredient sets from foods containing that allergen
+//! - Collect all ingredients that could contain any allergen
+//! - Count occurrences of ingredients that cannot contain any allergen
+//!
+//! **Part 2 Strategy**: Constraint solving via elimination
+//! - Assigns each allergen its own ingredient with `aoc::csp::assign_unique`
+//! - Repeatedly finds allergens with only one possible ingredient and eliminates that ingredient from other allergen possibilities
+//! - Sorts allergens alphabetically and returns corresponding ingredients as comma-separated list
+//!
+//! **Algorithm**: Constraint satisfaction problem solved using iterative elimination, with a backtracking fallback on the smallest domain first.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::collections::{FastMap, FastSet};
+use crate::csp;
+use crate::error::AocError;
+use crate::Solver;
+
+/// Represents a food item with its ingredients and known allergens
+#[derive(Debug, Clone)]
+pub struct Food {
+    pub ingredients: FastSet<String>,
+    pub allergens: FastSet<String>,
+}
+
+/// Ingredients, then the allergens they contain if known, e.g.
+/// `sqjhc fvjkl (contains soy)`.
+impl FromStr for Food {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, AocError> {
+        let (ingredients, allergens) = match s.split_once(" (contains ") {
+            Some((ingredients, allergens)) => {
+                let allergens =
+                    allergens.strip_suffix(')').ok_or_else(|| {
+                        AocError::at(
+                            s,
+                            s,
+                            format!("expected `)` at the end of `{s}`"),
+                        )
+                    })?;
+                (ingredients, allergens.split(", ").collect())
+            }
+            None => (s, Vec::new()),
+        };
+        let ingredients: FastSet<String> =
+            ingredients.split_whitespace().map(str::to_string).collect();
+        if ingredients.is_empty() {
+            return Err(AocError::at(
+                s,
+                s,
+                format!("expected ingredients, got `{s}`"),
+            ));
+        }
+        Ok(Food {
+            ingredients,
+            allergens: allergens.into_iter().map(str::to_string).collect(),
+        })
+    }
+}
+
+/// Parse the input string into a vector of Food items
+pub fn parse(input: &str) -> Result<Vec<Food>, AocError> {
+    scope!("parse");
+    input
+        .trim()
+        .lines()
+        .map(|s| s.parse().map_err(|e: AocError| e.within(input, s)))
+        .collect()
+}
+
+/// Find all possible ingredients that could contain each allergen
+/// For each allergen, return the intersection of all ingredient sets from foods containing that allergen
+fn find_possible_allergen_ingredients(
+    foods: &[Food],
+) -> FastMap<String, FastSet<String>> {
+    let mut allergen_possibilities: FastMap<String, FastSet<String>> =
+        FastMap::default();
+
+    // For each allergen, find the intersection of all ingredient sets that contain it
+    for food in foods {
+        for allergen in &food.allergens {
+            if let Some(current_possibilities) =
+                allergen_possibilities.get_mut(allergen)
+            {
+                // Intersect with current ingredients
+                *current_possibilities = current_possibilities
+                    .intersection(&food.ingredients)
+                    .cloned()
+                    .collect();
+            } else {
+                // First time seeing this allergen
+                allergen_possibilities
+                    .insert(allergen.clone(), food.ingredients.clone());
+            }
+        }
+    }
+
+    allergen_possibilities
+}
+
+/// The ingredient that contains each allergen, in order of allergen
+pub fn dangerous_ingredients(
+    foods: &[Food],
+) -> Result<BTreeMap<String, String>, AocError> {
+    let candidates = find_possible_allergen_ingredients(foods)
+        .into_iter()
+        .map(|(allergen, ingredients)| {
+            (allergen, ingredients.into_iter().collect())
+        })
+        .collect();
+    csp::assign_unique(candidates)
+}
+
+pub struct Solution;
+
+register!(21, "Allergen Assessment");
+
+impl Solver for Solution {
+    type Parsed<'a> = Vec<Food>;
+    type One = usize;
+    type Two = String;
+
+    fn parse(input: &str) -> Result<Vec<Food>, AocError> {
+        parse(input)
+    }
+
+    /// Part 1: Count how many times ingredients that cannot contain allergens appear
+    fn part_one(foods: &Vec<Food>) -> Result<usize, AocError> {
+        let allergen_possibilities =
+            find_possible_allergen_ingredients(foods);
+
+        // Get all ingredients that could contain allergens
+        let possible_allergen_ingredients: FastSet<String> =
+            allergen_possibilities
+                .values()
+                .flat_map(|ingredients| ingredients.iter())
+                .cloned()
+                .collect();
+
+        // Count occurrences of ingredients that cannot contain allergens
+        let mut count = 0;
+        for food in foods {
+            for ingredient in &food.ingredients {
+                if !possible_allergen_ingredients.contains(ingredient) {
+                    count += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    /// Part 2: Return the canonical dangerous ingredient list (sorted by allergen name)
+    fn part_two(foods: &Vec<Food>) -> Result<String, AocError> {
+        Ok(dangerous_ingredients(foods)?
+            .into_values()
+            .collect::<Vec<String>>()
+            .join(","))
+    }
+}
+
+pub fn part_one(input: &str) -> usize {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> String {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn foods() {
+        let food: Food = "sqjhc fvjkl (contains soy, fish)".parse().unwrap();
+        assert_eq!(food.ingredients.len(), 2);
+        assert!(food.allergens.contains("fish") && food.allergens.len() == 2);
+        let food: Food = "mxmxvkd".parse().unwrap();
+        assert!(food.allergens.is_empty());
+        let error = parse("kfcds (contains dairy)\nnhms (contains fish\n")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected `)` at the end of `nhms (contains fish`"
+        );
+    }
+
+    #[test]
+    fn example_dangerous_ingredients() {
+        let foods = parse(&read_example(21).unwrap()).unwrap();
+        let dangerous = dangerous_ingredients(&foods).unwrap();
+        let dangerous: Vec<(&str, &str)> = dangerous
+            .iter()
+            .map(|(a, i)| (a.as_str(), i.as_str()))
+            .collect();
+        assert_eq!(
+            dangerous,
+            [("dairy", "mxmxvkd"), ("fish", "sqjhc"), ("soy", "fvjkl")]
+        );
+    }
+}
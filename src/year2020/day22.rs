@@ -28,21 +28,43 @@
 //! - Use HashSet for infinite game prevention in recursive mode
 //! - Calculate score by iterating deck in reverse order with positional multipliers
 
-use std::collections::{HashSet, VecDeque};
+use std::collections::VecDeque;
+
+use crate::collections::FastSet;
+use crate::error::{number, AocError};
+use crate::Solver;
+
+/// The starting decks of the two players, top card first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decks {
+    pub player1: VecDeque<u32>,
+    pub player2: VecDeque<u32>,
+}
 
 /// Parse the input into two player decks
-fn parse_decks(input: &str) -> (VecDeque<u32>, VecDeque<u32>) {
-    let sections: Vec<&str> = input.trim().split("\n\n").collect();
+pub fn parse(input: &str) -> Result<Decks, AocError> {
+    scope!("parse");
+    let sections: Vec<&str> = crate::paragraphs(input);
+    let [deck1, deck2] = sections[..] else {
+        return Err(AocError::at(
+            input,
+            input.trim(),
+            "expected the decks of two players",
+        ));
+    };
 
-    let parse_deck = |section: &str| -> VecDeque<u32> {
+    let parse_deck = |section: &str| -> Result<VecDeque<u32>, AocError> {
         section
             .lines()
             .skip(1) // Skip "Player X:" line
-            .map(|line| line.parse().unwrap())
+            .map(|line| number(input, line))
             .collect()
     };
 
-    (parse_deck(sections[0]), parse_deck(sections[1]))
+    Ok(Decks {
+        player1: parse_deck(deck1)?,
+        player2: parse_deck(deck2)?,
+    })
 }
 
 /// Calculate the score of a deck
@@ -90,8 +112,8 @@ fn play_recursive_combat(
     mut deck1: VecDeque<u32>,
     mut deck2: VecDeque<u32>,
 ) -> (u32, VecDeque<u32>) {
-    let mut seen_states: HashSet<(VecDeque<u32>, VecDeque<u32>)> =
-        HashSet::new();
+    let mut seen_states: FastSet<(VecDeque<u32>, VecDeque<u32>)> =
+        FastSet::default();
 
     while !deck1.is_empty() && !deck2.is_empty() {
         // Check for infinite game prevention
@@ -137,30 +159,39 @@ fn play_recursive_combat(
     }
 }
 
-/// Part 1: Play regular Combat and return winning score
-/// Simple card game where higher card wins both cards
-pub fn part_one(input: &str) -> usize {
-    let (deck1, deck2) = parse_decks(input);
-    play_combat(deck1, deck2)
-}
+pub struct Solution;
 
-/// Part 2: Play Recursive Combat and return winning score
-/// Complex variant with recursive sub-games when conditions are met
-pub fn part_two(input: &str) -> usize {
-    let (deck1, deck2) = parse_decks(input);
-    let (_, winning_deck) = play_recursive_combat(deck1, deck2);
-    calculate_score(&winning_deck)
-}
+register!(22, "Crab Combat");
+
+impl Solver for Solution {
+    type Parsed<'a> = Decks;
+    type One = usize;
+    type Two = usize;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::read_example;
+    fn parse(input: &str) -> Result<Decks, AocError> {
+        parse(input)
+    }
+
+    /// Part 1: Play regular Combat and return winning score
+    /// Simple card game where higher card wins both cards
+    fn part_one(decks: &Decks) -> Result<usize, AocError> {
+        let Decks { player1, player2 } = decks.clone();
+        Ok(play_combat(player1, player2))
+    }
 
-    #[test]
-    fn example() {
-        let input = read_example(22);
-        assert_eq!(part_one(&input), 306);
-        assert_eq!(part_two(&input), 291);
+    /// Part 2: Play Recursive Combat and return winning score
+    /// Complex variant with recursive sub-games when conditions are met
+    fn part_two(decks: &Decks) -> Result<usize, AocError> {
+        let Decks { player1, player2 } = decks.clone();
+        let (_, winning_deck) = play_recursive_combat(player1, player2);
+        Ok(calculate_score(&winning_deck))
     }
 }
+
+pub fn part_one(input: &str) -> usize {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> usize {
+    crate::solver::part_two::<Solution>(input)
+}
@@ -0,0 +1,218 @@
+//! Day 23: Crab Cups - Circular cup shuffling game with two complexity levels
+//!
+//! Problem Summary:
+//! A circular arrangement of numbered cups where a "move" involves:
+//! 1. Current cup is the starting point
+//! 2. Pick up the next 3 cups clockwise
+//! 3. Find destination cup (current cup - 1, wrapping to max if needed)
+//! 4. Skip destination if it's one of the picked up cups
+//! 5. Place the 3 picked cups immediately clockwise of destination
+//! 6. New current cup is the one immediately clockwise of the previous current
+//!
+//! Part 1 - Simple Game:
+//! - Start with 9 cups in the order from input
+//! - Play 100 moves
+//! - Return the cup labels in order after cup 1 (excluding 1 itself)
+//!
+//! Part 2 - Large Scale Game:
+//! - Start with input cups followed by cups 10-1,000,000
+//! - Play 10,000,000 moves
+//! - Return the product of the two cups immediately clockwise of cup 1
+//!
+//! Solution Approach:
+//! - Both parts: Use an `aoc::circular::IndexList`, an array-based linked
+//!   list (next[i] = cup after cup i) with O(1) operations
+//! - Linked list approach allows handling 10M moves with 1M cups efficiently
+//! - Key insight: Array indexing is O(1) vs VecDeque's O(n) for insertions/removals
+//! - A VecDeque version is kept as the reference of the stress tests
+
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::cancel::{self, CHECK_EVERY};
+use crate::circular::IndexList;
+use crate::error::AocError;
+use crate::Solver;
+
+/// Parse the input string into a vector of cup numbers
+pub fn parse(input: &str) -> Result<Vec<u32>, AocError> {
+    scope!("parse");
+    let input = input.trim();
+    input
+        .chars()
+        .map(|c| {
+            c.to_digit(10).ok_or_else(|| {
+                AocError::at(input, input, format!("invalid cup label `{c}`"))
+            })
+        })
+        .collect()
+}
+
+/// Play the crab cups game the simple way, the reference of the stress
+/// tests
+/// Uses VecDeque for simplicity but slower for large inputs
+/// Each move: pick up 3 cups, find destination, insert after destination, move current
+pub fn play_game_simple(cups: Vec<u32>, moves: usize) -> VecDeque<u32> {
+    let mut circle: VecDeque<u32> = cups.into_iter().collect();
+    let mut current_index = 0;
+
+    for _ in 0..moves {
+        let current_cup = circle[current_index];
+
+        // Pick up 3 cups clockwise from current
+        let mut picked_up = Vec::new();
+        for _ in 0..3 {
+            let pick_index = (current_index + 1) % circle.len();
+            picked_up.push(circle.remove(pick_index).unwrap());
+            // Adjust current_index if we removed something before it
+            if pick_index <= current_index && current_index > 0 {
+                current_index -= 1;
+            }
+        }
+
+        // Find destination cup
+        let max_cup = circle.iter().max().unwrap();
+        let mut destination = if current_cup == 1 {
+            *max_cup
+        } else {
+            current_cup - 1
+        };
+
+        while !circle.contains(&destination) {
+            destination = if destination == 1 {
+                *max_cup
+            } else {
+                destination - 1
+            };
+        }
+
+        // Find destination position and insert picked up cups
+        let dest_pos = circle.iter().position(|&x| x == destination).unwrap();
+        for (i, &cup) in picked_up.iter().enumerate() {
+            circle.insert(dest_pos + 1 + i, cup);
+            // Adjust current_index if we inserted before it
+            if dest_pos + 1 + i <= current_index {
+                current_index += 1;
+            }
+        }
+
+        // Move current cup clockwise
+        current_index = (current_index + 1) % circle.len();
+    }
+
+    circle
+}
+
+/// Play the crab cups game on an `IndexList` of the cups, the input
+/// followed by the rest up to `total_cups`, returning the cups after cup 1
+pub fn play_game_efficient(
+    cups: Vec<u32>,
+    total_cups: usize,
+    moves: usize,
+) -> Result<Vec<u32>, AocError> {
+    let mut sorted = cups.clone();
+    sorted.sort_unstable();
+    if !sorted.iter().copied().eq(1..=cups.len() as u32)
+        || total_cups < cups.len()
+    {
+        return Err(AocError::no_solution(format!(
+            "the cups are not labelled 1 to {}",
+            cups.len()
+        )));
+    }
+    let labels = cups.iter().map(|&cup| cup as usize);
+    let mut circle =
+        IndexList::from_order(labels.chain(cups.len() + 1..=total_cups));
+
+    scope!("moves", moves);
+    for m in 0..moves {
+        if m % CHECK_EVERY == 0 {
+            cancel::check()?;
+        }
+        let current = circle.cursor().unwrap();
+        // Pick up the three cups after current
+        let picked = [(); 3].map(|_| circle.remove_after(current));
+
+        // Find destination cup, the next lower label still in the circle
+        let mut destination = current;
+        loop {
+            destination = if destination == 1 {
+                total_cups
+            } else {
+                destination - 1
+            };
+            if circle.contains(destination) {
+                break;
+            }
+        }
+
+        // Insert picked up cups after destination
+        for &cup in picked.iter().rev() {
+            circle.insert_after(destination, cup);
+        }
+
+        // Move to next current cup
+        circle.advance(1);
+    }
+
+    Ok(circle.iter_from(1).skip(1).map(|cup| cup as u32).collect())
+}
+
+pub struct Solution;
+
+register!(23, "Crab Cups");
+
+impl Solver for Solution {
+    type Parsed<'a> = Vec<u32>;
+    type One = String;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<Vec<u32>, AocError> {
+        parse(input)
+    }
+
+    /// Part 1: Play 100 moves with 9 cups, return order after cup 1
+    /// Returns concatenated cup labels clockwise from cup 1 (excluding cup 1 itself)
+    fn part_one(cups: &Vec<u32>) -> Result<String, AocError> {
+        let result = play_game_efficient(cups.clone(), cups.len(), 100)?;
+        Ok(result.iter().map(u32::to_string).collect())
+    }
+
+    /// Part 2: Play 10M moves with 1M cups, return product of two cups after cup 1
+    /// Extends cups 1-9 to 1-1000000, then multiplies the two cups immediately clockwise from cup 1
+    fn part_two(cups: &Vec<u32>) -> Result<u64, AocError> {
+        let result =
+            play_game_efficient(cups.clone(), 1_000_000, 10_000_000)?;
+
+        // The result already starts after cup 1, so first two elements
+        let cup1 = result[0] as u64;
+        let cup2 = result[1] as u64;
+        Ok(cup1 * cup2)
+    }
+}
+
+pub fn part_one(input: &str) -> String {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> u64 {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::solve;
+
+    #[test]
+    fn labels() {
+        let error = solve::<Solution>(1, "3892547").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no solution: the cups are not labelled 1 to 7"
+        );
+        assert!(solve::<Solution>(1, "38925471").is_err());
+    }
+}
@@ -0,0 +1,126 @@
+//! Day 24: Lobby Layout - Hexagonal tile flipping and cellular automaton
+//!
+//! Problem Summary:
+//! A lobby floor has hexagonal tiles arranged in a hexagonal grid. Each tile can be
+//! black or white (starts white). Directions are given as paths from a reference tile,
+//! and each tile at the end of a path gets flipped (black ↔ white).
+//!
+//! Part 1 - Initial Configuration:
+//! - Parse directional instructions (e.g., "esenee" = east, south-east, north-east, east)
+//! - Follow each path from origin (0,0,0) to identify tiles to flip
+//! - Count total black tiles after all flips
+//!
+//! Part 2 - Cellular Automaton:
+//! - Apply Conway's Game of Life rules on hexagonal grid for 100 days
+//! - Rules:
+//!   - Black tile with 1-2 black neighbors stays black
+//!   - White tile with exactly 2 black neighbors becomes black
+//!   - All other tiles become/remain white
+//!
+//! Solution Approach:
+//! - Use cube coordinates (x,y,z) where x+y+z=0 for hexagonal grid representation
+//! - Directions: e(1,-1,0), w(-1,1,0), ne(1,0,-1), nw(0,1,-1), se(0,-1,1), sw(-1,0,1)
+//! - Store only black tiles in HashSet<HexCoord> for efficiency
+//! - For cellular automaton: the `Lobby` rule run by the `Sparse` engine of
+//!   `aoc::automaton`, which counts black neighbors for all potentially
+//!   affected tiles
+//! - Coordinates, neighbors and the parsing of paths (multi-character
+//!   directions ne, nw, se, sw) come from `aoc::hex`
+
+use crate::automaton::{Automaton, Rule, Sparse};
+use crate::collections::FastSet;
+use crate::error::AocError;
+use crate::hex::{parse_path, HexCoord};
+use crate::Solver;
+
+/// Parse input and return set of black tiles after initial flipping
+pub fn parse(input: &str) -> Result<FastSet<HexCoord>, AocError> {
+    scope!("parse");
+    let mut black_tiles = FastSet::default();
+
+    for line in input.lines() {
+        let directions = parse_path(line.trim())
+            .map_err(|e| AocError::at(input, line, e))?;
+        let target = HexCoord::ORIGIN.walk(directions);
+
+        if black_tiles.contains(&target) {
+            black_tiles.remove(&target); // flip back to white
+        } else {
+            black_tiles.insert(target); // flip to black
+        }
+    }
+
+    Ok(black_tiles)
+}
+
+/// The rules of the floor, a tile being live when black.
+struct Lobby;
+
+impl Rule for Lobby {
+    type Cell = HexCoord;
+
+    fn neighbors(&self, tile: HexCoord) -> impl Iterator<Item = HexCoord> {
+        tile.neighbors()
+    }
+
+    fn next(&self, black: bool, black_neighbors: usize) -> bool {
+        if black {
+            // Black tile stays black if it has 1 or 2 black neighbors
+            black_neighbors == 1 || black_neighbors == 2
+        } else {
+            // White tile flips to black if it has exactly 2 black neighbors
+            black_neighbors == 2
+        }
+    }
+}
+
+pub struct Solution;
+
+register!(24, "Lobby Layout");
+
+impl Solver for Solution {
+    type Parsed<'a> = FastSet<HexCoord>;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<FastSet<HexCoord>, AocError> {
+        parse(input)
+    }
+
+    /// Part 1: Count black tiles after initial flipping
+    fn part_one(black_tiles: &FastSet<HexCoord>) -> Result<usize, AocError> {
+        Ok(black_tiles.len())
+    }
+
+    /// Part 2: Count black tiles after 100 days of cellular automaton
+    fn part_two(black_tiles: &FastSet<HexCoord>) -> Result<usize, AocError> {
+        let mut floor = Sparse::new(Lobby, black_tiles.iter().copied());
+        floor.run_n(100)?;
+        Ok(floor.population())
+    }
+}
+
+pub fn part_one(input: &str) -> usize {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> usize {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flips() {
+        // `nwwswee` leads back to the reference tile
+        let black_tiles = parse("esew\nnwwswee\nnwwswee\nesew\nesenee\n");
+        assert_eq!(black_tiles.unwrap().len(), 1);
+        let error = parse("esew\nenx\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: Invalid direction starting with n"
+        );
+    }
+}
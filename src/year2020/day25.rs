@@ -0,0 +1,149 @@
+//! Day 25: Combo Breaker - Cryptographic key exchange using modular exponentiation
+//!
+//! Problem Summary:
+//! A card and door use a cryptographic key exchange based on modular exponentiation:
+//! - Both use subject number 7 with secret loop sizes to generate public keys
+//! - Card has secret loop size, publishes public key
+//! - Door has secret loop size, publishes public key
+//! - Encryption key = transform(door_public_key, card_loop_size) = transform(card_public_key, door_loop_size)
+//!
+//! Part 1 - Find Encryption Key:
+//! - Given two public keys (card and door)
+//! - Find the loop size for one device by brute force
+//! - Use that loop size to transform the other device's public key
+//! - Return the resulting encryption key
+//!
+//! Solution Approach:
+//! - Use modular exponentiation: value = (value * subject) % 20201227
+//! - Brute force loop size finding by iterating from subject 7
+//! - Transform function is `math::mod_pow`, squaring rather than multiplying
+//!   'loop_size' times
+//! - The encryption key is symmetric: either transformation yields same result
+//!
+//! Note: Day 25 traditionally only has Part 1 as the final puzzle
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::error::{number, AocError};
+use crate::math;
+use crate::Solver;
+
+const MODULUS: u64 = 20201227;
+const SUBJECT_NUMBER: u64 = 7;
+
+/// Transform a subject number with given loop size using the cryptographic
+/// formula, `subject_number ^ loop_size % MODULUS`
+pub const fn transform(subject_number: u64, loop_size: usize) -> u64 {
+    math::mod_pow(subject_number as u128, loop_size as u128, MODULUS as u128)
+        as u64
+}
+
+/// Find the loop size that produces the given public key when transforming subject 7
+pub const fn find_loop_size(public_key: u64) -> usize {
+    let mut value = 1;
+    let mut loop_size = 0;
+
+    loop {
+        if value == public_key {
+            return loop_size;
+        }
+        value = (value * SUBJECT_NUMBER) % MODULUS;
+        loop_size += 1;
+    }
+}
+
+/// The encryption key the card and the door agree on, usable in a const
+/// context
+pub const fn encryption_key(
+    card_public_key: u64,
+    door_public_key: u64,
+) -> u64 {
+    transform(door_public_key, find_loop_size(card_public_key))
+}
+
+/// The public keys the card and the door transmit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKeys {
+    pub card: u64,
+    pub door: u64,
+}
+
+/// Parse the input to get the two public keys
+pub fn parse(input: &str) -> Result<PublicKeys, AocError> {
+    scope!("parse");
+    let lines: Vec<&str> = input.trim().lines().collect();
+    let [card, door] = lines[..] else {
+        return Err(AocError::at(
+            input,
+            input.trim(),
+            "expected the card and the door public keys",
+        ));
+    };
+    Ok(PublicKeys {
+        card: number(input, card)?,
+        door: number(input, door)?,
+    })
+}
+
+pub struct Solution;
+
+register!(25, "Combo Breaker");
+
+impl Solver for Solution {
+    type Parsed<'a> = PublicKeys;
+    type One = u64;
+    type Two = String;
+
+    fn parse(input: &str) -> Result<PublicKeys, AocError> {
+        parse(input)
+    }
+
+    /// Part 1: Calculate the encryption key from the two public keys
+    fn part_one(keys: &PublicKeys) -> Result<u64, AocError> {
+        Ok(encryption_key(keys.card, keys.door))
+    }
+
+    /// Part 2: Not applicable for Day 25 (final day traditionally has only one part)
+    fn part_two(_keys: &PublicKeys) -> Result<String, AocError> {
+        Ok("Done".to_string()) // Day 25 typically only has Part 1
+    }
+}
+
+pub fn part_one(input: &str) -> u64 {
+    crate::solver::part_one::<Solution>(input)
+}
+
+pub fn part_two(input: &str) -> String {
+    crate::solver::part_two::<Solution>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform() {
+        // Test examples from problem description
+        assert_eq!(transform(7, 8), 5764801); // Card's public key
+        assert_eq!(transform(7, 11), 17807724); // Door's public key
+
+        // Test encryption key calculation both ways
+        assert_eq!(transform(17807724, 8), 14897079); // Using card's loop size
+        assert_eq!(transform(5764801, 11), 14897079); // Using door's loop size
+    }
+
+    #[test]
+    fn test_find_loop_size() {
+        assert_eq!(find_loop_size(5764801), 8); // Card's loop size
+        assert_eq!(find_loop_size(17807724), 11); // Door's loop size
+        for loop_size in 0..10_000 {
+            let public_key = transform(SUBJECT_NUMBER, loop_size);
+            assert_eq!(find_loop_size(public_key), loop_size);
+        }
+    }
+
+    // evaluated by the compiler
+    const EXAMPLE: u64 = encryption_key(5764801, 17807724);
+    const _: () = assert!(EXAMPLE == 14897079);
+}
@@ -0,0 +1,44 @@
+//! Every solver against the recorded answers of the real inputs, so a
+//! refactor is checked on more than the examples. The answers live in
+//! `answers.toml` (or the file `AOC_ANSWERS` names), which is kept out of
+//! git like the inputs: record it with `cargo run --release -- answers
+//! --out answers.toml`. Without it the test passes vacuously, and days
+//! without an `inputs/NN-input.txt` are skipped. The slow days need
+//! `cargo test --release --test golden`.
+
+use std::{env, fs};
+
+use aoc::report::from_toml;
+
+#[test]
+fn real_answers() {
+    let path = env::var("AOC_ANSWERS").unwrap_or_else(|_| {
+        format!("{}/answers.toml", env!("CARGO_MANIFEST_DIR"))
+    });
+    let Ok(content) = fs::read_to_string(&path) else {
+        eprintln!("skipping: no recorded answers at {path}");
+        return;
+    };
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for entry in from_toml(&content) {
+        let Ok(input) = aoc::read_as_string(entry.day, "input") else {
+            eprintln!("skipping day {}: no input", entry.day);
+            continue;
+        };
+        let puzzle = aoc::registry::find(aoc::year(), entry.day).unwrap();
+        let answer = match puzzle.solve(entry.part, &input) {
+            Ok(answer) => answer.to_string(),
+            Err(e) => e.to_string(),
+        };
+        if answer != entry.answer {
+            failures.push(format!(
+                "day {} part {}: expected {}, got {answer}",
+                entry.day, entry.part, entry.answer
+            ));
+        }
+        checked += 1;
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+    eprintln!("{checked} answers match {path}");
+}